@@ -0,0 +1,112 @@
+use fuels::crypto::SecretKey;
+use std::{env, fmt, str::FromStr};
+
+/// Same as `MAX_MESSAGE_BODY_BYTES` in the Mailbox.
+const MAX_BODY_BYTES: usize = 2048;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scenario {
+    /// `dispatch` with mock hooks only, the baseline cost of the Mailbox.
+    Dispatch,
+    /// `dispatch` with the `MerkleTreeHook` as the required hook.
+    DispatchMerkle,
+    /// `dispatch` with the `MerkleTreeHook` as the required hook and the IGP as the hook.
+    DispatchMerkleIgp,
+    /// `process` of inbound messages with pre-signed message id multisig metadata.
+    Process,
+}
+
+impl Scenario {
+    pub fn all() -> Vec<Scenario> {
+        vec![
+            Scenario::Dispatch,
+            Scenario::DispatchMerkle,
+            Scenario::DispatchMerkleIgp,
+            Scenario::Process,
+        ]
+    }
+}
+
+impl FromStr for Scenario {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "dispatch" => Ok(Scenario::Dispatch),
+            "dispatch_merkle" => Ok(Scenario::DispatchMerkle),
+            "dispatch_merkle_igp" => Ok(Scenario::DispatchMerkleIgp),
+            "process" => Ok(Scenario::Process),
+            other => Err(format!("Unknown scenario: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for Scenario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Scenario::Dispatch => "dispatch",
+            Scenario::DispatchMerkle => "dispatch_merkle",
+            Scenario::DispatchMerkleIgp => "dispatch_merkle_igp",
+            Scenario::Process => "process",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+pub struct BenchConfig {
+    pub node_url: String,
+    pub secret_key: SecretKey,
+    pub senders: usize,
+    pub calls_per_sender: usize,
+    pub body_bytes: usize,
+    pub scenarios: Vec<Scenario>,
+    pub report_path: Option<String>,
+}
+
+impl BenchConfig {
+    pub fn from_env() -> Self {
+        let node_url = env::var("LOCAL_NODE_URL").unwrap_or_else(|_| "127.0.0.1:4000".to_owned());
+
+        let private_key = env::var("LOADED_FUEL_PRIVATE_KEY").unwrap_or_else(|_| {
+            "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c".to_owned()
+        });
+        let secret_key =
+            SecretKey::from_str(&private_key).expect("Invalid LOADED_FUEL_PRIVATE_KEY");
+
+        let body_bytes = parse_var("BENCH_BODY_BYTES", 128);
+        if body_bytes > MAX_BODY_BYTES {
+            panic!(
+                "BENCH_BODY_BYTES must be at most {}, got {}",
+                MAX_BODY_BYTES, body_bytes
+            );
+        }
+
+        let scenarios = match env::var("BENCH_SCENARIOS") {
+            Ok(value) => value
+                .split(',')
+                .map(|s| s.parse().unwrap_or_else(|e: String| panic!("{}", e)))
+                .collect(),
+            Err(_) => Scenario::all(),
+        };
+
+        Self {
+            node_url,
+            secret_key,
+            senders: parse_var("BENCH_SENDERS", 8),
+            calls_per_sender: parse_var("BENCH_CALLS_PER_SENDER", 25),
+            body_bytes,
+            scenarios,
+            report_path: env::var("BENCH_REPORT_PATH").ok(),
+        }
+    }
+}
+
+fn parse_var(name: &str, default: usize) -> usize {
+    env::var(name)
+        .ok()
+        .map(|v| {
+            v.parse()
+                .unwrap_or_else(|_| panic!("{} must be a positive integer, got {}", name, v))
+        })
+        .unwrap_or(default)
+}
@@ -0,0 +1,44 @@
+mod config;
+mod report;
+mod scenarios;
+mod setup;
+
+use config::BenchConfig;
+use fuels::prelude::*;
+use setup::{fund_senders, Topology};
+use std::{fs, path::Path};
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    let config = BenchConfig::from_env();
+
+    let provider = Provider::connect(&config.node_url).await.unwrap();
+    let wallet = WalletUnlocked::new_from_private_key(config.secret_key, Some(provider));
+
+    println!("Deploying benchmark contracts to {}", config.node_url);
+    let topology = Topology::deploy(&wallet).await;
+
+    println!("Funding {} senders", config.senders);
+    let senders = fund_senders(&wallet, config.senders).await;
+
+    println!(
+        "Running {} calls per sender with {} byte bodies",
+        config.calls_per_sender, config.body_bytes
+    );
+
+    let mut reports = vec![];
+    for scenario in config.scenarios.iter() {
+        let report = scenarios::run(*scenario, &topology, &senders, &config).await;
+        report.log();
+        reports.push(report);
+    }
+
+    if let Some(path) = &config.report_path {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(path, serde_json::to_string_pretty(&reports).unwrap()).unwrap();
+        println!("\nReport written to {}", path);
+    }
+}
@@ -0,0 +1,290 @@
+use serde::Serialize;
+use std::time::Duration;
+
+/// Number of buckets the merkle tree growth is split into.
+const GROWTH_BUCKETS: usize = 10;
+
+/// A single successful call made by a sender.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub latency: Duration,
+    pub gas_used: u64,
+    /// Index of the leaf inserted into the merkle tree by the call, if any.
+    pub leaf_index: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LatencyReport {
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GasReport {
+    pub mean: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+/// Average gas of the calls which inserted leaves in `[from_leaf, to_leaf]`.
+#[derive(Debug, Serialize)]
+pub struct GrowthBucket {
+    pub from_leaf: u32,
+    pub to_leaf: u32,
+    pub mean_gas: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MerkleReport {
+    pub count_before: u32,
+    pub count_after: u32,
+    pub growth: Vec<GrowthBucket>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScenarioReport {
+    pub scenario: String,
+    pub senders: usize,
+    pub successful_calls: usize,
+    pub failed_calls: usize,
+    pub duration_secs: f64,
+    pub throughput_per_sec: f64,
+    pub latency: Option<LatencyReport>,
+    pub gas: Option<GasReport>,
+    pub merkle: Option<MerkleReport>,
+}
+
+impl ScenarioReport {
+    pub fn new(
+        scenario: String,
+        senders: usize,
+        samples: &[Sample],
+        failed_calls: usize,
+        duration: Duration,
+    ) -> Self {
+        let duration_secs = duration.as_secs_f64();
+        let throughput_per_sec = if duration_secs > 0.0 {
+            samples.len() as f64 / duration_secs
+        } else {
+            0.0
+        };
+
+        Self {
+            scenario,
+            senders,
+            successful_calls: samples.len(),
+            failed_calls,
+            duration_secs,
+            throughput_per_sec,
+            latency: latency_report(samples),
+            gas: gas_report(samples),
+            merkle: None,
+        }
+    }
+
+    pub fn with_merkle(mut self, count_before: u32, count_after: u32, samples: &[Sample]) -> Self {
+        self.merkle = Some(MerkleReport {
+            count_before,
+            count_after,
+            growth: growth_buckets(samples),
+        });
+        self
+    }
+
+    pub fn log(&self) {
+        println!("\n{}", self.scenario);
+        println!(
+            "- Calls: {} successful, {} failed, {} senders",
+            self.successful_calls, self.failed_calls, self.senders
+        );
+        println!(
+            "- Throughput: {:.2} calls/sec over {:.3} sec",
+            self.throughput_per_sec, self.duration_secs
+        );
+        if let Some(latency) = &self.latency {
+            println!(
+                "- Latency (ms): mean {:.1}, p50 {:.1}, p90 {:.1}, p99 {:.1}, max {:.1}",
+                latency.mean_ms, latency.p50_ms, latency.p90_ms, latency.p99_ms, latency.max_ms
+            );
+        }
+        if let Some(gas) = &self.gas {
+            println!("- Gas: mean {}, min {}, max {}", gas.mean, gas.min, gas.max);
+        }
+        if let Some(merkle) = &self.merkle {
+            println!(
+                "- Merkle tree: {} -> {} leaves",
+                merkle.count_before, merkle.count_after
+            );
+            for bucket in &merkle.growth {
+                println!(
+                    "  leaves {}..={}: mean gas {}",
+                    bucket.from_leaf, bucket.to_leaf, bucket.mean_gas
+                );
+            }
+        }
+    }
+}
+
+fn latency_report(samples: &[Sample]) -> Option<LatencyReport> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let mut latencies: Vec<f64> = samples
+        .iter()
+        .map(|s| s.latency.as_secs_f64() * 1000.0)
+        .collect();
+    latencies.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mean_ms = latencies.iter().sum::<f64>() / latencies.len() as f64;
+
+    Some(LatencyReport {
+        mean_ms,
+        p50_ms: percentile(&latencies, 50.0),
+        p90_ms: percentile(&latencies, 90.0),
+        p99_ms: percentile(&latencies, 99.0),
+        max_ms: *latencies.last().unwrap(),
+    })
+}
+
+/// Nearest-rank percentile of an already sorted, non empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn gas_report(samples: &[Sample]) -> Option<GasReport> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let total: u64 = samples.iter().map(|s| s.gas_used).sum();
+
+    Some(GasReport {
+        mean: total / samples.len() as u64,
+        min: samples.iter().map(|s| s.gas_used).min().unwrap(),
+        max: samples.iter().map(|s| s.gas_used).max().unwrap(),
+    })
+}
+
+/// Splits the calls which inserted a leaf into buckets of consecutive leaves,
+/// showing how the cost of an insertion changes as the tree grows.
+fn growth_buckets(samples: &[Sample]) -> Vec<GrowthBucket> {
+    let mut inserted: Vec<(u32, u64)> = samples
+        .iter()
+        .filter_map(|s| s.leaf_index.map(|index| (index, s.gas_used)))
+        .collect();
+    if inserted.is_empty() {
+        return vec![];
+    }
+    inserted.sort_by_key(|(index, _)| *index);
+
+    let bucket_size = inserted.len().div_ceil(GROWTH_BUCKETS);
+
+    inserted
+        .chunks(bucket_size)
+        .map(|chunk| GrowthBucket {
+            from_leaf: chunk.first().unwrap().0,
+            to_leaf: chunk.last().unwrap().0,
+            mean_gas: chunk.iter().map(|(_, gas)| gas).sum::<u64>() / chunk.len() as u64,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(latency_ms: u64, gas_used: u64, leaf_index: Option<u32>) -> Sample {
+        Sample {
+            latency: Duration::from_millis(latency_ms),
+            gas_used,
+            leaf_index,
+        }
+    }
+
+    #[test]
+    fn test_report_without_samples() {
+        let report = ScenarioReport::new("dispatch".to_string(), 2, &[], 3, Duration::ZERO);
+
+        assert_eq!(report.successful_calls, 0);
+        assert_eq!(report.failed_calls, 3);
+        assert_eq!(report.throughput_per_sec, 0.0);
+        assert!(report.latency.is_none());
+        assert!(report.gas.is_none());
+        assert!(report.merkle.is_none());
+    }
+
+    #[test]
+    fn test_report_latency_gas_and_throughput() {
+        let samples: Vec<Sample> = (1..=100).map(|i| sample(i, i * 10, None)).collect();
+        let report = ScenarioReport::new(
+            "process".to_string(),
+            4,
+            &samples,
+            0,
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(report.successful_calls, 100);
+        assert_eq!(report.throughput_per_sec, 50.0);
+
+        let latency = report.latency.unwrap();
+        assert_eq!(latency.mean_ms, 50.5);
+        assert_eq!(latency.p50_ms, 50.0);
+        assert_eq!(latency.p90_ms, 90.0);
+        assert_eq!(latency.p99_ms, 99.0);
+        assert_eq!(latency.max_ms, 100.0);
+
+        let gas = report.gas.unwrap();
+        assert_eq!(gas.mean, 505);
+        assert_eq!(gas.min, 10);
+        assert_eq!(gas.max, 1000);
+    }
+
+    #[test]
+    fn test_percentile_of_single_value() {
+        assert_eq!(percentile(&[7.0], 1.0), 7.0);
+        assert_eq!(percentile(&[7.0], 99.0), 7.0);
+    }
+
+    #[test]
+    fn test_merkle_growth_buckets() {
+        // Out of order, with a call which inserted no leaf
+        let mut samples: Vec<Sample> = (0..20u32)
+            .rev()
+            .map(|index| sample(1, 100 + index as u64, Some(index)))
+            .collect();
+        samples.push(sample(1, 1, None));
+
+        let report = ScenarioReport::new(
+            "dispatch-merkle".to_string(),
+            1,
+            &samples,
+            0,
+            Duration::from_secs(1),
+        )
+        .with_merkle(0, 20, &samples);
+
+        let merkle = report.merkle.unwrap();
+        assert_eq!(merkle.count_before, 0);
+        assert_eq!(merkle.count_after, 20);
+        assert_eq!(merkle.growth.len(), GROWTH_BUCKETS);
+
+        let first = &merkle.growth[0];
+        assert_eq!(
+            (first.from_leaf, first.to_leaf, first.mean_gas),
+            (0, 1, 100)
+        );
+        let last = merkle.growth.last().unwrap();
+        assert_eq!((last.from_leaf, last.to_leaf, last.mean_gas), (18, 19, 118));
+    }
+
+    #[test]
+    fn test_merkle_growth_without_inserted_leaves() {
+        assert!(growth_buckets(&[sample(1, 1, None)]).is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+use fuel_hyperlane_sdk::abis::{InsertedIntoTreeEvent, Mailbox};
+use fuels::{
+    prelude::*,
+    programs::calls::Execution,
+    types::{Bits256, Bytes, ContractId},
+};
+use futures::future::join_all;
+use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage, H256};
+use rand::{thread_rng, Rng};
+use test_utils::{checkpoint::Checkpoint, ism_metadata::MessageIdMultisigMetadata};
+use tokio::time::Instant;
+
+use crate::{
+    config::{BenchConfig, Scenario},
+    report::{Sample, ScenarioReport},
+    setup::{Topology, LOCAL_DOMAIN, REMOTE_DOMAIN},
+};
+
+/// Outcome of all the calls made by a single sender.
+struct SenderResult {
+    samples: Vec<Sample>,
+    failures: usize,
+}
+
+pub async fn run(
+    scenario: Scenario,
+    topology: &Topology,
+    senders: &[WalletUnlocked],
+    config: &BenchConfig,
+) -> ScenarioReport {
+    match scenario {
+        Scenario::Process => run_process(topology, senders, config).await,
+        _ => run_dispatch(scenario, topology, senders, config).await,
+    }
+}
+
+async fn run_dispatch(
+    scenario: Scenario,
+    topology: &Topology,
+    senders: &[WalletUnlocked],
+    config: &BenchConfig,
+) -> ScenarioReport {
+    let post_dispatch_id = ContractId::from(topology.post_dispatch.id());
+    let merkle_tree_hook_id = ContractId::from(topology.merkle_tree_hook.id());
+    let igp_id = ContractId::from(topology.igp.id());
+
+    let (required_hook, hook) = match scenario {
+        Scenario::Dispatch => (post_dispatch_id, post_dispatch_id),
+        Scenario::DispatchMerkle => (merkle_tree_hook_id, post_dispatch_id),
+        Scenario::DispatchMerkleIgp => (merkle_tree_hook_id, igp_id),
+        Scenario::Process => unreachable!("process is not a dispatch scenario"),
+    };
+    topology.set_required_hook(required_hook).await;
+
+    let recipient = Bits256(ContractId::from(topology.recipient.id()).into());
+    let body = random_body(config.body_bytes);
+
+    // Quoted once up front, the gas price does not change during the run
+    let payment = topology
+        .mailbox
+        .methods()
+        .quote_dispatch(
+            REMOTE_DOMAIN,
+            recipient,
+            Bytes(body.clone()),
+            Bytes(vec![]),
+            hook,
+        )
+        .with_contracts(&[
+            &topology.post_dispatch,
+            &topology.merkle_tree_hook,
+            &topology.igp,
+            &topology.gas_oracle,
+        ])
+        .simulate(Execution::StateReadOnly)
+        .await
+        .expect("Failed to quote dispatch.")
+        .value;
+
+    let count_before = merkle_count(topology).await;
+    let start = Instant::now();
+
+    let results = join_all(senders.iter().map(|wallet| {
+        let mailbox = Mailbox::new(topology.mailbox.id(), wallet.clone());
+        let body = body.clone();
+        async move {
+            let mut result = SenderResult {
+                samples: vec![],
+                failures: 0,
+            };
+
+            for _ in 0..config.calls_per_sender {
+                let call_start = Instant::now();
+                let response = mailbox
+                    .methods()
+                    .dispatch(
+                        REMOTE_DOMAIN,
+                        recipient,
+                        Bytes(body.clone()),
+                        Bytes(vec![]),
+                        hook,
+                    )
+                    .call_params(CallParameters::default().with_amount(payment))
+                    .unwrap()
+                    .with_contracts(&[
+                        &topology.post_dispatch,
+                        &topology.merkle_tree_hook,
+                        &topology.igp,
+                        &topology.gas_oracle,
+                    ])
+                    .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+                    .call()
+                    .await;
+                let latency = call_start.elapsed();
+
+                match response {
+                    Ok(response) => {
+                        let leaf_index = response
+                            .decode_logs_with_type::<InsertedIntoTreeEvent>()
+                            .ok()
+                            .and_then(|events| events.first().map(|event| event.index));
+
+                        result.samples.push(Sample {
+                            latency,
+                            gas_used: response.gas_used,
+                            leaf_index,
+                        });
+                    }
+                    Err(e) => {
+                        eprintln!("{} call failed: {:?}", scenario, e);
+                        result.failures += 1;
+                    }
+                }
+            }
+
+            result
+        }
+    }))
+    .await;
+
+    let duration = start.elapsed();
+    let count_after = merkle_count(topology).await;
+
+    let (samples, failures) = merge(results);
+    let report = ScenarioReport::new(
+        scenario.to_string(),
+        senders.len(),
+        &samples,
+        failures,
+        duration,
+    );
+
+    match scenario {
+        Scenario::Dispatch => report,
+        _ => report.with_merkle(count_before, count_after, &samples),
+    }
+}
+
+async fn run_process(
+    topology: &Topology,
+    senders: &[WalletUnlocked],
+    config: &BenchConfig,
+) -> ScenarioReport {
+    // Signing is not part of what is measured, so every message is signed before the run
+    let mut batches = Vec::with_capacity(senders.len());
+    for sender_index in 0..senders.len() {
+        let mut batch = Vec::with_capacity(config.calls_per_sender);
+        for call_index in 0..config.calls_per_sender {
+            let nonce = (sender_index * config.calls_per_sender + call_index) as u32;
+            let message = inbound_message(topology, nonce, config.body_bytes);
+            let metadata = sign_metadata(topology, &message);
+            batch.push((metadata, RawHyperlaneMessage::from(&message)));
+        }
+        batches.push(batch);
+    }
+
+    let start = Instant::now();
+
+    let results = join_all(senders.iter().zip(batches).map(|(wallet, batch)| {
+        let mailbox = Mailbox::new(topology.mailbox.id(), wallet.clone());
+        async move {
+            let mut result = SenderResult {
+                samples: vec![],
+                failures: 0,
+            };
+
+            for (metadata, message) in batch {
+                let call_start = Instant::now();
+                let response = mailbox
+                    .methods()
+                    .process(Bytes(metadata), Bytes(message))
+                    .with_contracts(&[&topology.ism, &topology.recipient])
+                    .call()
+                    .await;
+                let latency = call_start.elapsed();
+
+                match response {
+                    Ok(response) => result.samples.push(Sample {
+                        latency,
+                        gas_used: response.gas_used,
+                        leaf_index: None,
+                    }),
+                    Err(e) => {
+                        eprintln!("{} call failed: {:?}", Scenario::Process, e);
+                        result.failures += 1;
+                    }
+                }
+            }
+
+            result
+        }
+    }))
+    .await;
+
+    let duration = start.elapsed();
+    let (samples, failures) = merge(results);
+
+    ScenarioReport::new(
+        Scenario::Process.to_string(),
+        senders.len(),
+        &samples,
+        failures,
+        duration,
+    )
+}
+
+fn inbound_message(topology: &Topology, nonce: u32, body_bytes: usize) -> HyperlaneMessage {
+    HyperlaneMessage {
+        version: 3,
+        nonce,
+        origin: REMOTE_DOMAIN,
+        sender: H256::random(),
+        destination: LOCAL_DOMAIN,
+        recipient: H256::from(*ContractId::from(topology.recipient.id())),
+        body: random_body(body_bytes),
+    }
+}
+
+/// Builds message id multisig ISM metadata signed by the topology validators.
+fn sign_metadata(topology: &Topology, message: &HyperlaneMessage) -> Vec<u8> {
+    let checkpoint = Checkpoint {
+        merkle_tree_hook_address: H256::random(),
+        mailbox_domain: message.origin,
+        root: H256::random(),
+        index: message.nonce,
+    }
+    .with_message_id(message.id());
+
+    topology
+        .validators
+        .sign(&checkpoint)
+        .into_iter()
+        .fold(
+            MessageIdMultisigMetadata::new(
+                checkpoint.checkpoint.merkle_tree_hook_address,
+                checkpoint.checkpoint.root,
+                checkpoint.checkpoint.index,
+            ),
+            MessageIdMultisigMetadata::with_signature,
+        )
+        .encode()
+}
+
+async fn merkle_count(topology: &Topology) -> u32 {
+    topology
+        .merkle_tree_hook
+        .methods()
+        .count()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .expect("Failed to read merkle tree count.")
+        .value
+}
+
+fn random_body(length: usize) -> Vec<u8> {
+    let mut body = vec![0u8; length];
+    thread_rng().fill(&mut body[..]);
+    body
+}
+
+fn merge(results: Vec<SenderResult>) -> (Vec<Sample>, usize) {
+    results
+        .into_iter()
+        .fold((vec![], 0), |(mut samples, failures), result| {
+            samples.extend(result.samples);
+            (samples, failures + result.failures)
+        })
+}
@@ -0,0 +1,254 @@
+use fuel_hyperlane_sdk::abis::*;
+use fuels::{
+    prelude::*,
+    types::{Bits256, ContractId, Identity, Salt},
+};
+use rand::{thread_rng, Rng};
+use test_utils::checkpoint::ValidatorSet;
+
+/// Domain the benchmark Mailbox is deployed with.
+pub const LOCAL_DOMAIN: u32 = 13373;
+/// Remote domain messages are dispatched to and processed from.
+pub const REMOTE_DOMAIN: u32 = 9913371;
+
+/// Base asset sent to each sender to cover fees and gas payments.
+const SENDER_FUNDING: u64 = 5_000_000_000;
+
+/// Contracts deployed for a single benchmark run.
+/// Deployed from scratch every run so the merkle tree starts empty
+/// and no state is shared with the E2E deployment.
+pub struct Topology {
+    pub mailbox: Mailbox<WalletUnlocked>,
+    pub post_dispatch: PostDispatchMock<WalletUnlocked>,
+    pub merkle_tree_hook: MerkleTreeHook<WalletUnlocked>,
+    pub gas_oracle: GasOracle<WalletUnlocked>,
+    pub igp: InterchainGasPaymaster<WalletUnlocked>,
+    pub ism: MessageIdMultisigISM<WalletUnlocked>,
+    pub recipient: TestRecipient<WalletUnlocked>,
+    /// The validators of the ISM, used to pre-sign `process` metadata.
+    pub validators: ValidatorSet,
+}
+
+impl Topology {
+    pub async fn deploy(wallet: &WalletUnlocked) -> Self {
+        let wallet_bits = Bits256(wallet.address().hash().into());
+        let owner = Identity::Address(Address::from(wallet.address()));
+
+        let mailbox_configurables = MailboxConfigurables::default()
+            .with_LOCAL_DOMAIN(LOCAL_DOMAIN)
+            .unwrap()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+        let mailbox_id = deploy(
+            "../contracts/mailbox/out/debug/mailbox.bin",
+            get_deployment_config().with_configurables(mailbox_configurables),
+            wallet,
+        )
+        .await;
+
+        let post_dispatch_id = deploy(
+            "../contracts/mocks/mock-post-dispatch/out/debug/mock-post-dispatch.bin",
+            get_deployment_config(),
+            wallet,
+        )
+        .await;
+
+        let merkle_configurables = MerkleTreeHookConfigurables::default()
+            .with_EXPECTED_INITIALIZER(wallet_bits)
+            .unwrap();
+        let merkle_tree_hook_id = deploy(
+            "../contracts/hooks/merkle-tree-hook/out/debug/merkle-tree-hook.bin",
+            get_deployment_config().with_configurables(merkle_configurables),
+            wallet,
+        )
+        .await;
+
+        let gas_oracle_configurables = GasOracleConfigurables::default()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+        let gas_oracle_id = deploy(
+            "../contracts/gas-oracle/out/debug/gas-oracle.bin",
+            get_deployment_config().with_configurables(gas_oracle_configurables),
+            wallet,
+        )
+        .await;
+
+        let igp_configurables = InterchainGasPaymasterConfigurables::default()
+            .with_TOKEN_EXCHANGE_RATE_SCALE(15_000_000_000_000)
+            .unwrap()
+            .with_DEFAULT_GAS_AMOUNT(5000)
+            .unwrap()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+        let igp_id = deploy(
+            "../contracts/hooks/gas-paymaster/out/debug/gas-paymaster.bin",
+            get_deployment_config().with_configurables(igp_configurables),
+            wallet,
+        )
+        .await;
+
+        let ism_configurables = MessageIdMultisigISMConfigurables::default()
+            .with_THRESHOLD(1)
+            .unwrap()
+            .with_EXPECTED_INITIALIZER(wallet_bits)
+            .unwrap();
+        let ism_id = deploy(
+            "../contracts/ism/multisig/message-id-multisig-ism/out/debug/message-id-multisig-ism.bin",
+            get_deployment_config().with_configurables(ism_configurables),
+            wallet,
+        )
+        .await;
+
+        let recipient_id = deploy(
+            "../contracts/test/msg-recipient-test/out/debug/msg-recipient-test.bin",
+            get_deployment_config(),
+            wallet,
+        )
+        .await;
+
+        let topology = Self {
+            mailbox: Mailbox::new(mailbox_id, wallet.clone()),
+            post_dispatch: PostDispatchMock::new(post_dispatch_id, wallet.clone()),
+            merkle_tree_hook: MerkleTreeHook::new(merkle_tree_hook_id, wallet.clone()),
+            gas_oracle: GasOracle::new(gas_oracle_id, wallet.clone()),
+            igp: InterchainGasPaymaster::new(igp_id, wallet.clone()),
+            ism: MessageIdMultisigISM::new(ism_id, wallet.clone()),
+            recipient: TestRecipient::new(recipient_id, wallet.clone()),
+            validators: ValidatorSet::random(1),
+        };
+
+        topology.initialize(owner).await;
+        topology
+    }
+
+    async fn initialize(&self, owner: Identity) {
+        let post_dispatch_address = Bits256(ContractId::from(self.post_dispatch.id()).into());
+        let ism_address = Bits256(ContractId::from(self.ism.id()).into());
+
+        self.mailbox
+            .methods()
+            .initialize(
+                owner,
+                ism_address,
+                post_dispatch_address,
+                post_dispatch_address,
+            )
+            .call()
+            .await
+            .expect("Failed to initialize Mailbox.");
+
+        self.merkle_tree_hook
+            .methods()
+            .initialize(self.mailbox.id())
+            .call()
+            .await
+            .expect("Failed to initialize Merkle Tree Hook.");
+
+        self.gas_oracle
+            .methods()
+            .initialize_ownership(owner)
+            .call()
+            .await
+            .expect("Failed to initialize Gas Oracle.");
+
+        self.gas_oracle
+            .methods()
+            .set_remote_gas_data_configs(vec![RemoteGasDataConfig {
+                domain: REMOTE_DOMAIN,
+                remote_gas_data: RemoteGasData {
+                    domain: REMOTE_DOMAIN,
+                    token_exchange_rate: 15000000000,
+                    gas_price: 37999464941,
+                    token_decimals: 18,
+                },
+            }])
+            .call()
+            .await
+            .expect("Failed to set gas data.");
+
+        self.igp
+            .methods()
+            .initialize(owner, owner)
+            .call()
+            .await
+            .expect("Failed to initialize IGP.");
+
+        self.igp
+            .methods()
+            .set_destination_gas_config(
+                vec![REMOTE_DOMAIN],
+                vec![DomainGasConfig {
+                    gas_oracle: Bits256(ContractId::from(self.gas_oracle.id()).into()),
+                    gas_overhead: 151966,
+                }],
+            )
+            .call()
+            .await
+            .expect("Failed to set destination gas config.");
+
+        self.ism
+            .methods()
+            .initialize(self.validators.addresses())
+            .call()
+            .await
+            .expect("Failed to initialize Message Id Multisig ISM.");
+
+        self.recipient
+            .methods()
+            .set_ism(self.ism.id())
+            .call()
+            .await
+            .expect("Failed to set ISM in Test Recipient.");
+    }
+
+    /// Sets the required hook of the Mailbox, which every dispatch goes through.
+    pub async fn set_required_hook(&self, hook: ContractId) {
+        self.mailbox
+            .methods()
+            .set_required_hook(hook)
+            .call()
+            .await
+            .expect("Failed to set required hook.");
+    }
+}
+
+/// Creates `count` new wallets and funds them from `wallet`, so that
+/// concurrent senders never compete for the same coins.
+pub async fn fund_senders(wallet: &WalletUnlocked, count: usize) -> Vec<WalletUnlocked> {
+    let provider = wallet.provider().unwrap().clone();
+    let mut senders = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let sender = WalletUnlocked::new_random(Some(provider.clone()));
+        wallet
+            .transfer(
+                sender.address(),
+                SENDER_FUNDING,
+                AssetId::BASE,
+                TxPolicies::default(),
+            )
+            .await
+            .expect("Failed to fund sender.");
+        senders.push(sender);
+    }
+
+    senders
+}
+
+async fn deploy(
+    binary_filepath: &str,
+    config: LoadConfiguration,
+    wallet: &WalletUnlocked,
+) -> Bech32ContractId {
+    Contract::load_from(binary_filepath, config)
+        .unwrap_or_else(|e| panic!("Failed to load {}: {:?}", binary_filepath, e))
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap_or_else(|e| panic!("Failed to deploy {}: {:?}", binary_filepath, e))
+}
+
+fn get_deployment_config() -> LoadConfiguration {
+    let mut bytes = [0u8; 32];
+    thread_rng().fill(&mut bytes[..]);
+    LoadConfiguration::default().with_salt(Salt::new(bytes))
+}
@@ -2,9 +2,11 @@ use fuels::{
     prelude::*,
     types::{Bits256, Identity},
 };
-use hyperlane_core::HyperlaneMessage;
+use hyperlane_core::{HyperlaneMessage, H256};
 use std::str::FromStr;
-use test_utils::{funded_wallet_with_private_key, get_revert_reason};
+use test_utils::{
+    funded_wallet_with_private_key, get_revert_reason, hook_metadata::StandardHookMetadata,
+};
 
 // Load abi from json
 abigen!(
@@ -50,25 +52,15 @@ fn create_mock_message() -> HyperlaneMessage {
     }
 }
 
-// variant:        [0:2]     // Set to 1
-// msg_value:      [2:34]    // Left as 0
-// gas_limit:      [34:66]   // Left as 0
-// refund_address: [66:98]   // Set to wallet address
 fn create_mock_metadata(wallet: &WalletUnlocked) -> Bytes {
-    let mut metadata = vec![0u8; MIN_METADATA_LENGTH as usize];
-
-    metadata[0] = 0;
-    metadata[1] = 1;
-
-    let mut gas_limit_bytes = [0u8; 32];
-
-    gas_limit_bytes[24..32].copy_from_slice(&TEST_GAS_AMOUNT.to_be_bytes());
-    metadata[34..66].copy_from_slice(&gas_limit_bytes);
-
     let wallet_bytes: [u8; 32] = wallet.address().hash().into();
-    metadata[66..98].copy_from_slice(&wallet_bytes);
 
-    Bytes(metadata)
+    Bytes(
+        StandardHookMetadata::new()
+            .with_gas_limit(TEST_GAS_AMOUNT)
+            .with_refund_address(H256::from(wallet_bytes))
+            .encode(),
+    )
 }
 
 async fn get_contract_instances() -> (GasPaymaster<WalletUnlocked>, GasOracle<WalletUnlocked>) {
@@ -5,7 +5,10 @@ pub mod ownable;
 pub mod message_recipient;
 pub mod isms;
 pub mod warp_route;
+pub mod multi_asset_warp_route;
 pub mod va;
 pub mod token_router;
 pub mod gas_router;
+pub mod rate_limited;
+pub mod token_recipient;
 pub mod hooks;
@@ -41,3 +41,14 @@ pub struct DefaultHookSetEvent {
 pub struct RequiredHookSetEvent {
     pub module: ContractId,
 }
+
+/// Logged when the hook allowlist is enabled or disabled.
+pub struct HookAllowlistEnabledSetEvent {
+    pub enabled: bool,
+}
+
+/// Logged when a hook is added to or removed from the allowlist.
+pub struct HookAllowedSetEvent {
+    pub hook: ContractId,
+    pub allowed: bool,
+}
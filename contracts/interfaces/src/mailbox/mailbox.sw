@@ -11,6 +11,14 @@ pub enum MailboxError {
     MessageAlreadyDelivered: (),
     MessageVerificationFailed: (),
     MessageTooLarge: u64,
+    HookNotAllowed: ContractId,
+    UnsupportedHookMetadata: ContractId,
+}
+
+/// Who processed a message and at which block height.
+pub struct Delivery {
+    pub processor: Identity,
+    pub block_number: u32,
 }
 
 
@@ -50,6 +58,32 @@ abi Mailbox {
     #[storage(read)]
     fn delivered(message_id: b256) -> bool;
 
+    /// Returns the account which processed the message.
+    ///
+    /// ### Arguments
+    ///
+    /// * `message_id`: [b256] - The unique identifier of the message.
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<Identity>] - The processor, or None if the message has not been processed,
+    /// or was processed before processors were recorded.
+    #[storage(read)]
+    fn processor(message_id: b256) -> Option<Identity>;
+
+    /// Returns the block height at which the message was processed.
+    ///
+    /// ### Arguments
+    ///
+    /// * `message_id`: [b256] - The unique identifier of the message.
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<u32>] - The block height, or None if the message has not been processed,
+    /// or was processed before processors were recorded.
+    #[storage(read)]
+    fn processed_at(message_id: b256) -> Option<u32>;
+
     /// Sets the default ISM used for message verification.
     ///
     /// ### Arguments
@@ -180,4 +214,44 @@ abi Mailbox {
     /// * [ContractId] - The ISM contract Id.
     #[storage(read)]
     fn recipient_ism(recipient: ContractId) -> ContractId;
+
+    /// Sets whether the hooks passed to `dispatch` are restricted to the allowlist,
+    /// and checked to support the metadata before they are paid.
+    /// The default hook is set by the owner and is always allowed without being on the allowlist,
+    /// so passing the zero hook keeps working while the allowlist is enabled.
+    ///
+    /// ### Arguments
+    ///
+    /// * `enabled`: [bool] - Whether the allowlist is enforced.
+    #[storage(write)]
+    fn set_hook_allowlist_enabled(enabled: bool);
+
+    /// Gets whether the hooks passed to `dispatch` are restricted to the allowlist.
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - Whether the allowlist is enforced.
+    #[storage(read)]
+    fn hook_allowlist_enabled() -> bool;
+
+    /// Adds a hook to or removes it from the allowlist.
+    ///
+    /// ### Arguments
+    ///
+    /// * `hook`: [ContractId] - The hook contract Id.
+    /// * `allowed`: [bool] - Whether the hook is allowed.
+    #[storage(write)]
+    fn set_hook_allowed(hook: ContractId, allowed: bool);
+
+    /// Gets whether a hook is on the allowlist.
+    ///
+    /// ### Arguments
+    ///
+    /// * `hook`: [ContractId] - The hook contract Id.
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - Whether the hook is allowed.
+    #[storage(read)]
+    fn is_hook_allowed(hook: ContractId) -> bool;
 }
@@ -0,0 +1,216 @@
+library;
+
+use std::{bytes::Bytes, string::String};
+
+/// Errors that can occur when interacting with the MultiAssetWarpRoute contract
+pub enum MultiAssetWarpRouteError {
+    InvalidAddress: (),
+    AssetAlreadyRegistered: (),
+    AssetNotRegistered: (),
+    RouterNotSet: (),
+    SenderNotMailbox: (),
+    SenderNotRouter: (),
+    InvalidMessageBody: (),
+    AmountRoundsToZero: (),
+    AmountOverflow: (),
+    AmountExceedsLocalRange: (),
+    InvalidAssetSend: (),
+    PaymentNotEqualToRequired: (),
+    AssetNotReceivedForTransfer: (),
+}
+
+/// The remote token a synthetic sub-asset of the MultiAssetWarpRoute contract represents
+pub struct RemoteToken {
+    /// The domain the token originates from
+    pub domain: u32,
+    /// The address of the token on its origin domain
+    pub token: b256,
+    /// The number of decimals of the token on its origin domain
+    pub decimals: u8,
+}
+
+/// Interface for a warp route minting one synthetic sub-asset per remote token.
+///
+/// Each sub-asset is keyed by the origin domain and address of the remote token,
+/// and transfers of all assets share the remote routers of the contract.
+/// The message body is the remote token, followed by the recipient and amount:
+/// `token (32 bytes) | recipient (32 bytes) | amount (32 bytes)`.
+abi MultiAssetWarpRoute {
+    /// Initializes the MultiAssetWarpRoute contract
+    ///
+    /// ### Arguments
+    ///
+    /// * `owner`: [Identity] - The address of the owner of the contract
+    /// * `mailbox_address`: [b256] - The address of the mailbox contract to use
+    /// * `hook`: [b256] - The address of the post dispatch hook contract to use
+    /// * `ism`: [b256] - The address of the ISM contract to use
+    #[storage(read, write)]
+    fn initialize(owner: Identity, mailbox_address: b256, hook: b256, ism: b256);
+
+    /// Registers a remote token and the synthetic sub-asset representing it
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain the token originates from
+    /// * `token`: [b256] - The address of the token on its origin domain
+    /// * `remote_decimals`: [u8] - The number of decimals of the token on its origin domain
+    /// * `name`: [String] - The name of the sub-asset
+    /// * `symbol`: [String] - The symbol of the sub-asset
+    /// * `decimals`: [u8] - The number of decimals of the sub-asset
+    ///
+    /// ### Returns
+    ///
+    /// * [AssetId] - The asset ID of the sub-asset
+    #[storage(read, write)]
+    fn register_asset(
+        domain: u32,
+        token: b256,
+        remote_decimals: u8,
+        name: String,
+        symbol: String,
+        decimals: u8,
+    ) -> AssetId;
+
+    /// Gets the sub-asset representing a remote token
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain the token originates from
+    /// * `token`: [b256] - The address of the token on its origin domain
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<AssetId>] - The asset ID of the sub-asset, or None if the token is not registered
+    #[storage(read)]
+    fn asset_id(domain: u32, token: b256) -> Option<AssetId>;
+
+    /// Gets the remote token a sub-asset represents
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset ID of the sub-asset
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<RemoteToken>] - The remote token, or None if the asset is not registered
+    #[storage(read)]
+    fn remote_token(asset: AssetId) -> Option<RemoteToken>;
+
+    /// Gets all sub-assets registered in the contract
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<AssetId>] - The registered sub-assets
+    #[storage(read)]
+    fn all_assets() -> Vec<AssetId>;
+
+    /// Transfers a sub-asset back to the domain of its remote token.
+    /// The sub-asset has to be sent to the contract beforehand, the gas payment is sent with the call.
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The sub-asset to transfer
+    /// * `recipient`: [b256] - The address of the recipient
+    /// * `amount`: [u64] - The amount of tokens to transfer
+    #[payable]
+    #[storage(read, write)]
+    fn transfer_remote(
+        asset: AssetId,
+        recipient: b256,
+        amount: u64,
+        metadata: Option<Bytes>,
+        hook: Option<ContractId>,
+    ) -> b256;
+
+    /// Gets the router address for a specific domain
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain to query
+    ///
+    /// ### Returns
+    ///
+    /// * [b256] - The router address (zero address if not set)
+    #[storage(read)]
+    fn router(domain: u32) -> b256;
+
+    /// Enrolls a router for a specific domain
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain to enroll
+    /// * `router`: [b256] - The router address to enroll
+    #[storage(read, write)]
+    fn enroll_remote_router(domain: u32, router: b256);
+
+    /// Gets the mailbox contract ID that the contract is using for transfers
+    ///
+    /// ### Returns
+    ///
+    /// * [ContractId] - The mailbox contract ID
+    #[storage(read)]
+    fn get_mailbox() -> ContractId;
+
+    /// Gets the post dispatch hook contract ID that the contract is using
+    ///
+    /// ### Returns
+    ///
+    /// * [ContractId] - The post dispatch hook contract ID
+    #[storage(read)]
+    fn get_hook() -> ContractId;
+
+    /// Sets the post dispatch hook contract ID that the contract is using
+    ///
+    /// ### Arguments
+    ///
+    /// * `hook`: [ContractId] - The post dispatch hook contract ID
+    #[storage(write)]
+    fn set_hook(hook: ContractId);
+
+    /// Sets the default ISM
+    ///
+    /// ### Arguments
+    ///
+    /// * `module`: [ContractId] - The ISM contract ID
+    #[storage(read, write)]
+    fn set_ism(module: ContractId);
+
+    /// Gets the quote for gas payment
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination_domain`: [u32] - The destination domain
+    #[storage(read)]
+    fn quote_gas_payment(destination_domain: u32) -> u64;
+}
+
+// --------------- Events ---------------
+
+/// Logged when a remote token is registered.
+pub struct AssetRegisteredEvent {
+    pub asset: AssetId,
+    pub domain: u32,
+    pub token: b256,
+}
+
+/// Event emitted when a sub-asset is transferred to the domain of its remote token.
+pub struct SentAssetTransferEvent {
+    pub asset: AssetId,
+    /// The identifier of the destination chain
+    pub destination: u32,
+    /// The address of the recipient on the destination chain
+    pub recipient: b256,
+    /// The amount of tokens being transferred, in the decimals of the remote token
+    pub amount: u256,
+}
+
+/// Event emitted when a sub-asset is minted for a transfer from a remote domain.
+pub struct ReceivedAssetTransferEvent {
+    pub asset: AssetId,
+    /// The identifier of the origin chain
+    pub origin: u32,
+    /// The address of the recipient on this chain
+    pub recipient: b256,
+    /// The amount of tokens received, in the decimals of the sub-asset
+    pub amount: u64,
+}
@@ -0,0 +1,83 @@
+library;
+
+/// Errors that can occur when a transfer is rate limited
+pub enum RateLimitError {
+    RateLimitExceeded: (),
+}
+
+/// The direction of the transfers a rate limit applies to
+pub enum RateLimitDirection {
+    /// Transfers received from the domain
+    Inbound: (),
+    /// Transfers sent to the domain
+    Outbound: (),
+}
+
+/// A rate limit in the style of Hyperlane's `RateLimited` routes.
+/// Transfers consume the level, which refills linearly per block up to the capacity,
+/// rather than per second as on other chains.
+/// Amounts are in the local decimals, and outbound transfers consume their whole amount,
+/// including the fee retained in `COLLATERAL_FEE` mode.
+pub struct RateLimit {
+    /// The maximum amount which can be transferred at once
+    pub capacity: u64,
+    /// The amount restored per block
+    pub refill_rate: u64,
+    /// The amount available at `last_updated`
+    pub level: u64,
+    /// The block height at which the level was last updated
+    pub last_updated: u32,
+}
+
+/// Interface for contracts limiting the amount transferred to and from each domain
+abi RateLimited {
+    /// Sets the rate limit for transfers to or from a domain.
+    /// A capacity of zero removes the limit.
+    /// The limit refills by `refill_rate` every block, and outbound limits count
+    /// the whole amount of a transfer including the fee.
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The remote domain
+    /// * `direction`: [RateLimitDirection] - The direction of the limited transfers
+    /// * `capacity`: [u64] - The maximum amount which can be transferred at once
+    /// * `refill_rate`: [u64] - The amount restored per block
+    #[storage(read, write)]
+    fn set_rate_limit(
+        domain: u32,
+        direction: RateLimitDirection,
+        capacity: u64,
+        refill_rate: u64,
+    );
+
+    /// Gets the rate limit for transfers to or from a domain, refilled up to the current block
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The remote domain
+    /// * `direction`: [RateLimitDirection] - The direction of the limited transfers
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<RateLimit>] - The rate limit, or None if the transfers are not limited
+    #[storage(read)]
+    fn rate_limit(domain: u32, direction: RateLimitDirection) -> Option<RateLimit>;
+}
+
+// --------------- Events ---------------
+
+/// Logged when a rate limit is set or removed.
+pub struct RateLimitSetEvent {
+    pub domain: u32,
+    pub direction: RateLimitDirection,
+    pub capacity: u64,
+    pub refill_rate: u64,
+}
+
+/// Logged when a transfer consumes the remaining amount of a rate limit,
+/// so further transfers revert with `RateLimitExceeded` until it refills.
+pub struct RateLimitReachedEvent {
+    pub domain: u32,
+    pub direction: RateLimitDirection,
+    pub capacity: u64,
+}
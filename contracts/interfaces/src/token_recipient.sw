@@ -0,0 +1,27 @@
+library;
+
+use std::bytes::Bytes;
+
+/// Interface of contracts receiving warp route transfers which carry a payload
+abi TokenRecipient {
+    /// Handles the payload of a transfer once the tokens have been credited to the contract.
+    /// Only recipients registered as contracts on the warp route are called back.
+    /// Reverting reverts the delivery of the message, which can be processed again later.
+    /// The warp route cannot be called again from within the callback.
+    ///
+    /// ### Arguments
+    ///
+    /// * `origin`: [u32] - The domain of the origin
+    /// * `sender`: [b256] - The account which sent the transfer on the origin domain
+    /// * `asset`: [AssetId] - The asset credited to the contract
+    /// * `amount`: [u64] - The amount credited, in the local decimals
+    /// * `payload`: [Bytes] - The payload of the transfer
+    #[storage(read, write)]
+    fn handle_transfer(
+        origin: u32,
+        sender: b256,
+        asset: AssetId,
+        amount: u64,
+        payload: Bytes,
+    );
+}
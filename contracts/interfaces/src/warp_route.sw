@@ -13,6 +13,15 @@ pub enum WarpRouteError {
     AmountNotConvertible: (),
     SenderNotMailbox: (),
     AssetNotReceivedForTransfer: (),
+    AmountRoundsToZero: (),
+    AmountOverflow: (),
+    InvalidMessageBody: (),
+    EmptyPayload: (),
+    FeeTooHigh: (),
+    InsufficientCollateral: (),
+    DepositNotSupported: (),
+    NoDeposit: (),
+    ReconcileNotSupported: (),
 }
 
 /// The mode of the WarpRoute contract
@@ -20,6 +29,8 @@ pub enum WarpRouteTokenMode {
     SYNTHETIC: (),
     COLLATERAL: (),
     NATIVE: (),
+    /// Collateral which retains a fee of each transfer for the beneficiary
+    COLLATERAL_FEE: (),
 }
 
 /// The metadata of the token managed by the WarpRoute contract
@@ -81,6 +92,27 @@ abi WarpRoute {
         hook: Option<ContractId>,
     ) -> b256;
 
+    /// Transfers tokens to a remote domain along with a payload,
+    /// which is forwarded to the recipient contract once the tokens are credited.
+    /// The sender of the transfer is encoded before the payload and passed to the recipient.
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination_domain`: [u32] - The domain to transfer the tokens to
+    /// * `recipient`: [b256] - The address of the recipient contract
+    /// * `amount`: [u64] - The amount of tokens to transfer
+    /// * `payload`: [Bytes] - The payload appended to the transfer
+    #[payable]
+    #[storage(read, write)]
+    fn transfer_remote_with_payload(
+        destination_domain: u32,
+        recipient: b256,
+        amount: u64,
+        payload: Bytes,
+        metadata: Option<Bytes>,
+        hook: Option<ContractId>,
+    ) -> b256;
+
     /// Gets the token mode of the WarpRoute contract
     ///
     /// ### Returns
@@ -170,27 +202,133 @@ abi WarpRoute {
     fn set_beneficiary(beneficiary: Identity);
 
     /// Claims the contract's balance and sends it to the beneficiary.
+    /// In `COLLATERAL_FEE` mode only the accrued fees are claimed, the collateral backing
+    /// remote transfers and assets sent for pending transfers stay in the contract.
     ///
     /// ### Arguments
     ///
     /// * `asset`: Option<[AssetId]> - The asset to claim. If None, the base asset is used.
     #[storage(read, write)]
     fn claim(asset: Option<AssetId>);
+
+    /// Sets whether incoming transfers to `recipient` are delivered to the contract
+    /// with that ID instead of the address.
+    ///
+    /// ### Arguments
+    ///
+    /// * `recipient`: [b256] - The recipient as encoded in the message body
+    /// * `is_contract`: [bool] - Whether the recipient is a contract
+    #[storage(read, write)]
+    fn set_contract_recipient(recipient: b256, is_contract: bool);
+
+    /// Gets whether incoming transfers to `recipient` are delivered to a contract
+    ///
+    /// ### Arguments
+    ///
+    /// * `recipient`: [b256] - The recipient as encoded in the message body
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - Whether the recipient is a contract
+    #[storage(read)]
+    fn is_contract_recipient(recipient: b256) -> bool;
+
+    /// Sets the fee retained on transfers in `COLLATERAL_FEE` mode
+    ///
+    /// ### Arguments
+    ///
+    /// * `fee_bps`: [u64] - The fee in basis points of the transferred amount
+    #[storage(read, write)]
+    fn set_fee(fee_bps: u64);
+
+    /// Gets the fee retained on transfers in `COLLATERAL_FEE` mode
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The fee in basis points
+    #[storage(read)]
+    fn fee() -> u64;
+
+    /// Gets the retained fees which can be claimed by the beneficiary
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The accrued fees
+    #[storage(read)]
+    fn accrued_fees() -> u64;
+
+    /// Gets the balance locked as collateral by transfers
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The collateral balance
+    #[storage(read)]
+    fn collateral_balance() -> u64;
+
+    /// Reconciles the accounted balances with the balance of the contract in `COLLATERAL_FEE` mode,
+    /// e.g. after a rebase of the collateral asset. A shortfall is written off the accrued fees
+    /// and then the collateral, a surplus is reported but left for pending transfers.
+    #[storage(read, write)]
+    fn reconcile();
+
+    /// Sets the remote token the synthetic asset represents, exposed as SRC-7 metadata
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain the token originates from
+    /// * `token`: [b256] - The address of the token on its origin domain
+    #[storage(read, write)]
+    fn set_origin_token(domain: u32, token: b256);
+
+    /// Deposits the asset of the WarpRoute for a later `transfer_remote` by the sender.
+    /// Unlike assets sent to the contract directly, deposits can only be used
+    /// by their sender and can be refunded.
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    /// Refunds the deposit of the sender which has not been transferred
+    #[storage(read, write)]
+    fn refund_deposit();
+
+    /// Gets the deposit of a sender which has not been transferred
+    ///
+    /// ### Arguments
+    ///
+    /// * `depositor`: [Identity] - The sender of the deposit
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The deposited amount
+    #[storage(read)]
+    fn deposit_of(depositor: Identity) -> u64;
 }
 
 // --------------- Events ---------------
 
 /// Event emitted when tokens are transferred to a remote domain.
 /// This event contains information about the destination chain, recipient, and amount.
+/// It is only emitted when the amount fits in a `u64`, see `SentTransferRemoteAmountEvent`.
 pub struct SentTransferRemoteEvent {
     /// The identifier of the destination chain
     pub destination: u32,
     /// The address of the recipient on the destination chain
     pub recipient: b256,
-    /// The amount of tokens being transferred
+    /// The amount of tokens being transferred, in the decimals of the destination router
     pub amount: u64,
 }
 
+/// Event emitted when tokens are transferred to a remote domain, with the full `u256` amount
+/// carried by the message.
+pub struct SentTransferRemoteAmountEvent {
+    /// The identifier of the destination chain
+    pub destination: u32,
+    /// The address of the recipient on the destination chain
+    pub recipient: b256,
+    /// The amount of tokens being transferred, in the decimals of the destination router
+    pub amount: u256,
+}
+
 /// Event emitted when tokens are received from a remote domain.
 /// This event contains information about the origin chain, recipient, and amount.
 pub struct ReceivedTransferRemoteEvent {
@@ -198,7 +336,7 @@ pub struct ReceivedTransferRemoteEvent {
     pub origin: u32,
     /// The address of the recipient on this chain
     pub recipient: b256,
-    /// The amount of tokens received
+    /// The amount of tokens received, in the local decimals
     pub amount: u64,
 }
 
@@ -218,3 +356,53 @@ pub struct ClaimEvent {
     pub beneficiary: Identity,
     pub amount: u64,
 }
+
+/// Logged when a transfer carrying a payload is delivered to a recipient which is not
+/// a registered contract, so the tokens are credited without calling it back.
+pub struct PayloadDroppedEvent {
+    pub origin: u32,
+    /// The account which sent the transfer on the origin domain
+    pub sender: b256,
+    pub recipient: b256,
+}
+
+/// Logged when a recipient is registered or unregistered as a contract.
+pub struct ContractRecipientSetEvent {
+    pub recipient: b256,
+    pub is_contract: bool,
+}
+
+/// Logged when the transfer fee is set.
+pub struct FeeSetEvent {
+    pub fee_bps: u64,
+}
+
+/// Logged when a fee is retained from a transfer to a remote domain.
+pub struct TransferFeeRetainedEvent {
+    pub destination: u32,
+    pub fee: u64,
+}
+
+/// Logged when the accounted balances are reconciled with the balance of the contract.
+pub struct BalanceReconciledEvent {
+    pub surplus: u64,
+    pub shortfall: u64,
+}
+
+/// Logged when the remote token the synthetic asset represents is set.
+pub struct OriginTokenSetEvent {
+    pub domain: u32,
+    pub token: b256,
+}
+
+/// Logged when assets are deposited for a later transfer.
+pub struct DepositEvent {
+    pub depositor: Identity,
+    pub amount: u64,
+}
+
+/// Logged when a deposit is refunded to its sender.
+pub struct DepositRefundedEvent {
+    pub depositor: Identity,
+    pub amount: u64,
+}
@@ -5,8 +5,12 @@ use fuels::{
     prelude::*,
     types::{Bits256, Bytes32, ContractId, EvmAddress},
 };
-use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage, H256};
-use test_utils::get_revert_reason;
+use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage, Signable, H256};
+use test_utils::{
+    checkpoint::{recover_validator, Checkpoint, ValidatorSet},
+    get_revert_reason,
+    ism_metadata::MessageIdMultisigMetadata,
+};
 
 // Load abi from json
 abigen!(Contract(
@@ -395,7 +399,171 @@ async fn verify_invalid_metadata() {
     assert_eq!(get_revert_reason(error), "assertion failed");
 }
 
-// ============ Note ============
-// Verification logic tests in the demo
-// due to the lack of testing data from
-// Hyperlane
+// ============ Checkpoint Utils ============
+#[tokio::test]
+async fn digest_matches_checkpoint_utils() {
+    let (ism, _, _) = get_contract_instance().await;
+
+    let (messages, metadata, expected_digests, _) = message_id_test_data();
+
+    for (index, message) in messages.iter().enumerate() {
+        let decoded = MessageIdMultisigMetadata::decode(&metadata[index].0).unwrap();
+        let checkpoint = Checkpoint {
+            merkle_tree_hook_address: decoded.origin_merkle_tree_hook,
+            mailbox_domain: message.origin,
+            root: decoded.root,
+            index: decoded.index,
+        }
+        .with_message_id(message.id());
+
+        assert_eq!(checkpoint.digest(), expected_digests[index]);
+        assert_eq!(
+            checkpoint.signing_hash(),
+            checkpoint.to_hyperlane().signing_hash()
+        );
+        assert_eq!(
+            checkpoint.digest(),
+            checkpoint.to_hyperlane().eth_signed_message_hash()
+        );
+
+        let digest = ism
+            .methods()
+            .digest(
+                metadata[index].clone(),
+                Bytes(RawHyperlaneMessage::from(message)),
+            )
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(digest.0, checkpoint.digest().as_bytes());
+    }
+}
+
+// ============ Verify With Validator Set ============
+async fn get_initialized_instance(
+    validators: &ValidatorSet,
+    threshold: u8,
+) -> MessageIdMultisigIsm<WalletUnlocked> {
+    let (_, _, wallet) = get_contract_instance().await;
+    let wallet_bits = Bits256(wallet.address().hash().into());
+
+    let configurables = MessageIdMultisigIsmConfigurables::default()
+        .with_THRESHOLD(threshold)
+        .unwrap()
+        .with_EXPECTED_INITIALIZER(wallet_bits)
+        .unwrap();
+
+    let id = Contract::load_from(
+        "./out/debug/message-id-multisig-ism.bin",
+        LoadConfiguration::default().with_configurables(configurables),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+
+    let ism = MessageIdMultisigIsm::new(id, wallet);
+    ism.methods()
+        .initialize(validators.addresses())
+        .call()
+        .await
+        .unwrap();
+    ism
+}
+
+fn signed_metadata(
+    message: &HyperlaneMessage,
+    validators: &ValidatorSet,
+    indexes: &[usize],
+) -> (Bytes, H256) {
+    let checkpoint = Checkpoint {
+        merkle_tree_hook_address: H256::repeat_byte(0x11),
+        mailbox_domain: message.origin,
+        root: H256::repeat_byte(0x22),
+        index: message.nonce,
+    }
+    .with_message_id(message.id());
+
+    let metadata = validators.sign_with(&checkpoint, indexes).into_iter().fold(
+        MessageIdMultisigMetadata::new(
+            checkpoint.checkpoint.merkle_tree_hook_address,
+            checkpoint.checkpoint.root,
+            checkpoint.checkpoint.index,
+        ),
+        |metadata, signature| metadata.with_signature(signature),
+    );
+
+    (Bytes(metadata.encode()), checkpoint.digest())
+}
+
+#[tokio::test]
+async fn verify_with_validator_set() {
+    let validators = ValidatorSet::random(3);
+    let ism = get_initialized_instance(&validators, 2).await;
+    let (messages, _, _, _) = message_id_test_data();
+    let message = &messages[0];
+
+    // Any two validators reach the threshold, in any order of signing
+    for indexes in [[0, 1], [2, 0], [1, 2]] {
+        let (metadata, digest) = signed_metadata(message, &validators, &indexes);
+
+        let signatures = MessageIdMultisigMetadata::decode(&metadata.0)
+            .unwrap()
+            .signatures;
+        let mut sorted = indexes.to_vec();
+        sorted.sort();
+        for (signature, index) in signatures.iter().zip(sorted) {
+            assert_eq!(
+                recover_validator(signature, digest),
+                Some(validators.addresses()[index])
+            );
+        }
+
+        let verified = ism
+            .methods()
+            .verify(metadata, Bytes(RawHyperlaneMessage::from(message)))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert!(verified);
+    }
+}
+
+#[tokio::test]
+async fn verify_fails_below_threshold() {
+    let validators = ValidatorSet::random(3);
+    let ism = get_initialized_instance(&validators, 2).await;
+    let (messages, _, _, _) = message_id_test_data();
+    let message = &messages[0];
+
+    // Signed by a validator which is not in the set
+    let outsiders = ValidatorSet::random(1);
+    let checkpoint = Checkpoint {
+        merkle_tree_hook_address: H256::repeat_byte(0x11),
+        mailbox_domain: message.origin,
+        root: H256::repeat_byte(0x22),
+        index: message.nonce,
+    }
+    .with_message_id(message.id());
+    let metadata = MessageIdMultisigMetadata::new(
+        checkpoint.checkpoint.merkle_tree_hook_address,
+        checkpoint.checkpoint.root,
+        checkpoint.checkpoint.index,
+    )
+    .with_signature(validators.sign_threshold(&checkpoint, 1)[0])
+    .with_signature(outsiders.sign(&checkpoint)[0]);
+
+    let error = ism
+        .methods()
+        .verify(
+            Bytes(metadata.encode()),
+            Bytes(RawHyperlaneMessage::from(message)),
+        )
+        .call()
+        .await
+        .unwrap_err();
+
+    assert_eq!(get_revert_reason(error), "NoValidatorMatch");
+}
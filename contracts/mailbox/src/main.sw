@@ -14,6 +14,7 @@ use interfaces::{
     ownable::*,
 };
 use std::{
+    block::height,
     bytes::Bytes,
     context::msg_amount,
     contract_id::ContractId,
@@ -50,6 +51,13 @@ storage {
     latest_dispatched_id: b256 = b256::zero(),
     /// The nonce used for message IDs.
     nonce: u32 = 0,
+    /// Whether the hooks passed to `dispatch` are restricted to the allowlist.
+    hook_allowlist_enabled: bool = false,
+    /// The hooks which can be passed to `dispatch` when the allowlist is enabled.
+    allowed_hooks: StorageMap<ContractId, bool> = StorageMap::<ContractId, bool> {},
+    /// A map of processed message IDs to their processor and processing block height,
+    /// kept apart from `delivered` so that its storage layout stays unchanged.
+    deliveries: StorageMap<b256, Delivery> = StorageMap::<b256, Delivery> {},
 }
 
 impl Mailbox for Contract {
@@ -101,6 +109,42 @@ impl Mailbox for Contract {
         _delivered(message_id)
     }
 
+    /// Returns the account which processed the message.
+    ///
+    /// ### Arguments
+    ///
+    /// * `message_id`: [b256] - The unique identifier of the message.
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<Identity>] - The processor, or None if the message has not been processed,
+    /// or was processed before processors were recorded.
+    #[storage(read)]
+    fn processor(message_id: b256) -> Option<Identity> {
+        match _delivery(message_id) {
+            Some(delivery) => Some(delivery.processor),
+            None => None,
+        }
+    }
+
+    /// Returns the block height at which the message was processed.
+    ///
+    /// ### Arguments
+    ///
+    /// * `message_id`: [b256] - The unique identifier of the message.
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<u32>] - The block height, or None if the message has not been processed,
+    /// or was processed before processors were recorded.
+    #[storage(read)]
+    fn processed_at(message_id: b256) -> Option<u32> {
+        match _delivery(message_id) {
+            Some(delivery) => Some(delivery.block_number),
+            None => None,
+        }
+    }
+
     /// Sets the default ISM used for message verification.
     ///
     /// ### Arguments
@@ -225,6 +269,8 @@ impl Mailbox for Contract {
     /// * If the message body is too large.
     /// * If the contract is paused.
     /// * If reentrancy is detected.
+    /// * If the hook is not allowed while the hook allowlist is enabled.
+    /// * If the required hook or the hook does not support the metadata.
     /// * If any external call fails.
     #[payable]
     #[storage(read, write)]
@@ -249,6 +295,7 @@ impl Mailbox for Contract {
         if hook == ContractId::from(b256::zero()) {
             hook = storage.default_hook.read();
         }
+        require(_is_hook_permitted(hook), MailboxError::HookNotAllowed(hook));
 
         let message = _build_message(destination_domain, recipient_address, message_body);
         let id = message.id();
@@ -264,8 +311,12 @@ impl Mailbox for Contract {
         });
         log(DispatchIdEvent { message_id: id });
 
+        let required_hook_id = storage.required_hook.read();
+        _require_supports_metadata(required_hook_id, metadata);
+        _require_supports_metadata(hook, metadata);
+
         let hook = abi(PostDispatchHook, b256::from(hook));
-        let required_hook = abi(PostDispatchHook, b256::from(storage.required_hook.read()));
+        let required_hook = abi(PostDispatchHook, b256::from(required_hook_id));
         let mut required_value = required_hook.quote_dispatch(metadata, message.message_clean().bytes);
         if (msg_amount() < required_value) {
             required_value = msg_amount()
@@ -302,6 +353,8 @@ impl Mailbox for Contract {
     ///
     /// ### Reverts
     ///
+    /// * If the hook is not allowed while the hook allowlist is enabled.
+    /// * If the required hook or the hook does not support the metadata.
     /// * If any external call fails.
     #[storage(read)]
     fn quote_dispatch(
@@ -315,11 +368,16 @@ impl Mailbox for Contract {
         if hook == ContractId::from(b256::zero()) {
             hook = storage.default_hook.read();
         }
+        require(_is_hook_permitted(hook), MailboxError::HookNotAllowed(hook));
 
         let message = _build_message(destination_domain, recipient_address, message_body);
         let msg_bytes = message.message_clean().bytes;
 
-        let required_hook = abi(PostDispatchHook, b256::from(storage.required_hook.read()));
+        let required_hook_id = storage.required_hook.read();
+        _require_supports_metadata(required_hook_id, metadata);
+        _require_supports_metadata(hook, metadata);
+
+        let required_hook = abi(PostDispatchHook, b256::from(required_hook_id));
         let hook = abi(PostDispatchHook, b256::from(hook));
 
         required_hook.quote_dispatch(metadata, msg_bytes) + hook.quote_dispatch(metadata, msg_bytes)
@@ -361,6 +419,13 @@ impl Mailbox for Contract {
         let id = message.id();
         require(!_delivered(id), MailboxError::MessageAlreadyDelivered);
         storage.delivered.insert(id, true);
+        storage.deliveries.insert(
+            id,
+            Delivery {
+                processor: msg_sender().unwrap(),
+                block_number: height(),
+            },
+        );
 
         let recipient = message.recipient();
 
@@ -412,6 +477,68 @@ impl Mailbox for Contract {
             ism
         }
     }
+
+    /// Sets whether the hooks passed to `dispatch` are restricted to the allowlist,
+    /// and checked to support the metadata before they are paid.
+    /// The default hook is set by the owner and is always allowed without being on the allowlist,
+    /// so passing the zero hook keeps working while the allowlist is enabled.
+    ///
+    /// ### Arguments
+    ///
+    /// * `enabled`: [bool] - Whether the allowlist is enforced.
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner.
+    #[storage(write)]
+    fn set_hook_allowlist_enabled(enabled: bool) {
+        only_owner();
+        storage.hook_allowlist_enabled.write(enabled);
+        log(HookAllowlistEnabledSetEvent { enabled });
+    }
+
+    /// Gets whether the hooks passed to `dispatch` are restricted to the allowlist.
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - Whether the allowlist is enforced.
+    #[storage(read)]
+    fn hook_allowlist_enabled() -> bool {
+        storage.hook_allowlist_enabled.read()
+    }
+
+    /// Adds a hook to or removes it from the allowlist.
+    ///
+    /// ### Arguments
+    ///
+    /// * `hook`: [ContractId] - The hook contract Id.
+    /// * `allowed`: [bool] - Whether the hook is allowed.
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner.
+    /// * If the provided hook address is zero.
+    #[storage(write)]
+    fn set_hook_allowed(hook: ContractId, allowed: bool) {
+        only_owner();
+        require(!hook.is_zero(), MailboxError::InvalidHookAddress);
+        storage.allowed_hooks.insert(hook, allowed);
+        log(HookAllowedSetEvent { hook, allowed });
+    }
+
+    /// Gets whether a hook is on the allowlist.
+    ///
+    /// ### Arguments
+    ///
+    /// * `hook`: [ContractId] - The hook contract Id.
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - Whether the hook is allowed.
+    #[storage(read)]
+    fn is_hook_allowed(hook: ContractId) -> bool {
+        _is_hook_allowed(hook)
+    }
 }
 
 // Internal Contract Functions
@@ -444,6 +571,36 @@ fn _build_message(
     )
 }
 
+/// Whether `hook` can be called by `dispatch`.
+/// The default hook is always permitted, it is set by the owner like the allowlist.
+#[storage(read)]
+fn _is_hook_permitted(hook: ContractId) -> bool {
+    if !storage.hook_allowlist_enabled.read() {
+        return true;
+    }
+    hook == storage.default_hook.read() || _is_hook_allowed(hook)
+}
+
+#[storage(read)]
+fn _is_hook_allowed(hook: ContractId) -> bool {
+    storage.allowed_hooks.get(hook).try_read().unwrap_or(false)
+}
+
+/// Reverts if the hook does not support the metadata, before any payment is forwarded to it.
+fn _require_supports_metadata(hook: ContractId, metadata: Bytes) {
+    let hook_contract = abi(PostDispatchHook, b256::from(hook));
+    require(
+        hook_contract
+            .supports_metadata(metadata),
+        MailboxError::UnsupportedHookMetadata(hook),
+    );
+}
+
+#[storage(read)]
+fn _delivery(message_id: b256) -> Option<Delivery> {
+    storage.deliveries.get(message_id).try_read()
+}
+
 #[storage(read)]
 fn _delivered(message_id: b256) -> bool {
     storage.delivered.get(message_id).try_read().unwrap_or(false)
@@ -7,7 +7,8 @@ use fuels::{
 use hyperlane_core::{Encode, HyperlaneMessage as HyperlaneAgentMessage, H256};
 
 use test_utils::{
-    bits256_to_h256, funded_wallet_with_private_key, get_revert_reason, h256_to_bits256,
+    bits256_to_h256, fixtures, funded_wallet_with_private_key, get_revert_reason, h256_to_bits256,
+    merkle::MerkleTree,
 };
 
 // Load abi from json
@@ -420,6 +421,71 @@ async fn test_process_deliver_twice() {
     );
 }
 
+// ============ Process Records Delivery ============
+#[tokio::test]
+async fn test_process_records_delivery() {
+    let (mailbox, _, recipient, _, _) = get_contract_instance().await;
+
+    let (message, metadata, _) = test_message(&mailbox, &recipient, false);
+    let message_id = h256_to_bits256(message.id());
+
+    let processor = mailbox
+        .methods()
+        .processor(message_id)
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    let processed_at = mailbox
+        .methods()
+        .processed_at(message_id)
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(processor, None);
+    assert_eq!(processed_at, None);
+
+    mailbox
+        .methods()
+        .process(metadata, Bytes(message.to_vec()))
+        .with_tx_policies(TxPolicies::default())
+        .determine_missing_contracts(Some(3))
+        .await
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    let block_height = mailbox
+        .account()
+        .try_provider()
+        .unwrap()
+        .latest_block_height()
+        .await
+        .unwrap();
+
+    let processor = mailbox
+        .methods()
+        .processor(message_id)
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    let processed_at = mailbox
+        .methods()
+        .processed_at(message_id)
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        processor,
+        Some(Identity::Address(mailbox.account().address().into()))
+    );
+    assert_eq!(processed_at, Some(block_height));
+}
+
 // ============ Process ISM Reject ============
 #[tokio::test]
 async fn test_process_ism_reject() {
@@ -772,3 +838,362 @@ async fn test_set_required_hook_reverts_if_not_owner() {
     assert!(call.is_err());
     assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner",);
 }
+
+// ============ Hook Allowlist ============
+
+// Deploys a hook which is not the default or required hook of the mailbox.
+async fn deploy_custom_hook(mailbox: &Mailbox<WalletUnlocked>) -> ContractId {
+    Contract::load_from(
+        "../mocks/mock-post-dispatch/out/debug/mock-post-dispatch.bin",
+        LoadConfiguration::default().with_salt([1u8; 32]),
+    )
+    .unwrap()
+    .deploy(&mailbox.account(), TxPolicies::default())
+    .await
+    .unwrap()
+    .into()
+}
+
+#[tokio::test]
+async fn test_set_hook_allowlist() {
+    let (mailbox, _, _, _, _) = get_contract_instance().await;
+    let hook = deploy_custom_hook(&mailbox).await;
+
+    let enabled = mailbox
+        .methods()
+        .hook_allowlist_enabled()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert!(!enabled);
+
+    let call = mailbox
+        .methods()
+        .set_hook_allowlist_enabled(true)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.decode_logs_with_type::<HookAllowlistEnabledSetEvent>()
+            .unwrap(),
+        vec![HookAllowlistEnabledSetEvent { enabled: true }]
+    );
+
+    let call = mailbox
+        .methods()
+        .set_hook_allowed(hook, true)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.decode_logs_with_type::<HookAllowedSetEvent>().unwrap(),
+        vec![HookAllowedSetEvent {
+            hook,
+            allowed: true,
+        }]
+    );
+
+    let enabled = mailbox
+        .methods()
+        .hook_allowlist_enabled()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    let allowed = mailbox
+        .methods()
+        .is_hook_allowed(hook)
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert!(enabled);
+    assert!(allowed);
+
+    let call = mailbox
+        .methods()
+        .set_hook_allowed(ContractId::default(), true)
+        .call()
+        .await;
+    assert_eq!(get_revert_reason(call.unwrap_err()), "InvalidHookAddress");
+}
+
+#[tokio::test]
+async fn test_set_hook_allowlist_reverts_if_not_owner() {
+    let (mailbox, _, _, post_dispatch_id, _) = get_contract_instance().await;
+
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY).await;
+    let mailbox = mailbox.with_account(non_owner_wallet);
+
+    let call = mailbox
+        .methods()
+        .set_hook_allowlist_enabled(true)
+        .call()
+        .await;
+    assert_eq!(get_revert_reason(call.unwrap_err()), "NotOwner");
+
+    let call = mailbox
+        .methods()
+        .set_hook_allowed(post_dispatch_id.into(), true)
+        .call()
+        .await;
+    assert_eq!(get_revert_reason(call.unwrap_err()), "NotOwner");
+}
+
+#[tokio::test]
+async fn test_dispatch_hook_not_allowed() {
+    let (mailbox, _, recipient, post_dispatch_id, _) = get_contract_instance().await;
+    let hook = deploy_custom_hook(&mailbox).await;
+    let (message, metadata, _) = test_message(&mailbox, &recipient, true);
+
+    mailbox
+        .methods()
+        .set_hook_allowlist_enabled(true)
+        .call()
+        .await
+        .unwrap();
+
+    let dispatch = |hook: ContractId| {
+        mailbox
+            .methods()
+            .dispatch(
+                message.destination,
+                h256_to_bits256(message.recipient),
+                Bytes(message.body.clone()),
+                metadata.clone(),
+                hook,
+            )
+            .with_contract_ids(&[post_dispatch_id.clone(), Bech32ContractId::from(hook)])
+    };
+
+    let call = dispatch(hook).call().await;
+    assert!(get_revert_reason(call.unwrap_err()).starts_with("HookNotAllowed"));
+
+    // The quote agrees with the dispatch
+    let call = mailbox
+        .methods()
+        .quote_dispatch(
+            message.destination,
+            h256_to_bits256(message.recipient),
+            Bytes(message.body.clone()),
+            metadata.clone(),
+            hook,
+        )
+        .with_contract_ids(&[post_dispatch_id.clone(), Bech32ContractId::from(hook)])
+        .simulate(Execution::StateReadOnly)
+        .await;
+    assert!(get_revert_reason(call.unwrap_err()).starts_with("HookNotAllowed"));
+
+    // The default hook does not have to be allowed
+    dispatch(ContractId::default()).call().await.unwrap();
+    dispatch(post_dispatch_id.clone().into())
+        .call()
+        .await
+        .unwrap();
+
+    mailbox
+        .methods()
+        .set_hook_allowed(hook, true)
+        .call()
+        .await
+        .unwrap();
+    dispatch(hook).call().await.unwrap();
+
+    // Disabling the allowlist allows any hook again
+    mailbox
+        .methods()
+        .set_hook_allowed(hook, false)
+        .call()
+        .await
+        .unwrap();
+    mailbox
+        .methods()
+        .set_hook_allowlist_enabled(false)
+        .call()
+        .await
+        .unwrap();
+    dispatch(hook).call().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_dispatch_unsupported_hook_metadata() {
+    let (mailbox, _, _, post_dispatch_id, _) = get_contract_instance().await;
+
+    // The merkle tree hook only supports empty or standard hook metadata
+    let merkle_tree_hook_id = Contract::load_from(
+        "../hooks/merkle-tree-hook/out/debug/merkle-tree-hook.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&mailbox.account(), TxPolicies::default())
+    .await
+    .unwrap();
+
+    mailbox
+        .methods()
+        .set_hook_allowlist_enabled(true)
+        .call()
+        .await
+        .unwrap();
+    mailbox
+        .methods()
+        .set_hook_allowed(merkle_tree_hook_id.clone().into(), true)
+        .call()
+        .await
+        .unwrap();
+
+    let call = mailbox
+        .methods()
+        .dispatch(
+            TEST_REMOTE_DOMAIN,
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            Bytes(vec![10u8; 100]),
+            Bytes::from_hex_str("0x01").unwrap(),
+            merkle_tree_hook_id.clone().into(),
+        )
+        .with_contract_ids(&[post_dispatch_id, merkle_tree_hook_id])
+        .call()
+        .await;
+
+    assert!(get_revert_reason(call.unwrap_err()).starts_with("UnsupportedHookMetadata"));
+}
+
+#[tokio::test]
+async fn test_dispatch_unsupported_hook_metadata_allowlist_disabled() {
+    let (mailbox, _, _, post_dispatch_id, _) = get_contract_instance().await;
+
+    let merkle_tree_hook_id = Contract::load_from(
+        "../hooks/merkle-tree-hook/out/debug/merkle-tree-hook.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&mailbox.account(), TxPolicies::default())
+    .await
+    .unwrap();
+
+    // The metadata is checked whether the hook allowlist is enabled or not
+    assert!(
+        !mailbox
+            .methods()
+            .hook_allowlist_enabled()
+            .simulate(Execution::StateReadOnly)
+            .await
+            .unwrap()
+            .value
+    );
+
+    let quote = mailbox
+        .methods()
+        .quote_dispatch(
+            TEST_REMOTE_DOMAIN,
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            Bytes(vec![10u8; 100]),
+            Bytes::from_hex_str("0x01").unwrap(),
+            merkle_tree_hook_id.clone().into(),
+        )
+        .with_contract_ids(&[post_dispatch_id.clone(), merkle_tree_hook_id.clone()])
+        .simulate(Execution::StateReadOnly)
+        .await;
+    assert!(get_revert_reason(quote.unwrap_err()).starts_with("UnsupportedHookMetadata"));
+
+    let call = mailbox
+        .methods()
+        .dispatch(
+            TEST_REMOTE_DOMAIN,
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            Bytes(vec![10u8; 100]),
+            Bytes::from_hex_str("0x01").unwrap(),
+            merkle_tree_hook_id.clone().into(),
+        )
+        .with_contract_ids(&[post_dispatch_id, merkle_tree_hook_id])
+        .call()
+        .await;
+    assert!(get_revert_reason(call.unwrap_err()).starts_with("UnsupportedHookMetadata"));
+}
+
+// ============ Topology Fixture ============
+#[tokio::test]
+async fn test_topology_dispatch_through_merkle_tree_and_igp() {
+    let topology = fixtures::Topology::launch(
+        fixtures::TopologyConfig::default()
+            .with_default_hook(fixtures::HookConfig::MerkleTreeAndIgp),
+    )
+    .await;
+
+    let message_id = topology.dispatch(b"hello".to_vec()).await.unwrap();
+
+    let root = topology
+        .merkle_tree_hook
+        .methods()
+        .root()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    let expected_root = MerkleTree::from_leaves([bits256_to_h256(message_id)])
+        .unwrap()
+        .root();
+    assert_eq!(bits256_to_h256(root), expected_root);
+
+    let required_hook_called = topology
+        .mock_hook
+        .methods()
+        .was_called()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert!(required_hook_called);
+}
+
+#[tokio::test]
+async fn test_topology_process_with_message_id_multisig() {
+    let topology = fixtures::Topology::launch(fixtures::TopologyConfig::default().with_ism(
+        fixtures::IsmConfig::MessageIdMultisig {
+            validators: 3,
+            threshold: 2,
+        },
+    ))
+    .await;
+
+    let message =
+        topology.inbound_message(topology.recipient.contract_id().into(), b"hello".to_vec());
+    topology.process(&message).await.unwrap();
+
+    let handled = topology
+        .recipient
+        .methods()
+        .handled()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert!(handled);
+}
+
+#[tokio::test]
+async fn test_topology_process_with_merkle_root_multisig() {
+    let topology = fixtures::Topology::launch(fixtures::TopologyConfig::default().with_ism(
+        fixtures::IsmConfig::MerkleRootMultisig {
+            validators: 3,
+            threshold: 2,
+        },
+    ))
+    .await;
+
+    let message =
+        topology.inbound_message(topology.recipient.contract_id().into(), b"hello".to_vec());
+    topology.process(&message).await.unwrap();
+
+    let delivered = topology
+        .mailbox
+        .methods()
+        .delivered(h256_to_bits256(message.id()))
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert!(delivered);
+}
@@ -0,0 +1,675 @@
+contract;
+
+use sway_libs::{
+    asset::{
+        base::{
+            _decimals,
+            _name,
+            _set_decimals,
+            _set_name,
+            _set_symbol,
+            _symbol,
+            _total_assets,
+            _total_supply,
+        },
+        supply::{
+            _burn,
+            _mint,
+        },
+    },
+    ownership::*,
+    pausable::*,
+    reentrancy::reentrancy_guard,
+};
+
+use std::{
+    bytes::Bytes,
+    call_frames::msg_asset_id,
+    context::{
+        msg_amount,
+        this_balance,
+    },
+    contract_id::ContractId,
+    hash::*,
+    storage::storage_map::*,
+    storage::storage_string::*,
+    storage::storage_vec::*,
+    string::String,
+};
+
+use interfaces::{
+    mailbox::mailbox::*,
+    message_recipient::MessageRecipient,
+    multi_asset_warp_route::*,
+    ownable::*,
+};
+use standards::{src20::SRC20, src5::State};
+
+storage {
+    /// The address of the mailbox contract to use for message dispatch
+    mailbox: ContractId = ContractId::zero(),
+    /// The address of the default hook contract to use for message dispatch
+    default_hook: ContractId = ContractId::zero(),
+    /// The address of the default ISM contract to use for message dispatch
+    default_ism: ContractId = ContractId::zero(),
+    /// Mapping of domain identifiers to their corresponding router addresses
+    routers: StorageMap<u32, b256> = StorageMap {},
+    /// Mapping of sub-assets to the remote tokens they represent
+    remote_tokens: StorageMap<AssetId, RemoteToken> = StorageMap {},
+    /// List of registered sub-assets
+    assets: StorageVec<AssetId> = StorageVec {},
+    /// The total number of unique assets minted by this contract.
+    total_assets: u64 = 0,
+    /// The current total number of coins minted for a particular asset.
+    total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    /// The mapping of asset ID to the name of the token
+    name: StorageMap<AssetId, StorageString> = StorageMap {},
+    /// The mapping of asset ID to the symbol of the token
+    symbol: StorageMap<AssetId, StorageString> = StorageMap {},
+    /// The mapping of asset ID to the number of decimals of the token
+    decimals: StorageMap<AssetId, u8> = StorageMap {},
+}
+
+configurable {
+    EXPECTED_OWNER: b256 = b256::zero(),
+}
+
+/// The largest exponent for which a power of ten fits in a `u256`
+const MAX_DECIMALS_DIFFERENCE: u8 = 77;
+/// The length of the remote token, recipient and amount of a transfer message
+const TOKEN_MESSAGE_LENGTH: u64 = 96;
+
+impl MultiAssetWarpRoute for Contract {
+    /// Initializes the MultiAssetWarpRoute contract
+    ///
+    /// ### Arguments
+    ///
+    /// * `owner`: [Identity] - The address of the owner of the contract
+    /// * `mailbox_address`: [b256] - The address of the mailbox contract to use
+    /// * `hook`: [b256] - The address of the post dispatch hook contract to use
+    /// * `ism`: [b256] - The address of the ISM contract to use
+    ///
+    /// ### Reverts
+    ///
+    /// * If the contract is already initialized
+    #[storage(read, write)]
+    fn initialize(owner: Identity, mailbox_address: b256, hook: b256, ism: b256) {
+        initialize_ownership(owner);
+        storage.mailbox.write(ContractId::from(mailbox_address));
+        storage.default_hook.write(ContractId::from(hook));
+        storage.default_ism.write(ContractId::from(ism));
+    }
+
+    /// Registers a remote token and the synthetic sub-asset representing it
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain the token originates from
+    /// * `token`: [b256] - The address of the token on its origin domain
+    /// * `remote_decimals`: [u8] - The number of decimals of the token on its origin domain
+    /// * `name`: [String] - The name of the sub-asset
+    /// * `symbol`: [String] - The symbol of the sub-asset
+    /// * `decimals`: [u8] - The number of decimals of the sub-asset
+    ///
+    /// ### Returns
+    ///
+    /// * [AssetId] - The asset ID of the sub-asset
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    /// * If the token address is zero
+    /// * If the token is already registered
+    #[storage(read, write)]
+    fn register_asset(
+        domain: u32,
+        token: b256,
+        remote_decimals: u8,
+        name: String,
+        symbol: String,
+        decimals: u8,
+    ) -> AssetId {
+        only_owner();
+        require(token != b256::zero(), MultiAssetWarpRouteError::InvalidAddress);
+
+        let asset = AssetId::new(ContractId::this(), _sub_id(domain, token));
+        require(
+            storage
+                .remote_tokens
+                .get(asset)
+                .try_read()
+                .is_none(),
+            MultiAssetWarpRouteError::AssetAlreadyRegistered,
+        );
+
+        storage.remote_tokens.insert(
+            asset,
+            RemoteToken {
+                domain,
+                token,
+                decimals: remote_decimals,
+            },
+        );
+        storage.assets.push(asset);
+
+        // The asset is counted once registered, minting does not count it again
+        storage.total_assets.write(storage.total_assets.read() + 1);
+        storage.total_supply.insert(asset, 0);
+        _set_name(storage.name, asset, name);
+        _set_symbol(storage.symbol, asset, symbol);
+        _set_decimals(storage.decimals, asset, decimals);
+
+        log(AssetRegisteredEvent {
+            asset,
+            domain,
+            token,
+        });
+
+        asset
+    }
+
+    /// Gets the sub-asset representing a remote token
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain the token originates from
+    /// * `token`: [b256] - The address of the token on its origin domain
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<AssetId>] - The asset ID of the sub-asset, or None if the token is not registered
+    #[storage(read)]
+    fn asset_id(domain: u32, token: b256) -> Option<AssetId> {
+        let asset = AssetId::new(ContractId::this(), _sub_id(domain, token));
+        match storage.remote_tokens.get(asset).try_read() {
+            Some(_) => Some(asset),
+            None => None,
+        }
+    }
+
+    /// Gets the remote token a sub-asset represents
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset ID of the sub-asset
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<RemoteToken>] - The remote token, or None if the asset is not registered
+    #[storage(read)]
+    fn remote_token(asset: AssetId) -> Option<RemoteToken> {
+        storage.remote_tokens.get(asset).try_read()
+    }
+
+    /// Gets all sub-assets registered in the contract
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<AssetId>] - The registered sub-assets
+    #[storage(read)]
+    fn all_assets() -> Vec<AssetId> {
+        storage.assets.load_vec()
+    }
+
+    /// Transfers a sub-asset back to the domain of its remote token
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The sub-asset to transfer
+    /// * `recipient`: [b256] - The address of the recipient
+    /// * `amount`: [u64] - The amount of tokens to transfer
+    ///
+    /// ### Reverts
+    ///
+    /// * If the contract is paused
+    /// * If reentrancy is detected
+    /// * If the asset is not registered
+    /// * If no router is enrolled for the domain of the remote token
+    /// * If the amount was not sent to the contract beforehand
+    /// * If the payment is not the gas quote in the base asset
+    /// * If the amount rounds to zero or overflows when scaled to the remote decimals
+    /// * If any external call fails
+    #[payable]
+    #[storage(read, write)]
+    fn transfer_remote(
+        asset: AssetId,
+        recipient: b256,
+        amount: u64,
+        metadata: Option<Bytes>,
+        hook: Option<ContractId>,
+    ) -> b256 {
+        reentrancy_guard();
+        require_not_paused();
+
+        let remote_token = storage.remote_tokens.get(asset).try_read();
+        require(
+            remote_token
+                .is_some(),
+            MultiAssetWarpRouteError::AssetNotRegistered,
+        );
+        let remote_token = remote_token.unwrap();
+        let destination_domain = remote_token.domain;
+
+        let remote_domain_router = _get_router(destination_domain);
+        require(
+            remote_domain_router != b256::zero(),
+            MultiAssetWarpRouteError::RouterNotSet,
+        );
+
+        let local_decimals = _decimals(storage.decimals, asset).unwrap();
+        let remote_amount = _to_remote_amount(amount, local_decimals, remote_token.decimals);
+        let message_body = _build_token_message_bytes(remote_token.token, recipient, remote_amount);
+
+        let hook_contract = hook.unwrap_or(storage.default_hook.read());
+        let metadata = metadata.unwrap_or(Bytes::new());
+        let quote = _get_quote_for_gas_payment(
+            destination_domain,
+            remote_domain_router,
+            message_body,
+            metadata,
+            hook_contract,
+        );
+
+        //The sub-assets are only held by the contract while they are being transferred
+        require(
+            this_balance(asset) >= amount,
+            MultiAssetWarpRouteError::AssetNotReceivedForTransfer,
+        );
+
+        require(
+            msg_amount() == quote,
+            MultiAssetWarpRouteError::PaymentNotEqualToRequired,
+        );
+
+        require(
+            msg_asset_id() == AssetId::base(),
+            MultiAssetWarpRouteError::InvalidAssetSend,
+        );
+
+        //Burn has checks inside along with decreasing total supply
+        _burn(
+            storage
+                .total_supply,
+            _sub_id(destination_domain, remote_token.token),
+            amount,
+        );
+
+        let mailbox = abi(Mailbox, b256::from(storage.mailbox.read()));
+        let message_id = mailbox.dispatch {
+            coins: quote,
+            asset_id: b256::from(AssetId::base()),
+        }(
+            destination_domain,
+            remote_domain_router,
+            message_body,
+            metadata,
+            hook_contract,
+        );
+
+        log(SentAssetTransferEvent {
+            asset,
+            destination: destination_domain,
+            recipient,
+            amount: remote_amount,
+        });
+
+        message_id
+    }
+
+    /// Gets the router address for a specific domain
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain to query
+    ///
+    /// ### Returns
+    ///
+    /// * [b256] - The router address (zero address if not set)
+    #[storage(read)]
+    fn router(domain: u32) -> b256 {
+        _get_router(domain)
+    }
+
+    /// Enrolls a router for a specific domain
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain to enroll
+    /// * `router`: [b256] - The router address to enroll
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    #[storage(read, write)]
+    fn enroll_remote_router(domain: u32, router: b256) {
+        only_owner();
+        storage.routers.insert(domain, router);
+    }
+
+    /// Gets the mailbox contract ID that the contract is using for transfers
+    ///
+    /// ### Returns
+    ///
+    /// * [ContractId] - The mailbox contract ID
+    #[storage(read)]
+    fn get_mailbox() -> ContractId {
+        storage.mailbox.read()
+    }
+
+    /// Gets the post dispatch hook contract ID that the contract is using
+    ///
+    /// ### Returns
+    ///
+    /// * [ContractId] - The post dispatch hook contract ID
+    #[storage(read)]
+    fn get_hook() -> ContractId {
+        storage.default_hook.read()
+    }
+
+    /// Sets the post dispatch hook contract ID that the contract is using
+    ///
+    /// ### Arguments
+    ///
+    /// * `hook`: [ContractId] - The post dispatch hook contract ID
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    /// * If the hook address is zero
+    #[storage(write)]
+    fn set_hook(hook: ContractId) {
+        only_owner();
+        require(!hook.is_zero(), MultiAssetWarpRouteError::InvalidAddress);
+        storage.default_hook.write(hook);
+    }
+
+    /// Sets the default ISM
+    ///
+    /// ### Arguments
+    ///
+    /// * `module`: [ContractId] - The ISM contract ID
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    #[storage(read, write)]
+    fn set_ism(module: ContractId) {
+        only_owner();
+        storage.default_ism.write(module)
+    }
+
+    /// Gets the quote for gas payment
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination_domain`: [u32] - The destination domain
+    #[storage(read)]
+    fn quote_gas_payment(destination_domain: u32) -> u64 {
+        _get_quote_for_gas_payment(
+            destination_domain,
+            b256::zero(),
+            Bytes::new(),
+            Bytes::new(),
+            storage
+                .default_hook
+                .read(),
+        )
+    }
+}
+
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        _total_assets(storage.total_assets)
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        _total_supply(storage.total_supply, asset)
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        _name(storage.name, asset)
+    }
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        _symbol(storage.symbol, asset)
+    }
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        _decimals(storage.decimals, asset)
+    }
+}
+
+impl MessageRecipient for Contract {
+    /// Handles a transfer from a remote domain by minting the sub-asset of the remote token
+    ///
+    /// ### Arguments
+    ///
+    /// * `origin`: [u32] - The domain of the origin
+    /// * `sender`: [b256] - The address of the sender
+    /// * `message_body`: [bytes] - The message body
+    ///
+    /// ### Reverts
+    ///
+    /// * If the contract is paused
+    /// * If the sender is not the mailbox
+    /// * If the message is not sent by the router enrolled for the origin domain
+    /// * If the remote token is not registered
+    /// * If the amount rounds to zero or exceeds a `u64` when scaled to the local decimals
+    #[storage(read, write)]
+    fn handle(origin: u32, sender: b256, message_body: Bytes) {
+        reentrancy_guard();
+        require_not_paused();
+
+        require(
+            msg_sender()
+                .unwrap() == Identity::ContractId(storage.mailbox.read()),
+            MultiAssetWarpRouteError::SenderNotMailbox,
+        );
+
+        let router = _get_router(origin);
+        require(
+            router != b256::zero() && router == sender,
+            MultiAssetWarpRouteError::SenderNotRouter,
+        );
+
+        let (token, recipient, amount) = _extract_asset_data_from_body(message_body);
+        let sub_id = _sub_id(origin, token);
+        let asset = AssetId::new(ContractId::this(), sub_id);
+
+        let remote_token = storage.remote_tokens.get(asset).try_read();
+        require(
+            remote_token
+                .is_some(),
+            MultiAssetWarpRouteError::AssetNotRegistered,
+        );
+
+        let local_decimals = _decimals(storage.decimals, asset).unwrap();
+        let adjusted_amount = _to_local_amount(amount, remote_token.unwrap().decimals, local_decimals);
+
+        let _ = _mint(
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            Identity::Address(Address::from(recipient)),
+            sub_id,
+            adjusted_amount,
+        );
+
+        log(ReceivedAssetTransferEvent {
+            asset,
+            origin,
+            recipient,
+            amount: adjusted_amount,
+        });
+    }
+
+    #[storage(read)]
+    fn interchain_security_module() -> ContractId {
+        storage.default_ism.read()
+    }
+}
+
+impl Pausable for Contract {
+    #[storage(write)]
+    fn pause() {
+        only_owner();
+        _pause();
+    }
+
+    #[storage(write)]
+    fn unpause() {
+        only_owner();
+        _unpause();
+    }
+
+    #[storage(read)]
+    fn is_paused() -> bool {
+        _is_paused()
+    }
+}
+
+impl Ownable for Contract {
+    #[storage(read)]
+    fn owner() -> State {
+        _owner()
+    }
+
+    #[storage(read)]
+    fn only_owner() {
+        only_owner();
+    }
+
+    #[storage(write)]
+    fn transfer_ownership(new_owner: Identity) {
+        transfer_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn initialize_ownership(new_owner: Identity) {
+        _is_expected_owner(new_owner);
+        initialize_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn renounce_ownership() {
+        renounce_ownership();
+    }
+}
+
+// ------------------------------------------------------------
+// ------------------ Internal Functions ----------------------
+// ------------------------------------------------------------
+
+/// The sub ID of the sub-asset representing `token` of `domain`
+fn _sub_id(domain: u32, token: b256) -> SubId {
+    sha256((domain, token))
+}
+
+fn _build_token_message_bytes(token: b256, recipient: b256, amount: u256) -> Bytes {
+    let mut buffer = Buffer::new();
+
+    buffer = token.abi_encode(buffer);
+    buffer = recipient.abi_encode(buffer);
+    buffer = amount.abi_encode(buffer);
+    Bytes::from(buffer.as_raw_slice())
+}
+
+fn _extract_asset_data_from_body(body: Bytes) -> (b256, b256, u256) {
+    require(
+        body.len() == TOKEN_MESSAGE_LENGTH,
+        MultiAssetWarpRouteError::InvalidMessageBody,
+    );
+    let mut buffer_reader = BufferReader::from_parts(body.ptr(), body.len());
+
+    let token = buffer_reader.read::<b256>();
+    let recipient = buffer_reader.read::<b256>();
+    let amount = buffer_reader.read::<u256>();
+    (token, recipient, amount)
+}
+
+#[storage(read)]
+fn _get_router(domain: u32) -> b256 {
+    storage.routers.get(domain).try_read().unwrap_or(b256::zero())
+}
+
+/// Scales a local amount to the decimals of the remote token, rounding down
+fn _to_remote_amount(amount: u64, local_decimals: u8, remote_decimals: u8) -> u256 {
+    _scale_amount(u256::from(amount), local_decimals, remote_decimals)
+}
+
+/// Scales a remote amount to the local decimals, rounding down.
+/// Fuel assets are limited to `u64`, larger amounts are rejected.
+fn _to_local_amount(amount: u256, remote_decimals: u8, local_decimals: u8) -> u64 {
+    let local_amount = _scale_amount(amount, remote_decimals, local_decimals);
+    require(
+        local_amount <= u256::from(u64::max()),
+        MultiAssetWarpRouteError::AmountExceedsLocalRange,
+    );
+    <u64 as TryFrom<u256>>::try_from(local_amount).unwrap()
+}
+
+/// Scales an amount between decimals.
+/// Zero amounts and amounts which round down to zero are rejected,
+/// instead of transferring nothing or dust as nothing.
+fn _scale_amount(amount: u256, from_decimals: u8, to_decimals: u8) -> u256 {
+    require(
+        amount != u256::zero(),
+        MultiAssetWarpRouteError::AmountRoundsToZero,
+    );
+    if from_decimals == to_decimals {
+        return amount;
+    }
+
+    if from_decimals > to_decimals {
+        let difference = from_decimals - to_decimals;
+        let scaled = if difference > MAX_DECIMALS_DIFFERENCE {
+            u256::zero()
+        } else {
+            amount / _pow10(difference)
+        };
+        require(
+            scaled != u256::zero(),
+            MultiAssetWarpRouteError::AmountRoundsToZero,
+        );
+        scaled
+    } else {
+        let difference = to_decimals - from_decimals;
+        require(
+            difference <= MAX_DECIMALS_DIFFERENCE,
+            MultiAssetWarpRouteError::AmountOverflow,
+        );
+        let factor = _pow10(difference);
+        require(
+            amount <= u256::max() / factor,
+            MultiAssetWarpRouteError::AmountOverflow,
+        );
+        amount * factor
+    }
+}
+
+fn _pow10(exponent: u8) -> u256 {
+    u256::from(10u64).pow(exponent.as_u32())
+}
+
+#[storage(read)]
+fn _get_quote_for_gas_payment(
+    destination_domain: u32,
+    recipient: b256,
+    message_body: Bytes,
+    metadata: Bytes,
+    hook: ContractId,
+) -> u64 {
+    let mailbox = abi(Mailbox, b256::from(storage.mailbox.read()));
+    mailbox.quote_dispatch(destination_domain, recipient, message_body, metadata, hook)
+}
+
+// Front-run guard
+fn _is_expected_owner(owner: Identity) {
+    require(owner.bits() == EXPECTED_OWNER, OwnableError::UnexpectedOwner);
+}
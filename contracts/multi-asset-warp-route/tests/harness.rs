@@ -0,0 +1,534 @@
+#[cfg(test)]
+mod multi_asset_warp_route {
+
+    use fuels::{
+        prelude::*,
+        types::{errors::Error, Bits256, Identity, U256},
+    };
+    use hyperlane_core::{Encode, HyperlaneMessage, H256};
+    use rand::{thread_rng, Rng};
+    use test_utils::{
+        fixtures::dispatched_messages, funded_wallet_with_private_key, get_revert_reason,
+    };
+
+    // Load abi from JSON
+    abigen!(
+        Contract(
+            name = "MultiAssetWarpRoute",
+            abi = "contracts/multi-asset-warp-route/out/debug/multi-asset-warp-route-abi.json"
+        ),
+        Contract(
+            name = "Mailbox",
+            abi = "contracts/mailbox/out/debug/mailbox-abi.json"
+        ),
+    );
+
+    const TEST_NON_OWNER_PRIVATE_KEY: &str =
+        "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
+
+    const REMOTE_ROUTER_ADDRESS: &str =
+        "0x00000000000000000000000000000000000000000000000000000000deadbeef";
+
+    const REMOTE_TOKEN_A: &str =
+        "0x000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+    const REMOTE_TOKEN_B: &str =
+        "0x000000000000000000000000dac17f958d2ee523a2206206994597c13d831ec7";
+
+    const TEST_RECIPIENT: &str =
+        "0x2407159311d2abbf43ef472a9fd20a526abeadb048116b2ab5c93f7d1c733682";
+
+    const TEST_LOCAL_DOMAIN: u32 = 1717982312;
+    const TEST_REMOTE_DOMAIN: u32 = 11155111;
+
+    const DECIMALS: u8 = 9;
+    const REMOTE_DECIMALS_A: u8 = 18;
+    const REMOTE_DECIMALS_B: u8 = 6;
+
+    struct TestContracts {
+        warp_route: MultiAssetWarpRoute<WalletUnlocked>,
+        mailbox: Mailbox<WalletUnlocked>,
+        contract_ids: Vec<Bech32ContractId>,
+        asset_a: AssetId,
+        asset_b: AssetId,
+    }
+
+    fn remote_router() -> Bits256 {
+        Bits256::from_hex_str(REMOTE_ROUTER_ADDRESS).unwrap()
+    }
+
+    fn build_message_body(token: Bits256, recipient: Bits256, amount: U256) -> Vec<u8> {
+        let mut amount_bytes = [0u8; 32];
+        amount.to_big_endian(&mut amount_bytes);
+
+        [token.0, recipient.0, amount_bytes].concat()
+    }
+
+    /// Processes a transfer of `token` sent by `sender`, decoding reverts of the warp route
+    async fn process_transfer_message(
+        contracts: &TestContracts,
+        sender: Bits256,
+        token: Bits256,
+        amount: U256,
+        recipient: Bits256,
+    ) -> std::result::Result<(), Error> {
+        let message = HyperlaneMessage {
+            version: 3u8,
+            nonce: thread_rng().gen_range(0..1000000) as u32,
+            origin: TEST_REMOTE_DOMAIN,
+            sender: H256::from(sender.0),
+            destination: TEST_LOCAL_DOMAIN,
+            recipient: H256::from_slice(contracts.warp_route.contract_id().hash().as_slice()),
+            body: build_message_body(token, recipient, amount),
+        };
+
+        contracts
+            .mailbox
+            .methods()
+            .process(Bytes(message.to_vec()), Bytes(message.to_vec()))
+            .with_contracts(&[&contracts.warp_route])
+            .with_contract_ids(&contracts.contract_ids)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(10))
+            .call()
+            .await
+            .map(|_| ())
+    }
+
+    async fn get_balance(wallet: &WalletUnlocked, asset: AssetId) -> u64 {
+        wallet
+            .provider()
+            .unwrap()
+            .get_asset_balance(wallet.address(), asset)
+            .await
+            .unwrap()
+    }
+
+    // deploy the test contracts with two registered remote tokens
+    async fn get_contract_instance() -> TestContracts {
+        let wallet = launch_provider_and_get_wallet().await.unwrap();
+        let wallet_bits = Bits256(wallet.address().hash().into());
+
+        let configurables = MultiAssetWarpRouteConfigurables::default()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+
+        let warp_route_id = Contract::load_from(
+            "./out/debug/multi-asset-warp-route.bin",
+            LoadConfiguration::default().with_configurables(configurables),
+        )
+        .unwrap()
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+        let configurables = MailboxConfigurables::default()
+            .with_LOCAL_DOMAIN(TEST_LOCAL_DOMAIN)
+            .unwrap()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+
+        let mailbox_id = Contract::load_from(
+            "../mailbox/out/debug/mailbox.bin",
+            LoadConfiguration::default().with_configurables(configurables),
+        )
+        .unwrap()
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+        let post_dispatch_id = Contract::load_from(
+            "../mocks/mock-post-dispatch/out/debug/mock-post-dispatch.bin",
+            LoadConfiguration::default(),
+        )
+        .unwrap()
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+        let default_ism_id = Contract::load_from(
+            "../test/ism-test/out/debug/ism-test.bin",
+            LoadConfiguration::default(),
+        )
+        .unwrap()
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+        let warp_route = MultiAssetWarpRoute::new(warp_route_id.clone(), wallet.clone());
+        let mailbox = Mailbox::new(mailbox_id.clone(), wallet.clone());
+
+        let owner = Identity::from(wallet.address());
+        let mailbox_address = Bits256(ContractId::from(&mailbox_id).into());
+        let hook_address = Bits256(ContractId::from(&post_dispatch_id).into());
+        let default_ism_address = Bits256(ContractId::from(&default_ism_id).into());
+
+        let init_res = warp_route
+            .methods()
+            .initialize(owner, mailbox_address, hook_address, default_ism_address)
+            .call()
+            .await;
+        assert!(init_res.is_ok(), "Failed to initialize Warp Route.");
+
+        let mailbox_init_res = mailbox
+            .methods()
+            .initialize(owner, default_ism_address, hook_address, hook_address)
+            .call()
+            .await;
+        assert!(mailbox_init_res.is_ok(), "Failed to initialize Mailbox.");
+
+        warp_route
+            .methods()
+            .enroll_remote_router(TEST_REMOTE_DOMAIN, remote_router())
+            .call()
+            .await
+            .unwrap();
+
+        let asset_a = register_asset(&warp_route, REMOTE_TOKEN_A, REMOTE_DECIMALS_A, "USD Coin")
+            .await
+            .unwrap();
+        let asset_b = register_asset(&warp_route, REMOTE_TOKEN_B, REMOTE_DECIMALS_B, "Tether")
+            .await
+            .unwrap();
+
+        TestContracts {
+            warp_route,
+            mailbox,
+            contract_ids: vec![warp_route_id, mailbox_id, post_dispatch_id, default_ism_id],
+            asset_a,
+            asset_b,
+        }
+    }
+
+    async fn register_asset(
+        warp_route: &MultiAssetWarpRoute<WalletUnlocked>,
+        token: &str,
+        remote_decimals: u8,
+        name: &str,
+    ) -> std::result::Result<AssetId, Error> {
+        warp_route
+            .methods()
+            .register_asset(
+                TEST_REMOTE_DOMAIN,
+                Bits256::from_hex_str(token).unwrap(),
+                remote_decimals,
+                name.to_string(),
+                name.to_uppercase(),
+                DECIMALS,
+            )
+            .call()
+            .await
+            .map(|response| response.value)
+    }
+
+    /// ============ register_asset ============
+    #[tokio::test]
+    async fn test_register_asset() {
+        let contracts = get_contract_instance().await;
+        let warp_route = &contracts.warp_route;
+        let token_a = Bits256::from_hex_str(REMOTE_TOKEN_A).unwrap();
+
+        assert_ne!(contracts.asset_a, contracts.asset_b);
+
+        let asset = warp_route
+            .methods()
+            .asset_id(TEST_REMOTE_DOMAIN, token_a)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(asset, Some(contracts.asset_a));
+
+        let remote_token = warp_route
+            .methods()
+            .remote_token(contracts.asset_a)
+            .call()
+            .await
+            .unwrap()
+            .value
+            .unwrap();
+        assert_eq!(remote_token.domain, TEST_REMOTE_DOMAIN);
+        assert_eq!(remote_token.token, token_a);
+        assert_eq!(remote_token.decimals, REMOTE_DECIMALS_A);
+
+        let all_assets = warp_route
+            .methods()
+            .all_assets()
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(all_assets, vec![contracts.asset_a, contracts.asset_b]);
+
+        // Each sub-asset exposes its own metadata
+        let total_assets = warp_route
+            .methods()
+            .total_assets()
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(total_assets, 2);
+
+        let name_a = warp_route
+            .methods()
+            .name(contracts.asset_a)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        let symbol_b = warp_route
+            .methods()
+            .symbol(contracts.asset_b)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        let decimals_b = warp_route
+            .methods()
+            .decimals(contracts.asset_b)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(name_a, Some("USD Coin".to_string()));
+        assert_eq!(symbol_b, Some("TETHER".to_string()));
+        assert_eq!(decimals_b, Some(DECIMALS));
+
+        // A token of another domain is a different asset
+        let other_domain_asset = warp_route
+            .methods()
+            .asset_id(TEST_LOCAL_DOMAIN, token_a)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(other_domain_asset, None);
+    }
+
+    #[tokio::test]
+    async fn test_register_asset_twice() {
+        let contracts = get_contract_instance().await;
+
+        let call = register_asset(
+            &contracts.warp_route,
+            REMOTE_TOKEN_A,
+            REMOTE_DECIMALS_A,
+            "USD Coin",
+        )
+        .await;
+
+        assert_eq!(
+            get_revert_reason(call.unwrap_err()),
+            "AssetAlreadyRegistered"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_asset_unauthorized() {
+        let contracts = get_contract_instance().await;
+        let non_owner_wallet = funded_wallet_with_private_key(
+            &contracts.warp_route.account(),
+            TEST_NON_OWNER_PRIVATE_KEY,
+        )
+        .await;
+
+        let call = register_asset(
+            &contracts.warp_route.with_account(non_owner_wallet),
+            "0x0000000000000000000000006b175474e89094c44da98b954eedeac495271d0f",
+            18,
+            "Dai",
+        )
+        .await;
+
+        assert_eq!(get_revert_reason(call.unwrap_err()), "NotOwner");
+    }
+
+    /// ============ handle ============
+    #[tokio::test]
+    async fn test_handle_message() {
+        let contracts = get_contract_instance().await;
+        let wallet = contracts.warp_route.account();
+        let recipient = Bits256(wallet.address().hash().into());
+
+        // 1 token of each remote token, in their own decimals
+        process_transfer_message(
+            &contracts,
+            remote_router(),
+            Bits256::from_hex_str(REMOTE_TOKEN_A).unwrap(),
+            U256::exp10(REMOTE_DECIMALS_A as usize),
+            recipient,
+        )
+        .await
+        .unwrap();
+        process_transfer_message(
+            &contracts,
+            remote_router(),
+            Bits256::from_hex_str(REMOTE_TOKEN_B).unwrap(),
+            U256::exp10(REMOTE_DECIMALS_B as usize),
+            recipient,
+        )
+        .await
+        .unwrap();
+
+        let one_token = 10u64.pow(DECIMALS as u32);
+        assert_eq!(get_balance(&wallet, contracts.asset_a).await, one_token);
+        assert_eq!(get_balance(&wallet, contracts.asset_b).await, one_token);
+
+        let total_supply = contracts
+            .warp_route
+            .methods()
+            .total_supply(contracts.asset_a)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(total_supply, Some(one_token));
+
+        let total_assets = contracts
+            .warp_route
+            .methods()
+            .total_assets()
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(total_assets, 2);
+    }
+
+    #[tokio::test]
+    async fn test_handle_message_unregistered_token() {
+        let contracts = get_contract_instance().await;
+
+        let call = process_transfer_message(
+            &contracts,
+            remote_router(),
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            U256::exp10(18),
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+        )
+        .await;
+
+        assert_eq!(get_revert_reason(call.unwrap_err()), "AssetNotRegistered");
+    }
+
+    #[tokio::test]
+    async fn test_handle_message_sender_not_router() {
+        let contracts = get_contract_instance().await;
+
+        let call = process_transfer_message(
+            &contracts,
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            Bits256::from_hex_str(REMOTE_TOKEN_A).unwrap(),
+            U256::exp10(18),
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+        )
+        .await;
+
+        assert_eq!(get_revert_reason(call.unwrap_err()), "SenderNotRouter");
+    }
+
+    #[tokio::test]
+    async fn test_handle_message_zero_amount() {
+        let contracts = get_contract_instance().await;
+
+        // Scaling up from the remote decimals would keep a zero amount as is
+        let call = process_transfer_message(
+            &contracts,
+            remote_router(),
+            Bits256::from_hex_str(REMOTE_TOKEN_B).unwrap(),
+            U256::zero(),
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+        )
+        .await;
+
+        assert_eq!(get_revert_reason(call.unwrap_err()), "AmountRoundsToZero");
+    }
+
+    /// ============ transfer_remote ============
+    #[tokio::test]
+    async fn test_transfer_remote() {
+        let contracts = get_contract_instance().await;
+        let wallet = contracts.warp_route.account();
+        let token_b = Bits256::from_hex_str(REMOTE_TOKEN_B).unwrap();
+        let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+        let amount = 10u64.pow(DECIMALS as u32);
+
+        process_transfer_message(
+            &contracts,
+            remote_router(),
+            token_b,
+            U256::exp10(REMOTE_DECIMALS_B as usize),
+            Bits256(wallet.address().hash().into()),
+        )
+        .await
+        .unwrap();
+
+        wallet
+            .force_transfer_to_contract(
+                contracts.warp_route.contract_id(),
+                amount,
+                contracts.asset_b,
+                TxPolicies::default(),
+            )
+            .await
+            .unwrap();
+
+        let call = contracts
+            .warp_route
+            .methods()
+            .transfer_remote(contracts.asset_b, recipient, amount, None, None)
+            .with_contracts(&[&contracts.mailbox])
+            .with_contract_ids(&contracts.contract_ids)
+            .call()
+            .await
+            .unwrap();
+
+        let remote_amount = U256::exp10(REMOTE_DECIMALS_B as usize);
+        let events = call
+            .decode_logs_with_type::<SentAssetTransferEvent>()
+            .unwrap();
+        assert_eq!(events[0].asset, contracts.asset_b);
+        assert_eq!(events[0].destination, TEST_REMOTE_DOMAIN);
+        assert_eq!(events[0].amount, remote_amount);
+
+        // The message routes by the remote token
+        let messages = dispatched_messages(&call).unwrap();
+        assert_eq!(messages[0].recipient, H256::from(remote_router().0));
+        assert_eq!(
+            messages[0].body,
+            build_message_body(token_b, recipient, remote_amount)
+        );
+
+        assert_eq!(get_balance(&wallet, contracts.asset_b).await, 0);
+        let total_supply = contracts
+            .warp_route
+            .methods()
+            .total_supply(contracts.asset_b)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(total_supply, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_remote_unregistered_asset() {
+        let contracts = get_contract_instance().await;
+
+        let call = contracts
+            .warp_route
+            .methods()
+            .transfer_remote(
+                AssetId::BASE,
+                Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+                1,
+                None,
+                None,
+            )
+            .with_contract_ids(&contracts.contract_ids)
+            .call()
+            .await;
+
+        assert_eq!(get_revert_reason(call.unwrap_err()), "AssetNotRegistered");
+    }
+}
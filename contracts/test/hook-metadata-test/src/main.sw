@@ -0,0 +1,60 @@
+contract;
+
+use std::bytes::Bytes;
+use std_hook_metadata::*;
+
+abi HookMetadataTest {
+    /// Decodes metadata with `StandardHookMetadata::from_bytes`.
+    fn from_bytes(metadata: Bytes) -> StandardHookMetadata;
+
+    /// Returns whether `StandardHookMetadata::is_valid` accepts the metadata.
+    fn is_valid(metadata: Bytes) -> bool;
+
+    /// Returns the custom metadata read by `StandardHookMetadata::get_custom_metadata`.
+    fn get_custom_metadata(metadata: Bytes) -> Bytes;
+
+    /// Encodes metadata with `StandardHookMetadata::format_metadata`.
+    fn format_metadata(
+        msg_value: u256,
+        gas_limit: u256,
+        refund_address: b256,
+        custom_metadata: Bytes,
+    ) -> Bytes;
+
+    /// Encodes metadata with `StandardHookMetadata::override_gas_limit`.
+    fn override_gas_limit(gas_limit: u256) -> Bytes;
+
+    /// Encodes metadata with `StandardHookMetadata::override_refund_address`.
+    fn override_refund_address(refund_address: b256) -> Bytes;
+}
+
+impl HookMetadataTest for Contract {
+    fn from_bytes(metadata: Bytes) -> StandardHookMetadata {
+        StandardHookMetadata::from_bytes(metadata)
+    }
+
+    fn is_valid(metadata: Bytes) -> bool {
+        StandardHookMetadata::is_valid(metadata)
+    }
+
+    fn get_custom_metadata(metadata: Bytes) -> Bytes {
+        StandardHookMetadata::get_custom_metadata(metadata)
+    }
+
+    fn format_metadata(
+        msg_value: u256,
+        gas_limit: u256,
+        refund_address: b256,
+        custom_metadata: Bytes,
+    ) -> Bytes {
+        StandardHookMetadata::format_metadata(msg_value, gas_limit, refund_address, custom_metadata)
+    }
+
+    fn override_gas_limit(gas_limit: u256) -> Bytes {
+        StandardHookMetadata::override_gas_limit(gas_limit)
+    }
+
+    fn override_refund_address(refund_address: b256) -> Bytes {
+        StandardHookMetadata::override_refund_address(refund_address)
+    }
+}
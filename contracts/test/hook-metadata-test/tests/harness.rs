@@ -0,0 +1,228 @@
+use fuels::{
+    prelude::*,
+    types::{Bits256, Bytes, U256},
+};
+use hyperlane_core::{H256, U256 as HyperlaneU256};
+use test_utils::hook_metadata::{
+    is_valid, validate, HookMetadataError, StandardHookMetadata as RustHookMetadata,
+    MIN_METADATA_LENGTH,
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "HookMetadataTest",
+    abi = "contracts/test/hook-metadata-test/out/debug/hook-metadata-test-abi.json"
+));
+
+async fn get_contract_instance() -> (HookMetadataTest<WalletUnlocked>, WalletUnlocked) {
+    // Launch a local network and deploy the contract
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(
+            Some(1),             /* Single wallet */
+            Some(1),             /* Single coin (UTXO) */
+            Some(1_000_000_000), /* Amount per coin */
+        ),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let wallet = wallets.pop().unwrap();
+
+    let id = Contract::load_from(
+        "./out/debug/hook-metadata-test.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+
+    (HookMetadataTest::new(id, wallet.clone()), wallet)
+}
+
+fn to_fuels_u256(value: HyperlaneU256) -> U256 {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    U256::from_big_endian(&bytes)
+}
+
+fn assert_decoded_identically(sway: &StandardHookMetadata, rust: &RustHookMetadata) {
+    assert_eq!(sway.variant, rust.variant);
+    assert_eq!(sway.msg_value, to_fuels_u256(rust.msg_value));
+    assert_eq!(sway.gas_limit, to_fuels_u256(rust.gas_limit));
+    assert_eq!(sway.refund_address, Bits256(rust.refund_address.0));
+}
+
+fn test_metadata() -> RustHookMetadata {
+    RustHookMetadata::new()
+        .with_msg_value(HyperlaneU256::MAX - 1)
+        .with_gas_limit(300_000u64)
+        .with_refund_address(H256::repeat_byte(0xca))
+        .with_custom_metadata(vec![1, 2, 3, 4, 5])
+}
+
+// ============ Encoding ============
+
+#[tokio::test]
+async fn encode_decode_round_trip() {
+    let metadata = test_metadata();
+    let encoded = metadata.encode();
+
+    assert_eq!(encoded.len(), MIN_METADATA_LENGTH + 5);
+    assert_eq!(RustHookMetadata::decode(&encoded).unwrap(), metadata);
+    assert_eq!(RustHookMetadata::from_bytes(&encoded), metadata);
+}
+
+#[tokio::test]
+async fn sway_decodes_rust_metadata() {
+    let (instance, _) = get_contract_instance().await;
+
+    for metadata in [
+        RustHookMetadata::new(),
+        test_metadata(),
+        test_metadata().with_custom_metadata(vec![]),
+        RustHookMetadata::new().with_gas_limit(HyperlaneU256::MAX),
+    ] {
+        let encoded = metadata.encode();
+
+        let decoded = instance
+            .methods()
+            .from_bytes(Bytes(encoded.clone()))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_decoded_identically(&decoded, &metadata);
+
+        let custom_metadata = instance
+            .methods()
+            .get_custom_metadata(Bytes(encoded))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(custom_metadata.0, metadata.custom_metadata);
+    }
+}
+
+#[tokio::test]
+async fn sway_and_rust_decode_short_metadata_identically() {
+    let (instance, _) = get_contract_instance().await;
+    let encoded = test_metadata().encode();
+
+    // Cut inside each of the fields
+    for length in [0, 1, 2, 33, 34, 65, 66, 97] {
+        let bytes = encoded[..length].to_vec();
+        let rust = RustHookMetadata::from_bytes(&bytes);
+
+        let sway = instance
+            .methods()
+            .from_bytes(Bytes(bytes))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_decoded_identically(&sway, &rust);
+        assert!(rust.custom_metadata.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn format_metadata_matches_sway() {
+    let (instance, wallet) = get_contract_instance().await;
+    let refund_address = H256::repeat_byte(0xca);
+    let custom_metadata = vec![9, 8, 7];
+
+    let sway = instance
+        .methods()
+        .format_metadata(
+            U256::from(10),
+            U256::from(300_000),
+            Bits256(refund_address.0),
+            Bytes(custom_metadata.clone()),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        sway.0,
+        RustHookMetadata::format_metadata(10u64, 300_000u64, refund_address, &custom_metadata)
+    );
+
+    let sway = instance
+        .methods()
+        .override_gas_limit(U256::from(300_000))
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let sender = H256::from(<[u8; 32]>::from(wallet.address().hash()));
+    assert_eq!(
+        sway.0,
+        RustHookMetadata::override_gas_limit(300_000u64, sender)
+    );
+
+    let sway = instance
+        .methods()
+        .override_refund_address(Bits256(refund_address.0))
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        sway.0,
+        RustHookMetadata::override_refund_address(refund_address)
+    );
+}
+
+// ============ Validation ============
+
+#[tokio::test]
+async fn validation_matches_sway() {
+    let (instance, _) = get_contract_instance().await;
+    let encoded = test_metadata().encode();
+
+    let mut wrong_variant = encoded.clone();
+    wrong_variant[1] = 2;
+
+    let cases = [
+        (vec![], Ok(())),
+        (encoded.clone(), Ok(())),
+        (encoded[..MIN_METADATA_LENGTH].to_vec(), Ok(())),
+        (
+            encoded[..MIN_METADATA_LENGTH - 1].to_vec(),
+            Err(HookMetadataError::InvalidLength(MIN_METADATA_LENGTH - 1)),
+        ),
+        (wrong_variant, Err(HookMetadataError::InvalidVariant(2))),
+    ];
+
+    for (bytes, expected) in cases {
+        assert_eq!(validate(&bytes), expected);
+
+        let sway_valid = instance
+            .methods()
+            .is_valid(Bytes(bytes.clone()))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(sway_valid, is_valid(&bytes));
+    }
+}
+
+#[tokio::test]
+async fn decode_rejects_invalid_metadata() {
+    let mut encoded = test_metadata().encode();
+    encoded[1] = 0;
+
+    assert_eq!(
+        RustHookMetadata::decode(&encoded),
+        Err(HookMetadataError::InvalidVariant(0))
+    );
+    assert_eq!(
+        RustHookMetadata::decode(&encoded[..10]),
+        Err(HookMetadataError::InvalidLength(10))
+    );
+}
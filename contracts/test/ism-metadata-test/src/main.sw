@@ -0,0 +1,89 @@
+contract;
+
+use std::bytes::Bytes;
+use aggregation_ism_metadata::AggregationIsmMetadata;
+use merkle_root_multisig_ism_metadata::MerkleRootMultisigIsmMetadata;
+use message_id_multisig_ism_metadata::MessageIdMultisigIsmMetadata;
+
+/// The fixed fields of the message ID multisig metadata as read by the library getters.
+pub struct MessageIdMultisigFields {
+    pub origin_merkle_tree_hook: Bytes,
+    pub root: Bytes,
+    pub index: u32,
+}
+
+/// The fixed fields of the merkle root multisig metadata as read by the library getters.
+pub struct MerkleRootMultisigFields {
+    pub origin_merkle_tree_hook: Bytes,
+    pub message_index: u32,
+    pub signed_message_id: b256,
+    pub proof: [b256; 32],
+    pub signed_index: u32,
+}
+
+abi IsmMetadataTest {
+    /// Returns whether the aggregation metadata has metadata for the ISM at `index`.
+    fn aggregation_has_metadata(metadata: Bytes, index: u8) -> bool;
+
+    /// Returns the aggregation metadata of the ISM at `index`.
+    fn aggregation_metadata_at(metadata: Bytes, index: u8) -> Bytes;
+
+    /// Returns the aggregation metadata of the ISM at `inner_index` in the nested
+    /// aggregation metadata of the ISM at `outer_index`.
+    fn nested_aggregation_metadata_at(metadata: Bytes, outer_index: u8, inner_index: u8) -> Bytes;
+
+    /// Decodes the fixed fields of the message ID multisig metadata.
+    fn message_id_multisig_fields(metadata: Bytes) -> MessageIdMultisigFields;
+
+    /// Returns the signature at `index` of the message ID multisig metadata.
+    fn message_id_multisig_signature_at(metadata: Bytes, index: u32) -> Bytes;
+
+    /// Decodes the fixed fields of the merkle root multisig metadata.
+    fn merkle_root_multisig_fields(metadata: Bytes) -> MerkleRootMultisigFields;
+
+    /// Returns the signature at `index` of the merkle root multisig metadata.
+    fn merkle_root_multisig_signature_at(metadata: Bytes, index: u32) -> Bytes;
+}
+
+impl IsmMetadataTest for Contract {
+    fn aggregation_has_metadata(metadata: Bytes, index: u8) -> bool {
+        AggregationIsmMetadata::new(metadata).has_metadata(index)
+    }
+
+    fn aggregation_metadata_at(metadata: Bytes, index: u8) -> Bytes {
+        AggregationIsmMetadata::new(metadata).metadata_at(index)
+    }
+
+    fn nested_aggregation_metadata_at(metadata: Bytes, outer_index: u8, inner_index: u8) -> Bytes {
+        let inner = AggregationIsmMetadata::new(metadata).metadata_at(outer_index);
+        AggregationIsmMetadata::new(inner).metadata_at(inner_index)
+    }
+
+    fn message_id_multisig_fields(metadata: Bytes) -> MessageIdMultisigFields {
+        let metadata = MessageIdMultisigIsmMetadata::new(metadata);
+        MessageIdMultisigFields {
+            origin_merkle_tree_hook: metadata.origin_merkle_tree_hook(),
+            root: metadata.root(),
+            index: metadata.index(),
+        }
+    }
+
+    fn message_id_multisig_signature_at(metadata: Bytes, index: u32) -> Bytes {
+        MessageIdMultisigIsmMetadata::new(metadata).signature_at(index)
+    }
+
+    fn merkle_root_multisig_fields(metadata: Bytes) -> MerkleRootMultisigFields {
+        let metadata = MerkleRootMultisigIsmMetadata::new(metadata);
+        MerkleRootMultisigFields {
+            origin_merkle_tree_hook: metadata.origin_merkle_tree_hook(),
+            message_index: metadata.message_index(),
+            signed_message_id: metadata.signed_message_id(),
+            proof: metadata.proof(),
+            signed_index: metadata.signed_index(),
+        }
+    }
+
+    fn merkle_root_multisig_signature_at(metadata: Bytes, index: u32) -> Bytes {
+        MerkleRootMultisigIsmMetadata::new(metadata).signature_at(index)
+    }
+}
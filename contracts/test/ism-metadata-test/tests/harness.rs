@@ -0,0 +1,396 @@
+use fuels::{
+    prelude::*,
+    types::{Bits256, Bytes},
+};
+use hyperlane_core::{
+    accumulator::merkle::Proof, Signature as HyperlaneSignature, H256, U256 as HyperlaneU256,
+};
+use test_utils::{
+    bits256_to_h256, get_merkle_test_cases,
+    ism_metadata::{
+        signature_to_bytes, AggregationMetadata, MerkleRootMultisigMetadata,
+        MessageIdMultisigMetadata, MetadataError, RoutingMetadata, SIGNATURE_LENGTH,
+    },
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "IsmMetadataTest",
+    abi = "contracts/test/ism-metadata-test/out/debug/ism-metadata-test-abi.json"
+));
+
+async fn get_contract_instance() -> IsmMetadataTest<WalletUnlocked> {
+    // Launch a local network and deploy the contract
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(
+            Some(1),             /* Single wallet */
+            Some(1),             /* Single coin (UTXO) */
+            Some(1_000_000_000), /* Amount per coin */
+        ),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let wallet = wallets.pop().unwrap();
+
+    let id = Contract::load_from(
+        "./out/debug/ism-metadata-test.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+
+    IsmMetadataTest::new(id, wallet)
+}
+
+fn signatures(count: u8) -> Vec<[u8; SIGNATURE_LENGTH]> {
+    (0..count)
+        .map(|i| {
+            let mut signature = [i + 1; SIGNATURE_LENGTH];
+            signature[64] = 27 + (i % 2);
+            signature
+        })
+        .collect()
+}
+
+fn message_id_metadata() -> MessageIdMultisigMetadata {
+    signatures(3).into_iter().fold(
+        MessageIdMultisigMetadata::new(
+            H256::repeat_byte(0xaa),
+            H256::repeat_byte(0xbb),
+            u32::MAX - 1,
+        ),
+        |metadata, signature| metadata.with_signature(signature),
+    )
+}
+
+fn merkle_test_proof() -> (Proof, H256) {
+    let test_case = get_merkle_test_cases("../merkle-test/tests/test_cases.json")
+        .into_iter()
+        .find(|test_case| test_case.test_name == "forty-two leaves")
+        .unwrap();
+    let merkle_proof = &test_case.proofs[17];
+
+    let mut path = [H256::zero(); 32];
+    for (node, bits) in path.iter_mut().zip(merkle_proof.path.iter()) {
+        *node = bits256_to_h256(*bits);
+    }
+
+    let proof = Proof {
+        leaf: bits256_to_h256(merkle_proof.leaf),
+        index: merkle_proof.index as usize,
+        path,
+    };
+    (proof, bits256_to_h256(test_case.expected_root))
+}
+
+fn merkle_root_metadata() -> MerkleRootMultisigMetadata {
+    let (proof, _) = merkle_test_proof();
+    signatures(2).into_iter().fold(
+        MerkleRootMultisigMetadata::from_proof(
+            H256::repeat_byte(0xcc),
+            &proof,
+            H256::repeat_byte(0xdd),
+            41,
+        ),
+        |metadata, signature| metadata.with_signature(signature),
+    )
+}
+
+// ============ Message Id Multisig ============
+
+#[tokio::test]
+async fn message_id_multisig_round_trip() {
+    let metadata = message_id_metadata();
+    let encoded = metadata.encode();
+
+    assert_eq!(encoded.len(), 68 + 3 * SIGNATURE_LENGTH);
+    assert_eq!(
+        MessageIdMultisigMetadata::decode(&encoded).unwrap(),
+        metadata
+    );
+}
+
+#[tokio::test]
+async fn message_id_multisig_decoded_by_sway() {
+    let instance = get_contract_instance().await;
+    let metadata = message_id_metadata();
+    let encoded = metadata.encode();
+
+    let fields = instance
+        .methods()
+        .message_id_multisig_fields(Bytes(encoded.clone()))
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(
+        fields.origin_merkle_tree_hook.0,
+        metadata.origin_merkle_tree_hook.as_bytes()
+    );
+    assert_eq!(fields.root.0, metadata.root.as_bytes());
+    assert_eq!(fields.index, metadata.index);
+
+    for (index, signature) in metadata.signatures.iter().enumerate() {
+        let sway_signature = instance
+            .methods()
+            .message_id_multisig_signature_at(Bytes(encoded.clone()), index as u32)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(sway_signature.0, signature.to_vec());
+    }
+}
+
+#[tokio::test]
+async fn message_id_multisig_rejects_malformed() {
+    let encoded = message_id_metadata().encode();
+
+    assert_eq!(
+        MessageIdMultisigMetadata::decode(&encoded[..67]),
+        Err(MetadataError::TooShort {
+            expected: 68,
+            actual: 67
+        })
+    );
+    assert_eq!(
+        MessageIdMultisigMetadata::decode(&encoded[..encoded.len() - 1]),
+        Err(MetadataError::InvalidSignaturesLength(
+            3 * SIGNATURE_LENGTH - 1
+        ))
+    );
+}
+
+// ============ Merkle Root Multisig ============
+
+#[tokio::test]
+async fn merkle_root_multisig_round_trip() {
+    let metadata = merkle_root_metadata();
+    let encoded = metadata.encode();
+
+    assert_eq!(encoded.len(), 1096 + 2 * SIGNATURE_LENGTH);
+    assert_eq!(
+        MerkleRootMultisigMetadata::decode(&encoded).unwrap(),
+        metadata
+    );
+}
+
+#[tokio::test]
+async fn merkle_root_multisig_matches_hyperlane_proof() {
+    let (proof, expected_root) = merkle_test_proof();
+    let metadata = MerkleRootMultisigMetadata::decode(&merkle_root_metadata().encode()).unwrap();
+
+    let decoded_proof = Proof {
+        leaf: proof.leaf,
+        index: metadata.message_index as usize,
+        path: metadata.proof,
+    };
+    assert_eq!(decoded_proof.root(), expected_root);
+}
+
+#[tokio::test]
+async fn merkle_root_multisig_decoded_by_sway() {
+    let instance = get_contract_instance().await;
+    let metadata = merkle_root_metadata();
+    let encoded = metadata.encode();
+
+    let fields = instance
+        .methods()
+        .merkle_root_multisig_fields(Bytes(encoded.clone()))
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(
+        fields.origin_merkle_tree_hook.0,
+        metadata.origin_merkle_tree_hook.as_bytes()
+    );
+    assert_eq!(fields.message_index, metadata.message_index);
+    assert_eq!(
+        fields.signed_message_id,
+        Bits256(metadata.signed_message_id.0)
+    );
+    assert_eq!(fields.signed_index, metadata.signed_index);
+    for (sway_node, node) in fields.proof.iter().zip(metadata.proof.iter()) {
+        assert_eq!(*sway_node, Bits256(node.0));
+    }
+
+    for (index, signature) in metadata.signatures.iter().enumerate() {
+        let sway_signature = instance
+            .methods()
+            .merkle_root_multisig_signature_at(Bytes(encoded.clone()), index as u32)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(sway_signature.0, signature.to_vec());
+    }
+}
+
+// ============ Aggregation ============
+
+#[tokio::test]
+async fn aggregation_round_trip() {
+    let metadata = AggregationMetadata::new(3)
+        .with_metadata(0, message_id_metadata().encode())
+        .with_metadata(2, merkle_root_metadata().encode());
+    let encoded = metadata.encode();
+
+    let ranges = metadata.ranges();
+    assert_eq!(ranges[0], (24, 24 + 68 + 3 * SIGNATURE_LENGTH as u32));
+    assert_eq!(ranges[1], (0, 0));
+    assert_eq!(ranges[2].0, ranges[0].1);
+    assert_eq!(ranges[2].1 as usize, encoded.len());
+
+    let decoded = AggregationMetadata::decode(&encoded, 3).unwrap();
+    assert_eq!(decoded, metadata);
+    assert!(!decoded.has_metadata(1));
+    assert_eq!(
+        MessageIdMultisigMetadata::decode(decoded.metadata_at(0).unwrap()).unwrap(),
+        message_id_metadata()
+    );
+    assert_eq!(
+        MerkleRootMultisigMetadata::decode(decoded.metadata_at(2).unwrap()).unwrap(),
+        merkle_root_metadata()
+    );
+}
+
+#[tokio::test]
+async fn aggregation_decoded_by_sway() {
+    let instance = get_contract_instance().await;
+    let metadata = AggregationMetadata::new(3)
+        .with_metadata(0, message_id_metadata().encode())
+        .with_metadata(
+            2,
+            RoutingMetadata::new(merkle_root_metadata().encode()).encode(),
+        );
+    let encoded = metadata.encode();
+
+    for index in 0..3u8 {
+        let has_metadata = instance
+            .methods()
+            .aggregation_has_metadata(Bytes(encoded.clone()), index)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(has_metadata, metadata.has_metadata(index as usize));
+
+        if has_metadata {
+            let sway_metadata = instance
+                .methods()
+                .aggregation_metadata_at(Bytes(encoded.clone()), index)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(
+                sway_metadata.0,
+                metadata.metadata_at(index as usize).unwrap()
+            );
+        }
+    }
+
+    // Indexes past the header have no metadata
+    let has_metadata = instance
+        .methods()
+        .aggregation_has_metadata(Bytes(encoded), 3)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert!(!has_metadata);
+}
+
+#[tokio::test]
+async fn nested_aggregation_decoded_by_sway() {
+    let instance = get_contract_instance().await;
+    let inner = AggregationMetadata::new(2)
+        .with_metadata(1, merkle_root_metadata().encode())
+        .encode();
+    let metadata = AggregationMetadata::new(2)
+        .with_metadata(0, message_id_metadata().encode())
+        .with_metadata(1, inner.clone());
+    let encoded = metadata.encode();
+
+    let decoded_inner = AggregationMetadata::decode(
+        AggregationMetadata::decode(&encoded, 2)
+            .unwrap()
+            .metadata_at(1)
+            .unwrap(),
+        2,
+    )
+    .unwrap();
+    assert_eq!(decoded_inner.encode(), inner);
+
+    let sway_metadata = instance
+        .methods()
+        .nested_aggregation_metadata_at(Bytes(encoded), 1, 1)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(sway_metadata.0, merkle_root_metadata().encode());
+}
+
+#[tokio::test]
+async fn aggregation_rejects_invalid_ranges() {
+    let mut encoded = AggregationMetadata::new(1)
+        .with_metadata(0, vec![1, 2, 3])
+        .encode();
+    // Point the end of the range past the metadata
+    encoded[4..8].copy_from_slice(&64u32.to_be_bytes());
+
+    assert_eq!(
+        AggregationMetadata::decode(&encoded, 1),
+        Err(MetadataError::InvalidRange {
+            index: 0,
+            start: 8,
+            end: 64
+        })
+    );
+    assert_eq!(
+        AggregationMetadata::decode(&encoded[..4], 1),
+        Err(MetadataError::TooShort {
+            expected: 8,
+            actual: 4
+        })
+    );
+}
+
+// ============ Routing ============
+
+#[tokio::test]
+async fn routing_passes_metadata_through() {
+    let inner = message_id_metadata().encode();
+    let metadata = RoutingMetadata::new(inner.clone());
+
+    assert_eq!(metadata.encode(), inner);
+    assert_eq!(RoutingMetadata::decode(&inner), metadata);
+}
+
+// ============ Signatures ============
+
+#[tokio::test]
+async fn signature_packed_as_r_s_v() {
+    let signature = HyperlaneSignature {
+        r: HyperlaneU256::from(1),
+        s: HyperlaneU256::from(2),
+        v: 28,
+    };
+    let bytes = signature_to_bytes(&signature);
+
+    assert_eq!(bytes[31], 1);
+    assert_eq!(bytes[63], 2);
+    assert_eq!(bytes[64], 28);
+
+    let metadata =
+        MessageIdMultisigMetadata::new(H256::zero(), H256::zero(), 0).with_signatures(&[signature]);
+    assert_eq!(metadata.signatures, vec![bytes]);
+}
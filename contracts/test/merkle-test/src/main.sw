@@ -25,6 +25,11 @@ impl TestStorageMerkleTree for Contract {
         storage.tree.get_count()
     }
 
+    #[storage(read)]
+    fn tree() -> MerkleTree {
+        storage.tree.load()
+    }
+
     fn branch_root(leaf: b256, branch: [b256; 32], index: u64) -> b256 {
         StorageMerkleTree::branch_root(leaf, branch, index)
     }
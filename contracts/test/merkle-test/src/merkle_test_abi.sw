@@ -1,5 +1,7 @@
 library;
 
+use merkle::MerkleTree;
+
 // A contract to test the StorageMerkleTree.
 abi TestStorageMerkleTree {
     #[storage(read, write)]
@@ -11,5 +13,8 @@ abi TestStorageMerkleTree {
     #[storage(read)]
     fn get_count() -> u32;
 
+    #[storage(read)]
+    fn tree() -> MerkleTree;
+
     fn branch_root(leaf: b256, branch: [b256; 32], index: u64) -> b256;
 }
@@ -1,6 +1,11 @@
 use fuels::{prelude::*, types::Bits256};
+use hyperlane_core::{accumulator::incremental::IncrementalMerkle, HyperlaneMessage, H256};
 use sha3::{Digest, Keccak256};
-use test_utils::{get_merkle_test_cases, to_eip_191_payload};
+use test_utils::{
+    bits256_to_h256, get_merkle_test_cases, h256_to_bits256,
+    merkle::{branch_root, MerkleTree as RustMerkleTree, MerkleTreeError},
+    to_eip_191_payload,
+};
 
 // Load abi from json
 abigen!(Contract(
@@ -92,3 +97,189 @@ async fn satisfies_test_cases() {
         }
     }
 }
+
+fn message_ids(nonces: std::ops::Range<u32>) -> Vec<H256> {
+    nonces
+        .map(|nonce| {
+            HyperlaneMessage {
+                nonce,
+                body: nonce.to_be_bytes().to_vec(),
+                ..Default::default()
+            }
+            .id()
+        })
+        .collect()
+}
+
+fn eip_191_leaf(leaf: &str) -> H256 {
+    let mut hasher = Keccak256::new();
+    hasher.update(to_eip_191_payload(leaf));
+    H256(hasher.finalize().into())
+}
+
+// ============ Rust Merkle Tree ============
+#[tokio::test]
+async fn rust_tree_satisfies_test_cases() {
+    let test_cases = get_merkle_test_cases("./tests/test_cases.json");
+
+    for case in test_cases.iter() {
+        let tree =
+            RustMerkleTree::from_leaves(case.leaves.iter().map(|leaf| eip_191_leaf(leaf))).unwrap();
+
+        assert_eq!(tree.count(), case.leaves.len() as u32);
+        assert_eq!(tree.root(), bits256_to_h256(case.expected_root));
+
+        for expected in case.proofs.iter() {
+            let proof = tree.proof(expected.index).unwrap();
+            assert_eq!(proof.leaf, bits256_to_h256(expected.leaf));
+            assert_eq!(
+                proof.path.to_vec(),
+                expected
+                    .path
+                    .iter()
+                    .map(|node| bits256_to_h256(*node))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn rust_tree_matches_hyperlane_core() {
+    let mut tree = RustMerkleTree::new();
+    let mut hyperlane_tree = IncrementalMerkle::default();
+
+    assert_eq!(tree.root(), hyperlane_tree.root());
+
+    for (index, id) in message_ids(0..70).into_iter().enumerate() {
+        assert_eq!(tree.ingest(id).unwrap(), index as u32);
+        hyperlane_tree.ingest(id);
+        assert_eq!(tree.root(), hyperlane_tree.root());
+    }
+
+    // Every proof is valid against the latest root
+    for index in 0..tree.count() {
+        assert_eq!(tree.proof(index).unwrap().root(), tree.root());
+    }
+
+    assert_eq!(
+        tree.proof(70),
+        Err(MerkleTreeError::IndexOutOfBounds {
+            index: 70,
+            count: 70
+        })
+    );
+}
+
+#[tokio::test]
+async fn rust_tree_matches_contract() {
+    let (test_merkle, _) = get_contract_instance().await;
+    let ids = message_ids(0..13);
+    let mut tree = RustMerkleTree::new();
+
+    for id in ids.iter() {
+        test_merkle
+            .methods()
+            .insert(h256_to_bits256(*id))
+            .call()
+            .await
+            .unwrap();
+        tree.ingest(*id).unwrap();
+
+        let root = test_merkle
+            .methods()
+            .root()
+            .simulate(Execution::StateReadOnly)
+            .await
+            .unwrap();
+        assert_eq!(bits256_to_h256(root.value), tree.root());
+    }
+
+    // The contract verifies the proof of every leaf
+    for (index, id) in ids.iter().enumerate() {
+        let branch = tree.branch_at(index as u32).unwrap();
+        assert_eq!(branch_root(*id, &branch, index as u32), tree.root());
+
+        let proof_root = test_merkle
+            .methods()
+            .branch_root(
+                h256_to_bits256(*id),
+                branch.map(h256_to_bits256),
+                index as u64,
+            )
+            .simulate(Execution::StateReadOnly)
+            .await
+            .unwrap();
+        assert_eq!(bits256_to_h256(proof_root.value), tree.root());
+    }
+}
+
+#[tokio::test]
+async fn rust_tree_reconstructs_from_contract() {
+    let (test_merkle, _) = get_contract_instance().await;
+    let ids = message_ids(0..21);
+    let (before, after) = ids.split_at(11);
+
+    for id in before.iter() {
+        test_merkle
+            .methods()
+            .insert(h256_to_bits256(*id))
+            .call()
+            .await
+            .unwrap();
+    }
+
+    let stored = test_merkle
+        .methods()
+        .tree()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    let branch: Vec<H256> = stored.branch.into_iter().map(bits256_to_h256).collect();
+    let mut tree = RustMerkleTree::from_branch_slice(&branch, stored.count).unwrap();
+
+    assert_eq!(
+        tree.root(),
+        RustMerkleTree::from_leaves(before.to_vec()).unwrap().root()
+    );
+    assert_eq!(tree.proof(0), Err(MerkleTreeError::UnknownLeaf(0)));
+
+    for id in after.iter() {
+        test_merkle
+            .methods()
+            .insert(h256_to_bits256(*id))
+            .call()
+            .await
+            .unwrap();
+        tree.ingest(*id).unwrap();
+    }
+
+    let root = test_merkle
+        .methods()
+        .root()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(bits256_to_h256(root), tree.root());
+
+    // Leaves ingested after the reconstruction can be proven
+    let full_tree = RustMerkleTree::from_leaves(ids.clone()).unwrap();
+    for index in before.len() as u32..tree.count() {
+        let proof = tree.proof(index).unwrap();
+        assert_eq!(proof, full_tree.proof(index).unwrap());
+
+        let proof_root = test_merkle
+            .methods()
+            .branch_root(
+                h256_to_bits256(proof.leaf),
+                proof.path.map(h256_to_bits256),
+                index as u64,
+            )
+            .simulate(Execution::StateReadOnly)
+            .await
+            .unwrap();
+        assert_eq!(bits256_to_h256(proof_root.value), tree.root());
+    }
+}
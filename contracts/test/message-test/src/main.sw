@@ -0,0 +1,132 @@
+contract;
+
+use std::bytes::Bytes;
+use message::EncodedMessage;
+
+/// The fields of a message as read by the `EncodedMessage` getters.
+pub struct DecodedMessage {
+    pub version: u8,
+    pub nonce: u32,
+    pub origin: u32,
+    pub sender: b256,
+    pub destination: u32,
+    pub recipient: b256,
+    pub body: Bytes,
+}
+
+abi MessageTest {
+    /// Encodes a message with `EncodedMessage::new` and returns the tightly packed bytes.
+    fn encode(
+        version: u8,
+        nonce: u32,
+        origin: u32,
+        sender: b256,
+        destination: u32,
+        recipient: b256,
+        body: Bytes,
+    ) -> Bytes;
+
+    /// Returns the ID of a message built with `EncodedMessage::new`.
+    fn encoded_id(
+        version: u8,
+        nonce: u32,
+        origin: u32,
+        sender: b256,
+        destination: u32,
+        recipient: b256,
+        body: Bytes,
+    ) -> b256;
+
+    /// Decodes tightly packed message bytes with `EncodedMessage::from_bytes`.
+    fn decode(message: Bytes) -> DecodedMessage;
+
+    /// Returns the ID of tightly packed message bytes.
+    fn id(message: Bytes) -> b256;
+
+    /// Decodes and re-encodes tightly packed message bytes.
+    fn round_trip(message: Bytes) -> Bytes;
+}
+
+impl MessageTest for Contract {
+    fn encode(
+        version: u8,
+        nonce: u32,
+        origin: u32,
+        sender: b256,
+        destination: u32,
+        recipient: b256,
+        body: Bytes,
+    ) -> Bytes {
+        EncodedMessage::new(
+            version,
+            nonce,
+            origin,
+            sender,
+            destination,
+            recipient,
+            body,
+        )
+            .message_clean()
+            .bytes
+    }
+
+    fn encoded_id(
+        version: u8,
+        nonce: u32,
+        origin: u32,
+        sender: b256,
+        destination: u32,
+        recipient: b256,
+        body: Bytes,
+    ) -> b256 {
+        EncodedMessage::new(
+            version,
+            nonce,
+            origin,
+            sender,
+            destination,
+            recipient,
+            body,
+        )
+            .id()
+    }
+
+    fn decode(message: Bytes) -> DecodedMessage {
+        let message = EncodedMessage::from_bytes(message);
+        DecodedMessage {
+            version: message.version(),
+            nonce: message.nonce(),
+            origin: message.origin(),
+            sender: message.sender(),
+            destination: message.destination(),
+            recipient: message.recipient(),
+            body: message.body(),
+        }
+    }
+
+    fn id(message: Bytes) -> b256 {
+        EncodedMessage::from_bytes(message).id()
+    }
+
+    fn round_trip(message: Bytes) -> Bytes {
+        let message = EncodedMessage::from_bytes(message);
+        EncodedMessage::new(
+            message
+                .version(),
+            message
+                .nonce(),
+            message
+                .origin(),
+            message
+                .sender(),
+            message
+                .destination(),
+            message
+                .recipient(),
+            message
+                .body(),
+        )
+            .message_clean()
+            .bytes
+    }
+}
@@ -0,0 +1,219 @@
+use fuels::{
+    prelude::*,
+    types::{Bits256, Bytes},
+};
+use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage, H256};
+use proptest::{
+    collection::vec,
+    prelude::*,
+    test_runner::{Config as ProptestConfig, TestCaseError, TestRunner},
+};
+use tokio::runtime::Runtime;
+
+// Load abi from json
+abigen!(Contract(
+    name = "MessageTest",
+    abi = "contracts/test/message-test/out/debug/message-test-abi.json"
+));
+
+/// Same as `MAX_MESSAGE_BODY_BYTES` in the Mailbox.
+const MAX_BODY_BYTES: usize = 2048;
+
+const PROPTEST_CASES: u32 = 64;
+
+async fn get_contract_instance() -> MessageTest<WalletUnlocked> {
+    // Launch a local network and deploy the contract
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(
+            Some(1),               /* Single wallet */
+            Some(1),               /* Single coin (UTXO) */
+            Some(100_000_000_000), /* Amount per coin */
+        ),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let wallet = wallets.pop().unwrap();
+
+    let id = Contract::load_from("./out/debug/message-test.bin", LoadConfiguration::default())
+        .unwrap()
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    MessageTest::new(id, wallet)
+}
+
+// ============ Strategies ============
+
+fn edge_u32() -> impl Strategy<Value = u32> {
+    prop_oneof![
+        Just(0u32),
+        Just(1u32),
+        Just(u32::MAX - 1),
+        Just(u32::MAX),
+        any::<u32>(),
+    ]
+}
+
+fn edge_u8() -> impl Strategy<Value = u8> {
+    prop_oneof![Just(0u8), Just(3u8), Just(u8::MAX), any::<u8>()]
+}
+
+fn edge_b256() -> impl Strategy<Value = [u8; 32]> {
+    prop_oneof![Just([0u8; 32]), Just([u8::MAX; 32]), any::<[u8; 32]>()]
+}
+
+fn body() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        Just(vec![]),
+        vec(any::<u8>(), MAX_BODY_BYTES..=MAX_BODY_BYTES),
+        vec(any::<u8>(), 1..=32),
+        vec(any::<u8>(), 0..=MAX_BODY_BYTES),
+    ]
+}
+
+fn message() -> impl Strategy<Value = HyperlaneMessage> {
+    (
+        edge_u8(),
+        edge_u32(),
+        edge_u32(),
+        edge_b256(),
+        edge_u32(),
+        edge_b256(),
+        body(),
+    )
+        .prop_map(
+            |(version, nonce, origin, sender, destination, recipient, body)| HyperlaneMessage {
+                version,
+                nonce,
+                origin,
+                sender: H256(sender),
+                destination,
+                recipient: H256(recipient),
+                body,
+            },
+        )
+}
+
+// ============ Checks ============
+
+async fn check_message(
+    instance: &MessageTest<WalletUnlocked>,
+    message: &HyperlaneMessage,
+) -> std::result::Result<(), TestCaseError> {
+    let expected_bytes = RawHyperlaneMessage::from(message);
+    let expected_id = Bits256(message.id().0);
+    let methods = instance.methods();
+
+    let encoded = methods
+        .encode(
+            message.version,
+            message.nonce,
+            message.origin,
+            Bits256(message.sender.0),
+            message.destination,
+            Bits256(message.recipient.0),
+            Bytes(message.body.clone()),
+        )
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    prop_assert_eq!(&encoded.0, &expected_bytes, "encode diverged");
+
+    let encoded_id = methods
+        .encoded_id(
+            message.version,
+            message.nonce,
+            message.origin,
+            Bits256(message.sender.0),
+            message.destination,
+            Bits256(message.recipient.0),
+            Bytes(message.body.clone()),
+        )
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    prop_assert_eq!(encoded_id, expected_id, "id of encoded message diverged");
+
+    let id = methods
+        .id(Bytes(expected_bytes.clone()))
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    prop_assert_eq!(id, expected_id, "id of decoded message diverged");
+
+    let decoded = methods
+        .decode(Bytes(expected_bytes.clone()))
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    prop_assert_eq!(decoded.version, message.version);
+    prop_assert_eq!(decoded.nonce, message.nonce);
+    prop_assert_eq!(decoded.origin, message.origin);
+    prop_assert_eq!(decoded.sender, Bits256(message.sender.0));
+    prop_assert_eq!(decoded.destination, message.destination);
+    prop_assert_eq!(decoded.recipient, Bits256(message.recipient.0));
+    prop_assert_eq!(&decoded.body.0, &message.body);
+
+    let round_trip = methods
+        .round_trip(Bytes(expected_bytes.clone()))
+        .simulate(Execution::StateReadOnly)
+        .await
+        .unwrap()
+        .value;
+    prop_assert_eq!(&round_trip.0, &expected_bytes, "round trip diverged");
+
+    Ok(())
+}
+
+// ============ Property Tests ============
+
+#[test]
+fn encoding_matches_hyperlane_core() {
+    let runtime = Runtime::new().unwrap();
+    let instance = runtime.block_on(get_contract_instance());
+
+    let mut runner = TestRunner::new(ProptestConfig::with_cases(PROPTEST_CASES));
+    runner
+        .run(&message(), |message| {
+            runtime.block_on(check_message(&instance, &message))
+        })
+        .unwrap();
+}
+
+#[test]
+fn encoding_matches_hyperlane_core_at_boundaries() {
+    let runtime = Runtime::new().unwrap();
+    let instance = runtime.block_on(get_contract_instance());
+
+    let boundaries = [
+        HyperlaneMessage {
+            version: 0,
+            nonce: 0,
+            origin: 0,
+            sender: H256::zero(),
+            destination: 0,
+            recipient: H256::zero(),
+            body: vec![],
+        },
+        HyperlaneMessage {
+            version: u8::MAX,
+            nonce: u32::MAX,
+            origin: u32::MAX,
+            sender: H256([u8::MAX; 32]),
+            destination: u32::MAX,
+            recipient: H256([u8::MAX; 32]),
+            body: vec![u8::MAX; MAX_BODY_BYTES],
+        },
+    ];
+
+    for message in boundaries.iter() {
+        runtime.block_on(check_message(&instance, message)).unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+contract;
+
+use std::{bytes::Bytes, context::this_balance, storage::storage_bytes::*};
+use interfaces::token_recipient::TokenRecipient;
+
+abi TestTokenRecipient {
+    #[storage(read)]
+    fn handled() -> u64;
+
+    #[storage(read)]
+    fn last_sender() -> b256;
+
+    #[storage(read)]
+    fn last_amount() -> u64;
+
+    #[storage(read)]
+    fn balance_on_handle() -> u64;
+
+    #[storage(read)]
+    fn last_payload() -> Option<Bytes>;
+
+    #[storage(write)]
+    fn set_rejects(rejects: bool);
+}
+
+enum TokenRecipientTestError {
+    Rejected: (),
+}
+
+storage {
+    handled: u64 = 0,
+    last_sender: b256 = b256::zero(),
+    last_amount: u64 = 0,
+    balance_on_handle: u64 = 0,
+    last_payload: StorageBytes = StorageBytes {},
+    rejects: bool = false,
+}
+
+impl TokenRecipient for Contract {
+    #[storage(read, write)]
+    fn handle_transfer(
+        _origin: u32,
+        sender: b256,
+        asset: AssetId,
+        amount: u64,
+        payload: Bytes,
+    ) {
+        require(!storage.rejects.read(), TokenRecipientTestError::Rejected);
+
+        storage.handled.write(storage.handled.read() + 1);
+        storage.last_sender.write(sender);
+        storage.last_amount.write(amount);
+        storage.balance_on_handle.write(this_balance(asset));
+        storage.last_payload.write_slice(payload);
+    }
+}
+
+impl TestTokenRecipient for Contract {
+    #[storage(read)]
+    fn handled() -> u64 {
+        storage.handled.read()
+    }
+
+    #[storage(read)]
+    fn last_sender() -> b256 {
+        storage.last_sender.read()
+    }
+
+    #[storage(read)]
+    fn last_amount() -> u64 {
+        storage.last_amount.read()
+    }
+
+    #[storage(read)]
+    fn balance_on_handle() -> u64 {
+        storage.balance_on_handle.read()
+    }
+
+    #[storage(read)]
+    fn last_payload() -> Option<Bytes> {
+        storage.last_payload.read_slice()
+    }
+
+    #[storage(write)]
+    fn set_rejects(rejects: bool) {
+        storage.rejects.write(rejects);
+    }
+}
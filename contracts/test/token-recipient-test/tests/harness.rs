@@ -0,0 +1,92 @@
+use fuels::{
+    prelude::*,
+    types::{Bits256, Bytes},
+};
+use test_utils::get_revert_reason;
+
+// Load abi from json
+abigen!(Contract(
+    name = "TokenRecipientTest",
+    abi = "contracts/test/token-recipient-test/out/debug/token-recipient-test-abi.json"
+));
+
+const TEST_ORIGIN_DOMAIN: u32 = 11155111;
+
+const TEST_SENDER: &str = "0x2407159311d2abbf43ef472a9fd20a526abeadb048116b2ab5c93f7d1c733682";
+
+async fn get_contract_instance() -> TokenRecipientTest<WalletUnlocked> {
+    // Launch a local network and deploy the contract
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(
+            Some(1),             /* Single wallet */
+            Some(1),             /* Single coin (UTXO) */
+            Some(1_000_000_000), /* Amount per coin */
+        ),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let wallet = wallets.pop().unwrap();
+
+    let id = Contract::load_from(
+        "./out/debug/token-recipient-test.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+
+    TokenRecipientTest::new(id, wallet)
+}
+
+#[tokio::test]
+async fn test_handle_transfer_records_the_transfer() {
+    let recipient = get_contract_instance().await;
+    let sender = Bits256::from_hex_str(TEST_SENDER).unwrap();
+    let payload = Bytes(b"deposit".to_vec());
+
+    recipient
+        .methods()
+        .handle_transfer(
+            TEST_ORIGIN_DOMAIN,
+            sender,
+            AssetId::BASE,
+            100,
+            payload.clone(),
+        )
+        .call()
+        .await
+        .unwrap();
+
+    let methods = recipient.methods();
+    assert_eq!(methods.handled().call().await.unwrap().value, 1);
+    assert_eq!(methods.last_sender().call().await.unwrap().value, sender);
+    assert_eq!(methods.last_amount().call().await.unwrap().value, 100);
+    assert_eq!(
+        methods.last_payload().call().await.unwrap().value,
+        Some(payload)
+    );
+}
+
+#[tokio::test]
+async fn test_handle_transfer_rejects() {
+    let recipient = get_contract_instance().await;
+
+    recipient.methods().set_rejects(true).call().await.unwrap();
+
+    let call = recipient
+        .methods()
+        .handle_transfer(
+            TEST_ORIGIN_DOMAIN,
+            Bits256::from_hex_str(TEST_SENDER).unwrap(),
+            AssetId::BASE,
+            100,
+            Bytes(b"deposit".to_vec()),
+        )
+        .call()
+        .await;
+    assert_eq!(get_revert_reason(call.unwrap_err()), "Rejected");
+    assert_eq!(recipient.methods().handled().call().await.unwrap().value, 0);
+}
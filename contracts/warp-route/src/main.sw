@@ -24,6 +24,7 @@ use sway_libs::{
 
 use std::{
     asset::transfer,
+    block::height,
     bytes::Bytes,
     call_frames::msg_asset_id,
     context::{
@@ -49,8 +50,23 @@ use interfaces::{
     token_router::*,
     warp_route::*,
     gas_router::*,
+    rate_limited::*,
+    token_recipient::TokenRecipient,
+};
+use standards::{
+    src20::{
+        SetDecimalsEvent,
+        SetNameEvent,
+        SetSymbolEvent,
+        SRC20,
+        TotalSupplyEvent,
+    },
+    src5::State,
+    src7::{
+        Metadata,
+        SRC7,
+    },
 };
-use standards::{src20::SRC20, src5::State};
 use message::{EncodedMessage, Message};
 use std_hook_metadata::*;
 
@@ -87,12 +103,30 @@ storage {
     symbol: StorageMap<AssetId, StorageString> = StorageMap {},
     /// The mapping of asset ID to the number of decimals of the token
     decimals: StorageMap<AssetId, u8> = StorageMap {},
-    /// The contract balance of the asset
+    /// The balance of the asset locked as collateral by transfers
     contract_balance: u64 = 0,
+    /// The fee retained on transfers in basis points, only charged in `COLLATERAL_FEE` mode
+    fee_bps: u64 = 0,
+    /// The retained fees which have not been claimed yet
+    accrued_fees: u64 = 0,
     /// Gas for domains
     destination_gas: StorageMap<u32, u64> = StorageMap {},
     /// The collateral token contract address of the Warp Route
     wrapped_token: ContractId = ContractId::zero(),
+    /// Recipients of incoming transfers which are contracts rather than addresses
+    contract_recipients: StorageMap<b256, bool> = StorageMap {},
+    /// Rate limits of transfers received from each domain
+    inbound_rate_limits: StorageMap<u32, RateLimit> = StorageMap {},
+    /// Rate limits of transfers sent to each domain
+    outbound_rate_limits: StorageMap<u32, RateLimit> = StorageMap {},
+    /// The domain the synthetic asset originates from, exposed as SRC-7 metadata
+    origin_domain: u32 = 0,
+    /// The address of the token the synthetic asset represents on its origin domain
+    origin_token: b256 = b256::zero(),
+    /// The assets deposited by each sender with `deposit` which are not transferred yet
+    deposits: StorageMap<Identity, u64> = StorageMap {},
+    /// The sum of the deposits, which is not available to other senders
+    total_deposits: u64 = 0,
 }
 
 configurable {
@@ -102,6 +136,15 @@ configurable {
     EXPECTED_OWNER: b256 = b256::zero(),
 }
 
+/// The largest exponent for which a power of ten fits in a `u256`
+const MAX_DECIMALS_DIFFERENCE: u8 = 77;
+/// The length of the recipient and amount of a transfer message, followed by the payload
+const TOKEN_MESSAGE_LENGTH: u64 = 64;
+/// The length of the sender which precedes the payload of a transfer message
+const PAYLOAD_SENDER_LENGTH: u64 = 32;
+/// The denominator of fees in basis points
+const BPS_DENOMINATOR: u64 = 10_000;
+
 impl WarpRoute for Contract {
     /// Initializes the WarpRoute contract
     ///
@@ -184,8 +227,16 @@ impl WarpRoute for Contract {
                         total_supply,
                     );
                 }
+
+                // The warp route is the issuer of the synthetic asset
+                storage.total_assets.write(1);
+                let sender = msg_sender().unwrap();
+                SetNameEvent::new(asset_id, _name(storage.name, asset_id), sender).log();
+                SetSymbolEvent::new(asset_id, _symbol(storage.symbol, asset_id), sender).log();
+                SetDecimalsEvent::new(asset_id, decimals.unwrap(), sender).log();
+                _log_total_supply(asset_id);
             }
-            WarpRouteTokenMode::COLLATERAL => {
+            WarpRouteTokenMode::COLLATERAL | WarpRouteTokenMode::COLLATERAL_FEE => {
                 // Require asset_id and asset_contract_id as input in collateral mode
                 require(
                     asset_id
@@ -222,6 +273,8 @@ impl WarpRoute for Contract {
     /// * If reentrancy is detected
     /// * If the amount provided is greater than amount sent
     /// * If the asset ID of the asset being transferred is not the same as the asset ID set on the contract
+    /// * If the amount is zero, or rounds to zero or overflows when scaled to the remote decimals
+    /// * If the amount exceeds the outbound rate limit of the destination domain
     /// * If any external call fails
     #[payable]
     #[storage(read, write)]
@@ -232,101 +285,50 @@ impl WarpRoute for Contract {
         metadata: Option<Bytes>,
         hook: Option<ContractId>,
     ) -> b256 {
-        reentrancy_guard();
-        require_not_paused();
-
-        let remote_domain_router = _get_router(destination_domain);
-        require(
-            remote_domain_router != b256::zero(),
-            TokenRouterError::RouterNotSet,
-        );
-
-        let remote_decimals = _get_remote_router_decimals(remote_domain_router);
-        require(remote_decimals != 0, WarpRouteError::RemoteDecimalsNotSet);
-
-        let asset = storage.asset_id.read();
-        let mailbox = abi(Mailbox, b256::from(storage.mailbox.read()));
-        let default_hook = storage.default_hook.read();
-        let hook_contract = hook.unwrap_or(default_hook);
-
-        let local_decimals = _decimals(storage.decimals, asset).unwrap();
-        let adjusted_amount = _adjust_decimals(amount, local_decimals, remote_decimals);
-        let message_body = _build_token_metadata_bytes(recipient, adjusted_amount);
-
-        let token_mode = storage.token_mode.read();
-
-        let quote = _get_quote_for_gas_payment(
+        _transfer_remote(
             destination_domain,
-            remote_domain_router,
-            message_body,
+            recipient,
+            amount,
+            None,
             metadata,
-            hook_contract,
-        );
-
-        let required_payment = match token_mode {
-            WarpRouteTokenMode::SYNTHETIC => quote,
-            WarpRouteTokenMode::COLLATERAL => quote,
-            WarpRouteTokenMode::NATIVE => amount + quote,
-        };
-
-        //Check if the asset is sent to the contract before transferring
-        let stored_balance = storage.contract_balance.read();
-        let current_contract_balance = this_balance(asset);
-
-        //Calculate the required contract balance - Native is sent with the gas quote not beforehand
-        let required_contract_balance = stored_balance + amount;
-
-        require(
-            current_contract_balance >= required_contract_balance,
-            WarpRouteError::AssetNotReceivedForTransfer,
-        );
-
-        require(
-            msg_amount() == required_payment,
-            WarpRouteError::PaymentNotEqualToRequired,
-        );
-
-        require(
-            msg_asset_id() == AssetId::base(),
-            WarpRouteError::InvalidAssetSend,
-        );
-
-        match token_mode {
-            WarpRouteTokenMode::SYNTHETIC => {
-                //Burn has checks inside along with decreasing total supply
-                _burn(storage.total_supply, storage.sub_id.read(), amount);
-            },
-            WarpRouteTokenMode::NATIVE | WarpRouteTokenMode::COLLATERAL => {
-                //Locked in the contract
-                transfer(Identity::ContractId(ContractId::this()), asset, amount);
-                storage.contract_balance.write(current_contract_balance);
-            },
-        }
-
-        let metadata = match metadata {
-            Some(metadata) => metadata,
-            None => _gas_router_hook_metadata(destination_domain),
-        };
+            hook,
+        )
+    }
 
-        //Dispatch the message to the destination domain
-        let message_id = mailbox.dispatch {
-            coins: quote,
-            asset_id: b256::from(AssetId::base()),
-        }(
+    /// Transfers tokens to a remote domain along with a payload,
+    /// which is forwarded to the recipient contract once the tokens are credited.
+    /// The sender of the transfer is encoded before the payload and passed to the recipient.
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination_domain`: [u32] - The domain to transfer the tokens to
+    /// * `recipient`: [b256] - The address of the recipient contract
+    /// * `amount`: [u64] - The amount of tokens to transfer
+    /// * `payload`: [Bytes] - The payload appended to the transfer
+    ///
+    /// ### Reverts
+    ///
+    /// * If the payload is empty
+    /// * In the same cases as `transfer_remote`
+    #[payable]
+    #[storage(read, write)]
+    fn transfer_remote_with_payload(
+        destination_domain: u32,
+        recipient: b256,
+        amount: u64,
+        payload: Bytes,
+        metadata: Option<Bytes>,
+        hook: Option<ContractId>,
+    ) -> b256 {
+        require(payload.len() > 0, WarpRouteError::EmptyPayload);
+        _transfer_remote(
             destination_domain,
-            remote_domain_router,
-            message_body,
-            metadata,
-            hook_contract,
-        );
-
-        log(SentTransferRemoteEvent {
-            destination: destination_domain,
             recipient,
-            amount: adjusted_amount,
-        });
-
-        message_id
+            amount,
+            Some(payload),
+            metadata,
+            hook,
+        )
     }
 
     /// Gets the token mode of the WarpRoute contract
@@ -466,10 +468,32 @@ impl WarpRoute for Contract {
         let beneficiary = storage.beneficiary.read();
         let stored_asset = storage.asset_id.read();
         let asset = asset.unwrap_or(stored_asset);
-        let balance = this_balance(asset);
+        let mut balance = this_balance(asset);
 
         if stored_asset == asset {
-            storage.contract_balance.write(0);
+            // The deposits belong to their senders
+            let deposits = storage.total_deposits.read();
+            balance = if balance > deposits {
+                balance - deposits
+            } else {
+                0
+            };
+
+            match storage.token_mode.read() {
+                // The collateral backing remote transfers stays locked, and unaccounted
+                // assets may still be used by a pending transfer, so only the fees are claimed
+                WarpRouteTokenMode::COLLATERAL_FEE => {
+                    let locked = storage.contract_balance.read();
+                    let unlocked = if balance > locked { balance - locked } else { 0 };
+                    let fees = storage.accrued_fees.read();
+                    balance = if unlocked > fees { fees } else { unlocked };
+                    storage.accrued_fees.write(fees - balance);
+                },
+                _ => {
+                    storage.contract_balance.write(0);
+                    storage.accrued_fees.write(0);
+                },
+            }
         }
 
         transfer(beneficiary, asset, balance);
@@ -478,6 +502,346 @@ impl WarpRoute for Contract {
             amount: balance,
         });
     }
+
+    /// Sets whether incoming transfers to `recipient` are delivered to the contract
+    /// with that ID instead of the address.
+    ///
+    /// ### Arguments
+    ///
+    /// * `recipient`: [b256] - The recipient as encoded in the message body
+    /// * `is_contract`: [bool] - Whether the recipient is a contract
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    #[storage(read, write)]
+    fn set_contract_recipient(recipient: b256, is_contract: bool) {
+        only_owner();
+        storage.contract_recipients.insert(recipient, is_contract);
+        log(ContractRecipientSetEvent {
+            recipient,
+            is_contract,
+        });
+    }
+
+    /// Gets whether incoming transfers to `recipient` are delivered to a contract
+    ///
+    /// ### Arguments
+    ///
+    /// * `recipient`: [b256] - The recipient as encoded in the message body
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - Whether the recipient is a contract
+    #[storage(read)]
+    fn is_contract_recipient(recipient: b256) -> bool {
+        _is_contract_recipient(recipient)
+    }
+
+    /// Sets the fee retained on transfers in `COLLATERAL_FEE` mode
+    ///
+    /// ### Arguments
+    ///
+    /// * `fee_bps`: [u64] - The fee in basis points of the transferred amount
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    /// * If the fee is 10000 basis points or more, which would bridge nothing
+    #[storage(read, write)]
+    fn set_fee(fee_bps: u64) {
+        only_owner();
+        require(fee_bps < BPS_DENOMINATOR, WarpRouteError::FeeTooHigh);
+        storage.fee_bps.write(fee_bps);
+        log(FeeSetEvent { fee_bps });
+    }
+
+    /// Gets the fee retained on transfers in `COLLATERAL_FEE` mode
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The fee in basis points
+    #[storage(read)]
+    fn fee() -> u64 {
+        storage.fee_bps.read()
+    }
+
+    /// Gets the retained fees which can be claimed by the beneficiary
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The accrued fees
+    #[storage(read)]
+    fn accrued_fees() -> u64 {
+        storage.accrued_fees.read()
+    }
+
+    /// Gets the balance locked as collateral by transfers
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The collateral balance
+    #[storage(read)]
+    fn collateral_balance() -> u64 {
+        storage.contract_balance.read()
+    }
+
+    /// Reconciles the accounted balances with the balance of the contract in `COLLATERAL_FEE` mode.
+    /// A shortfall, e.g. after a rebase of the collateral asset, reduces the accrued fees first
+    /// and then the collateral balance. A surplus is only reported: it cannot be told apart from
+    /// assets sent for a pending transfer, so it stays available to `transfer_remote`.
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    /// * If the WarpRoute is not in `COLLATERAL_FEE` mode
+    #[storage(read, write)]
+    fn reconcile() {
+        only_owner();
+        let supported = match storage.token_mode.read() {
+            WarpRouteTokenMode::COLLATERAL_FEE => true,
+            _ => false,
+        };
+        require(supported, WarpRouteError::ReconcileNotSupported);
+
+        // The deposits are excluded, they can still be used or refunded by their senders
+        let deposits = storage.total_deposits.read();
+        let mut balance = this_balance(storage.asset_id.read());
+        balance = if balance > deposits {
+            balance - deposits
+        } else {
+            0
+        };
+        let collateral = storage.contract_balance.read();
+        let fees = storage.accrued_fees.read();
+        let accounted = collateral + fees;
+
+        let mut surplus = 0;
+        let mut shortfall = 0;
+        if balance > accounted {
+            surplus = balance - accounted;
+        } else if balance < accounted {
+            shortfall = accounted - balance;
+            if shortfall <= fees {
+                storage.accrued_fees.write(fees - shortfall);
+            } else {
+                storage.accrued_fees.write(0);
+                storage.contract_balance.write(balance);
+            }
+        }
+
+        log(BalanceReconciledEvent {
+            surplus,
+            shortfall,
+        });
+    }
+
+    /// Sets the remote token the synthetic asset represents, exposed as SRC-7 metadata
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The domain the token originates from
+    /// * `token`: [b256] - The address of the token on its origin domain
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    #[storage(read, write)]
+    fn set_origin_token(domain: u32, token: b256) {
+        only_owner();
+        storage.origin_domain.write(domain);
+        storage.origin_token.write(token);
+        log(OriginTokenSetEvent { domain, token });
+    }
+
+    /// Deposits the asset of the WarpRoute for a later `transfer_remote` by the sender.
+    /// Unlike assets sent to the contract directly, deposits can only be used
+    /// by their sender and can be refunded.
+    ///
+    /// ### Reverts
+    ///
+    /// * If the contract is paused
+    /// * If the WarpRoute is in native mode, where the asset is sent with `transfer_remote`
+    /// * If the asset sent is not the asset of the WarpRoute
+    /// * If no asset is sent
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        require_not_paused();
+        let supported = match storage.token_mode.read() {
+            WarpRouteTokenMode::NATIVE => false,
+            _ => true,
+        };
+        require(supported, WarpRouteError::DepositNotSupported);
+        require(
+            msg_asset_id() == storage.asset_id.read(),
+            WarpRouteError::InvalidAssetSend,
+        );
+        let amount = msg_amount();
+        require(amount > 0, WarpRouteError::InvalidAssetSend);
+
+        let depositor = msg_sender().unwrap();
+        let deposit = _deposit_of(depositor) + amount;
+        storage.deposits.insert(depositor, deposit);
+        storage.total_deposits.write(storage.total_deposits.read() + amount);
+
+        log(DepositEvent {
+            depositor,
+            amount,
+        });
+    }
+
+    /// Refunds the deposit of the sender which has not been transferred
+    ///
+    /// ### Reverts
+    ///
+    /// * If reentrancy is detected
+    /// * If the sender has no deposit
+    #[storage(read, write)]
+    fn refund_deposit() {
+        reentrancy_guard();
+
+        let depositor = msg_sender().unwrap();
+        let amount = _deposit_of(depositor);
+        require(amount > 0, WarpRouteError::NoDeposit);
+
+        let _ = storage.deposits.remove(depositor);
+        storage.total_deposits.write(storage.total_deposits.read() - amount);
+        transfer(depositor, storage.asset_id.read(), amount);
+
+        log(DepositRefundedEvent {
+            depositor,
+            amount,
+        });
+    }
+
+    /// Gets the deposit of a sender which has not been transferred
+    ///
+    /// ### Arguments
+    ///
+    /// * `depositor`: [Identity] - The sender of the deposit
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The deposited amount
+    #[storage(read)]
+    fn deposit_of(depositor: Identity) -> u64 {
+        _deposit_of(depositor)
+    }
+}
+
+impl SRC20 for Contract {
+    /// Gets the number of assets issued by the WarpRoute contract,
+    /// only the synthetic asset is issued by it
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The number of assets
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        _total_assets(storage.total_assets)
+    }
+
+    /// Gets the total supply of the synthetic asset
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to query
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<u64>] - The total supply, or None if the asset is not issued by the contract
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        if !_is_synthetic_asset(asset) {
+            return None;
+        }
+        _total_supply(storage.total_supply, asset)
+    }
+
+    /// Gets the name of the synthetic asset
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to query
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<String>] - The name, or None if the asset is not issued by the contract
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        if !_is_synthetic_asset(asset) {
+            return None;
+        }
+        _name(storage.name, asset)
+    }
+
+    /// Gets the symbol of the synthetic asset
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to query
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<String>] - The symbol, or None if the asset is not issued by the contract
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        if !_is_synthetic_asset(asset) {
+            return None;
+        }
+        _symbol(storage.symbol, asset)
+    }
+
+    /// Gets the decimals of the synthetic asset
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to query
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<u8>] - The decimals, or None if the asset is not issued by the contract
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        if !_is_synthetic_asset(asset) {
+            return None;
+        }
+        _decimals(storage.decimals, asset)
+    }
+}
+
+impl SRC7 for Contract {
+    /// Gets the metadata of the synthetic asset describing where it is bridged from.
+    /// The supported keys are `origin_chain`, `origin_token` and `bridge`.
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to query
+    /// * `key`: [String] - The metadata key
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<Metadata>] - The metadata, or None if the key is not set for the asset
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        if !_is_synthetic_asset(asset) {
+            return None;
+        }
+
+        let origin_domain = storage.origin_domain.read();
+        if key == String::from_ascii_str("bridge") {
+            Some(Metadata::String(String::from_ascii_str("Hyperlane")))
+        } else if origin_domain == 0 {
+            None
+        } else if key == String::from_ascii_str("origin_chain") {
+            Some(Metadata::Int(origin_domain.as_u64()))
+        } else if key == String::from_ascii_str("origin_token") {
+            Some(Metadata::B256(storage.origin_token.read()))
+        } else {
+            None
+        }
+    }
 }
 
 impl TokenRouter for Contract {
@@ -687,8 +1051,84 @@ impl GasRouter for Contract {
     }
 }
 
+impl RateLimited for Contract {
+    /// Sets the rate limit for transfers to or from a domain.
+    /// A capacity of zero removes the limit.
+    /// The limit refills by `refill_rate` every block, and outbound limits count
+    /// the whole amount of a transfer including the fee.
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The remote domain
+    /// * `direction`: [RateLimitDirection] - The direction of the limited transfers
+    /// * `capacity`: [u64] - The maximum amount which can be transferred at once
+    /// * `refill_rate`: [u64] - The amount restored per block
+    ///
+    /// ### Reverts
+    ///
+    /// * If the caller is not the owner
+    #[storage(read, write)]
+    fn set_rate_limit(
+        domain: u32,
+        direction: RateLimitDirection,
+        capacity: u64,
+        refill_rate: u64,
+    ) {
+        only_owner();
+
+        if capacity == 0 {
+            _remove_rate_limit(domain, direction);
+        } else {
+            // Keep the amount already consumed, a new limit starts full
+            let level = match _rate_limit(domain, direction) {
+                Some(limit) => if limit.level < capacity {
+                    limit.level
+                } else {
+                    capacity
+                },
+                None => capacity,
+            };
+            _write_rate_limit(
+                domain,
+                direction,
+                RateLimit {
+                    capacity,
+                    refill_rate,
+                    level,
+                    last_updated: height(),
+                },
+            );
+        }
+
+        log(RateLimitSetEvent {
+            domain,
+            direction,
+            capacity,
+            refill_rate,
+        });
+    }
+
+    /// Gets the rate limit for transfers to or from a domain, refilled up to the current block
+    ///
+    /// ### Arguments
+    ///
+    /// * `domain`: [u32] - The remote domain
+    /// * `direction`: [RateLimitDirection] - The direction of the limited transfers
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<RateLimit>] - The rate limit, or None if the transfers are not limited
+    #[storage(read)]
+    fn rate_limit(domain: u32, direction: RateLimitDirection) -> Option<RateLimit> {
+        _rate_limit(domain, direction)
+    }
+}
+
 impl MessageRecipient for Contract {
-    /// Handles a transfer from a remote domain
+    /// Handles a transfer from a remote domain.
+    /// Recipients registered with `set_contract_recipient` receive the tokens as a contract,
+    /// and the payload of the transfer, if any, through `TokenRecipient::handle_transfer`.
+    /// Other recipients receive the tokens as an address and the payload is dropped.
     ///
     /// ### Arguments
     ///
@@ -702,6 +1142,9 @@ impl MessageRecipient for Contract {
     /// * If the message has already been delivered
     /// * If the sender is not the mailbox
     /// * If the cumulative supply exceeds the maximum supply
+    /// * If the amount rounds to zero or exceeds a `u64` when scaled to the local decimals
+    /// * If the amount exceeds the inbound rate limit of the origin domain
+    /// * If the payload callback of the recipient reverts
     #[storage(read, write)]
     fn handle(origin: u32, sender: b256, message_body: Bytes) {
         reentrancy_guard();
@@ -714,15 +1157,20 @@ impl MessageRecipient for Contract {
         );
 
         let asset = storage.asset_id.read();
-        let (recipient, amount) = _extract_asset_data_from_body(message_body);
-        let recipient_identity = Identity::Address(Address::from(recipient));
+        let (recipient, amount, transfer_payload) = _extract_asset_data_from_body(message_body);
+        let recipient_identity = if _is_contract_recipient(recipient) {
+            Identity::ContractId(ContractId::from(recipient))
+        } else {
+            Identity::Address(Address::from(recipient))
+        };
 
         let remote_decimals = _get_remote_router_decimals(sender);
         require(remote_decimals != 0, WarpRouteError::RemoteDecimalsNotSet);
 
         let local_decimals = _decimals(storage.decimals, asset).unwrap();
-        let adjusted_amount = _adjust_decimals(amount, remote_decimals, local_decimals);
-        let asset = storage.asset_id.read();
+        let adjusted_amount = _to_local_amount(amount, remote_decimals, local_decimals);
+
+        _consume_rate_limit(origin, RateLimitDirection::Inbound, adjusted_amount);
 
         match storage.token_mode.read() {
             WarpRouteTokenMode::SYNTHETIC => {
@@ -737,10 +1185,27 @@ impl MessageRecipient for Contract {
                         .read(),
                     adjusted_amount,
                 );
+                _log_total_supply(asset);
             }
-            WarpRouteTokenMode::NATIVE | WarpRouteTokenMode::COLLATERAL => {
+            WarpRouteTokenMode::NATIVE | WarpRouteTokenMode::COLLATERAL | WarpRouteTokenMode::COLLATERAL_FEE => {
                 transfer(recipient_identity, asset, adjusted_amount);
-                storage.contract_balance.write(this_balance(asset));
+
+                // Retained fees and deposits are not collateral and cannot be paid out
+                let accrued_fees = storage.accrued_fees.read();
+                require(
+                    this_balance(asset) >= accrued_fees + storage
+                        .total_deposits
+                        .read(),
+                    WarpRouteError::InsufficientCollateral,
+                );
+
+                let stored_balance = storage.contract_balance.read();
+                let remaining_balance = if stored_balance > adjusted_amount {
+                    stored_balance - adjusted_amount
+                } else {
+                    0
+                };
+                storage.contract_balance.write(remaining_balance);
             }
         }
 
@@ -749,6 +1214,21 @@ impl MessageRecipient for Contract {
             recipient,
             amount: adjusted_amount,
         });
+
+        // The callback runs once the tokens are credited, if it reverts the message is not delivered.
+        // Only registered contracts are called back, so an unregistered recipient cannot block delivery
+        if let Some((transfer_sender, payload)) = transfer_payload {
+            if _is_contract_recipient(recipient) {
+                let token_recipient = abi(TokenRecipient, recipient);
+                token_recipient.handle_transfer(origin, transfer_sender, asset, adjusted_amount, payload);
+            } else {
+                log(PayloadDroppedEvent {
+                    origin,
+                    sender: transfer_sender,
+                    recipient,
+                });
+            }
+        }
     }
 
     #[storage(read)]
@@ -820,24 +1300,312 @@ fn _get_metadata_of_asset(asset: AssetId) -> TokenMetadata {
     }
 }
 
-fn _build_token_metadata_bytes(recipient: b256, amount: u64) -> Bytes {
+#[storage(read, write)]
+fn _transfer_remote(
+    destination_domain: u32,
+    recipient: b256,
+    amount: u64,
+    payload: Option<Bytes>,
+    metadata: Option<Bytes>,
+    hook: Option<ContractId>,
+) -> b256 {
+    reentrancy_guard();
+    require_not_paused();
+
+    let remote_domain_router = _get_router(destination_domain);
+    require(
+        remote_domain_router != b256::zero(),
+        TokenRouterError::RouterNotSet,
+    );
+
+    let remote_decimals = _get_remote_router_decimals(remote_domain_router);
+    require(remote_decimals != 0, WarpRouteError::RemoteDecimalsNotSet);
+
+    // The whole amount is limited, including the fee retained in `COLLATERAL_FEE` mode
+    _consume_rate_limit(destination_domain, RateLimitDirection::Outbound, amount);
+
+    let asset = storage.asset_id.read();
+    let mailbox = abi(Mailbox, b256::from(storage.mailbox.read()));
+    let default_hook = storage.default_hook.read();
+    let hook_contract = hook.unwrap_or(default_hook);
+
+    let token_mode = storage.token_mode.read();
+
+    // The fee is retained from the amount, the rest is bridged
+    let fee = match token_mode {
+        WarpRouteTokenMode::COLLATERAL_FEE => _transfer_fee(amount),
+        _ => 0,
+    };
+    let bridged_amount = amount - fee;
+
+    let sender = msg_sender().unwrap();
+    let transfer_payload = match payload {
+        Some(payload) => Some((sender.bits(), payload)),
+        None => None,
+    };
+
+    let local_decimals = _decimals(storage.decimals, asset).unwrap();
+    let remote_amount = _to_remote_amount(bridged_amount, local_decimals, remote_decimals);
+    let message_body = _build_token_metadata_bytes(recipient, remote_amount, transfer_payload);
+
+    let quote = _get_quote_for_gas_payment(
+        destination_domain,
+        remote_domain_router,
+        message_body,
+        metadata,
+        hook_contract,
+    );
+
+    let required_payment = match token_mode {
+        WarpRouteTokenMode::SYNTHETIC => quote,
+        WarpRouteTokenMode::COLLATERAL => quote,
+        WarpRouteTokenMode::COLLATERAL_FEE => quote,
+        WarpRouteTokenMode::NATIVE => amount + quote,
+    };
+
+    //Check if the asset is sent to the contract before transferring
+    //A deposit of the sender covering the amount is used first, otherwise only the balance
+    //which is not accounted for as collateral, fees or deposits can be used.
+    //Assets sent without a deposit stay unaccounted until they are used or reconciled
+    let stored_balance = storage.contract_balance.read();
+    let accrued_fees = storage.accrued_fees.read();
+    let current_contract_balance = this_balance(asset);
+
+    let deposit = _deposit_of(sender);
+    let mut total_deposits = storage.total_deposits.read();
+
+    if deposit >= amount && amount > 0 {
+        storage.deposits.insert(sender, deposit - amount);
+        total_deposits = total_deposits - amount;
+        storage.total_deposits.write(total_deposits);
+    } else {
+        //Calculate the required contract balance - Native is sent with the gas quote not beforehand
+        let required_contract_balance = stored_balance + accrued_fees + total_deposits + amount;
+
+        require(
+            current_contract_balance >= required_contract_balance,
+            WarpRouteError::AssetNotReceivedForTransfer,
+        );
+    }
+
+    require(
+        msg_amount() == required_payment,
+        WarpRouteError::PaymentNotEqualToRequired,
+    );
+
+    require(
+        msg_asset_id() == AssetId::base(),
+        WarpRouteError::InvalidAssetSend,
+    );
+
+    match token_mode {
+        WarpRouteTokenMode::SYNTHETIC => {
+            //Burn has checks inside along with decreasing total supply
+            _burn(storage.total_supply, storage.sub_id.read(), amount);
+            _log_total_supply(asset);
+        },
+        WarpRouteTokenMode::NATIVE | WarpRouteTokenMode::COLLATERAL => {
+            //Locked in the contract, except for the deposits of other senders
+            transfer(Identity::ContractId(ContractId::this()), asset, amount);
+            storage.contract_balance.write(current_contract_balance - total_deposits);
+        },
+        WarpRouteTokenMode::COLLATERAL_FEE => {
+            //Only the bridged amount is locked, unaccounted assets are left for pending transfers
+            transfer(Identity::ContractId(ContractId::this()), asset, amount);
+            storage.contract_balance.write(stored_balance + bridged_amount);
+        },
+    }
+
+    if fee > 0 {
+        storage.accrued_fees.write(accrued_fees + fee);
+        log(TransferFeeRetainedEvent {
+            destination: destination_domain,
+            fee,
+        });
+    }
+
+    let metadata = match metadata {
+        Some(metadata) => metadata,
+        None => _gas_router_hook_metadata(destination_domain),
+    };
+
+    //Dispatch the message to the destination domain
+    let message_id = mailbox.dispatch {
+        coins: quote,
+        asset_id: b256::from(AssetId::base()),
+    }(
+        destination_domain,
+        remote_domain_router,
+        message_body,
+        metadata,
+        hook_contract,
+    );
+
+    // The u64 event is kept for existing consumers, amounts which do not fit only log the u256 event
+    if remote_amount <= u256::from(u64::max()) {
+        log(SentTransferRemoteEvent {
+            destination: destination_domain,
+            recipient,
+            amount: <u64 as TryFrom<u256>>::try_from(remote_amount).unwrap(),
+        });
+    }
+    log(SentTransferRemoteAmountEvent {
+        destination: destination_domain,
+        recipient,
+        amount: remote_amount,
+    });
+
+    message_id
+}
+
+fn _build_token_metadata_bytes(
+    recipient: b256,
+    amount: u256,
+    transfer_payload: Option<(b256, Bytes)>,
+) -> Bytes {
     let mut buffer = Buffer::new();
 
     buffer = recipient.abi_encode(buffer);
-    let amount_u256 = u256::from(amount); // Convert `u64` to `U256` for 32-byte padding
-    buffer = amount_u256.abi_encode(buffer);
-    let bytes = Bytes::from(buffer.as_raw_slice());
+    buffer = amount.abi_encode(buffer);
+    let mut bytes = Bytes::from(buffer.as_raw_slice());
+    // The sender and payload trail the recipient and amount, as the metadata of a Hyperlane `TokenMessage`
+    if let Some((sender, payload)) = transfer_payload {
+        let mut sender = Bytes::from(sender);
+        let mut payload = payload;
+        bytes.append(sender);
+        bytes.append(payload);
+    }
     bytes
 }
 
-fn _extract_asset_data_from_body(body: Bytes) -> (b256, u64) {
+fn _extract_asset_data_from_body(body: Bytes) -> (b256, u256, Option<(b256, Bytes)>) {
+    require(
+        body.len() >= TOKEN_MESSAGE_LENGTH,
+        WarpRouteError::InvalidMessageBody,
+    );
     let mut buffer_reader = BufferReader::from_parts(body.ptr(), body.len());
 
     let recipient = buffer_reader.read::<b256>();
-    let amount_u256 = buffer_reader.read::<u256>();
+    let amount = buffer_reader.read::<u256>();
+    if body.len() == TOKEN_MESSAGE_LENGTH {
+        return (recipient, amount, None);
+    }
+
+    require(
+        body.len() > TOKEN_MESSAGE_LENGTH + PAYLOAD_SENDER_LENGTH,
+        WarpRouteError::InvalidMessageBody,
+    );
+    let sender = buffer_reader.read::<b256>();
+    let (_, payload) = body.split_at(TOKEN_MESSAGE_LENGTH + PAYLOAD_SENDER_LENGTH);
+    (recipient, amount, Some((sender, payload)))
+}
+
+/// Whether `asset` is the synthetic asset issued by the contract
+#[storage(read)]
+fn _is_synthetic_asset(asset: AssetId) -> bool {
+    match storage.token_mode.read() {
+        WarpRouteTokenMode::SYNTHETIC => asset == storage.asset_id.read(),
+        _ => false,
+    }
+}
+
+#[storage(read)]
+fn _log_total_supply(asset: AssetId) {
+    let supply = storage.total_supply.get(asset).try_read().unwrap_or(0);
+    TotalSupplyEvent::new(asset, supply, msg_sender().unwrap()).log();
+}
+
+#[storage(read)]
+fn _deposit_of(depositor: Identity) -> u64 {
+    storage.deposits.get(depositor).try_read().unwrap_or(0)
+}
+
+#[storage(read)]
+fn _is_contract_recipient(recipient: b256) -> bool {
+    storage.contract_recipients.get(recipient).try_read().unwrap_or(false)
+}
+
+/// The fee retained from `amount`, rounded down in favour of the sender
+#[storage(read)]
+fn _transfer_fee(amount: u64) -> u64 {
+    let fee = u256::from(amount) * u256::from(storage.fee_bps.read()) / u256::from(BPS_DENOMINATOR);
+    <u64 as TryFrom<u256>>::try_from(fee).unwrap()
+}
+
+#[storage(read)]
+fn _rate_limit(domain: u32, direction: RateLimitDirection) -> Option<RateLimit> {
+    let limit = match direction {
+        RateLimitDirection::Inbound => storage.inbound_rate_limits.get(domain).try_read(),
+        RateLimitDirection::Outbound => storage.outbound_rate_limits.get(domain).try_read(),
+    };
+    match limit {
+        Some(limit) => Some(_refill_rate_limit(limit)),
+        None => None,
+    }
+}
+
+#[storage(write)]
+fn _write_rate_limit(domain: u32, direction: RateLimitDirection, limit: RateLimit) {
+    match direction {
+        RateLimitDirection::Inbound => storage.inbound_rate_limits.insert(domain, limit),
+        RateLimitDirection::Outbound => storage.outbound_rate_limits.insert(domain, limit),
+    };
+}
+
+#[storage(write)]
+fn _remove_rate_limit(domain: u32, direction: RateLimitDirection) {
+    let _ = match direction {
+        RateLimitDirection::Inbound => storage.inbound_rate_limits.remove(domain),
+        RateLimitDirection::Outbound => storage.outbound_rate_limits.remove(domain),
+    };
+}
+
+/// Refills the level of a rate limit up to the current block, capped at its capacity
+fn _refill_rate_limit(limit: RateLimit) -> RateLimit {
+    let now = height();
+    let elapsed = u256::from((now - limit.last_updated).as_u64());
+    let refilled = u256::from(limit.level) + elapsed * u256::from(limit.refill_rate);
+    let level = if refilled > u256::from(limit.capacity) {
+        limit.capacity
+    } else {
+        <u64 as TryFrom<u256>>::try_from(refilled).unwrap()
+    };
 
-    let amount = <u64 as TryFrom<u256>>::try_from(amount_u256).expect("Amount exceeds u64 range");
-    (recipient, amount)
+    RateLimit {
+        capacity: limit.capacity,
+        refill_rate: limit.refill_rate,
+        level,
+        last_updated: now,
+    }
+}
+
+/// Consumes `amount` of the rate limit of the domain, if it has one.
+/// Reaching the limit is logged by the transfer consuming the rest of it,
+/// as logs of the reverted transfers exceeding it are not indexed.
+#[storage(read, write)]
+fn _consume_rate_limit(domain: u32, direction: RateLimitDirection, amount: u64) {
+    if let Some(limit) = _rate_limit(domain, direction) {
+        require(amount <= limit.level, RateLimitError::RateLimitExceeded);
+
+        if amount == limit.level {
+            log(RateLimitReachedEvent {
+                domain,
+                direction,
+                capacity: limit.capacity,
+            });
+        }
+
+        _write_rate_limit(
+            domain,
+            direction,
+            RateLimit {
+                capacity: limit.capacity,
+                refill_rate: limit.refill_rate,
+                level: limit.level - amount,
+                last_updated: limit.last_updated,
+            },
+        );
+    }
 }
 
 #[storage(read)]
@@ -873,27 +1641,59 @@ fn _get_remote_router_decimals(router: b256) -> u8 {
     storage.remote_router_decimals.get(router).try_read().unwrap_or(0)
 }
 
-fn _adjust_decimals(amount: u64, from_decimals: u8, to_decimals: u8) -> u64 {
+/// Scales a local amount to the decimals of the remote router, rounding down
+fn _to_remote_amount(amount: u64, local_decimals: u8, remote_decimals: u8) -> u256 {
+    _scale_amount(u256::from(amount), local_decimals, remote_decimals)
+}
+
+/// Scales a remote amount to the local decimals, rounding down.
+/// Fuel assets are limited to `u64`, larger amounts are rejected.
+fn _to_local_amount(amount: u256, remote_decimals: u8, local_decimals: u8) -> u64 {
+    let local_amount = _scale_amount(amount, remote_decimals, local_decimals);
+    require(
+        local_amount <= u256::from(u64::max()),
+        WarpRouteError::AmountNotConvertible,
+    );
+    <u64 as TryFrom<u256>>::try_from(local_amount).unwrap()
+}
+
+/// Scales an amount between decimals.
+/// Zero amounts and amounts which round down to zero are rejected,
+/// instead of transferring nothing or dust as nothing.
+fn _scale_amount(amount: u256, from_decimals: u8, to_decimals: u8) -> u256 {
+    require(amount != u256::zero(), WarpRouteError::AmountRoundsToZero);
     if from_decimals == to_decimals {
         return amount;
     }
 
-    let difference = if from_decimals > to_decimals {
-        from_decimals - to_decimals
-    } else {
-        to_decimals - from_decimals
-    };
-
-    let factor = 10u64.pow(difference.as_u32());
-
     if from_decimals > to_decimals {
-        require(amount >= factor, WarpRouteError::AmountNotConvertible);
-        amount / factor
+        let difference = from_decimals - to_decimals;
+        let scaled = if difference > MAX_DECIMALS_DIFFERENCE {
+            u256::zero()
+        } else {
+            amount / _pow10(difference)
+        };
+        require(scaled != u256::zero(), WarpRouteError::AmountRoundsToZero);
+        scaled
     } else {
+        let difference = to_decimals - from_decimals;
+        require(
+            difference <= MAX_DECIMALS_DIFFERENCE,
+            WarpRouteError::AmountOverflow,
+        );
+        let factor = _pow10(difference);
+        require(
+            amount <= u256::max() / factor,
+            WarpRouteError::AmountOverflow,
+        );
         amount * factor
     }
 }
 
+fn _pow10(exponent: u8) -> u256 {
+    u256::from(10u64).pow(exponent.as_u32())
+}
+
 #[storage(read)]
 fn _get_quote_for_gas_payment(
     destination_domain: u32,
@@ -3,13 +3,16 @@ mod warp_route {
 
     use fuels::{
         prelude::*,
-        types::{Bits256, Identity, U256},
+        programs::responses::CallResponse,
+        types::{errors::Error, Bits256, Identity, U256},
     };
     use hyperlane_core::{Encode, HyperlaneMessage, H256};
     use once_cell::sync::Lazy;
     use rand::{thread_rng, Rng};
     use std::str::FromStr;
-    use test_utils::{funded_wallet_with_private_key, get_revert_reason};
+    use test_utils::{
+        fixtures::dispatched_messages, funded_wallet_with_private_key, get_revert_reason,
+    };
     use tokio::sync::Mutex;
 
     // Load abi from JSON
@@ -34,6 +37,10 @@ mod warp_route {
             name = "SRC20Test",
             abi = "contracts/test/src20-test/out/debug/src20-test-abi.json",
         ),
+        Contract(
+            name = "TokenRecipientTest",
+            abi = "contracts/test/token-recipient-test/out/debug/token-recipient-test-abi.json",
+        ),
     );
 
     const TEST_NON_OWNER_PRIVATE_KEY: &str =
@@ -91,11 +98,12 @@ mod warp_route {
     /// Asset recieve messages are only allowed when they are sent from mailbox
     pub fn _mock_asset_recieve_message(
         recipient: &Bech32ContractId,
-        amount: u64,
+        amount: U256,
         recipient_user: Bits256,
         sender: Bits256,
+        payload: &[u8],
     ) -> HyperlaneMessage {
-        let message_body = build_message_body(recipient_user, amount);
+        let message_body = build_message_body(recipient_user, amount, payload);
 
         HyperlaneMessage {
             version: 3u8,
@@ -108,19 +116,24 @@ mod warp_route {
         }
     }
 
-    fn build_message_body(recipient: Bits256, amount: u64) -> Bytes {
+    fn build_message_body(recipient: Bits256, amount: U256, payload: &[u8]) -> Bytes {
         let mut buffer = Vec::new();
 
-        let amount_u256 = U256::from(amount);
         let mut amount_bytes = [0u8; 32];
-        amount_u256.to_big_endian(&mut amount_bytes);
+        amount.to_big_endian(&mut amount_bytes);
 
         buffer.extend(&recipient.0);
         buffer.extend(&amount_bytes);
+        buffer.extend(payload);
 
         Bytes(buffer)
     }
 
+    /// The trailing part of a transfer message carrying a payload, which starts with the sender
+    fn transfer_payload(sender: Bits256, payload: &[u8]) -> Vec<u8> {
+        [sender.0.as_slice(), payload].concat()
+    }
+
     async fn trigger_handle_by_sending_message_from_mailbox(
         mailbox: &Mailbox<WalletUnlocked>,
         warp_route: &WarpRoute<WalletUnlocked>,
@@ -138,9 +151,10 @@ mod warp_route {
 
         let message = _mock_asset_recieve_message(
             &warp_route.contract_id().clone(),
-            amount,
+            U256::from(amount),
             recipient_b256,
             remote_router_address,
+            &[],
         );
 
         let _call = mailbox
@@ -153,6 +167,105 @@ mod warp_route {
             .map_err(|e| format!("Failed send message from mailbox: {:?}", e));
     }
 
+    /// Processes a transfer from the remote router, decoding reverts of the warp route
+    async fn process_transfer_message(
+        mailbox: &Mailbox<WalletUnlocked>,
+        warp_route: &WarpRoute<WalletUnlocked>,
+        contract_ids: Vec<Bech32ContractId>,
+        amount: U256,
+        recipient: Bits256,
+    ) -> std::result::Result<CallResponse<()>, Error> {
+        process_transfer_message_with_payload(
+            mailbox,
+            warp_route,
+            contract_ids,
+            amount,
+            recipient,
+            &[],
+        )
+        .await
+    }
+
+    /// Processes a transfer carrying a payload from the remote router
+    async fn process_transfer_message_with_payload(
+        mailbox: &Mailbox<WalletUnlocked>,
+        warp_route: &WarpRoute<WalletUnlocked>,
+        contract_ids: Vec<Bech32ContractId>,
+        amount: U256,
+        recipient: Bits256,
+        payload: &[u8],
+    ) -> std::result::Result<CallResponse<()>, Error> {
+        let remote_router_address =
+            Bits256(Address::from_str(REMOTE_ROUTER_ADDRESS).unwrap().into());
+
+        let message = _mock_asset_recieve_message(
+            warp_route.contract_id(),
+            amount,
+            recipient,
+            remote_router_address,
+            payload,
+        );
+
+        mailbox
+            .methods()
+            .process(Bytes(message.to_vec()), Bytes(message.to_vec()))
+            .with_contracts(&[warp_route])
+            .with_contract_ids(&contract_ids)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(10))
+            .call()
+            .await
+    }
+
+    /// Deploys a contract and registers it as a recipient of incoming transfers
+    async fn register_contract_recipient(warp_route: &WarpRoute<WalletUnlocked>) -> ContractId {
+        let wallet = warp_route.account();
+        let salt: [u8; 32] = thread_rng().gen();
+
+        let recipient_id: ContractId = Contract::load_from(
+            "../test/msg-recipient-test/out/debug/msg-recipient-test.bin",
+            LoadConfiguration::default().with_salt(salt),
+        )
+        .unwrap()
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .unwrap()
+        .into();
+
+        warp_route
+            .methods()
+            .set_contract_recipient(Bits256(recipient_id.into()), true)
+            .call()
+            .await
+            .unwrap();
+
+        recipient_id
+    }
+
+    /// Deploys a contract handling the payload of transfers and registers it as a recipient
+    async fn register_token_recipient(
+        warp_route: &WarpRoute<WalletUnlocked>,
+    ) -> TokenRecipientTest<WalletUnlocked> {
+        let wallet = warp_route.account();
+
+        let recipient_id = Contract::load_from(
+            "../test/token-recipient-test/out/debug/token-recipient-test.bin",
+            LoadConfiguration::default(),
+        )
+        .unwrap()
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+        warp_route
+            .methods()
+            .set_contract_recipient(Bits256(ContractId::from(&recipient_id).into()), true)
+            .call()
+            .await
+            .unwrap();
+
+        TokenRecipientTest::new(recipient_id, wallet)
+    }
+
     // Storing Test Configuration
     #[derive(Clone, Debug)]
     struct WarpRouteConfig {
@@ -308,7 +421,12 @@ mod warp_route {
         let mut asset_id = Some(config.asset_id.unwrap());
         let mut asset_contract_id = None;
 
-        if config.token_mode == WarpRouteTokenMode::COLLATERAL {
+        let is_collateral = matches!(
+            config.token_mode,
+            WarpRouteTokenMode::COLLATERAL | WarpRouteTokenMode::COLLATERAL_FEE
+        );
+
+        if is_collateral {
             asset_id = Some(collateral_token_id);
             asset_contract_id = Some(Bits256(collateral_token_contract_id.hash().into()));
         }
@@ -330,7 +448,7 @@ mod warp_route {
             )
             .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum);
 
-        if config.token_mode == WarpRouteTokenMode::COLLATERAL {
+        if is_collateral {
             call_handler = call_handler.with_contract_ids(&[collateral_token_contract_id]);
         }
 
@@ -507,6 +625,34 @@ mod warp_route {
             assert_eq!(token_mode, config.token_mode);
         }
 
+        /// ============ SRC-20 ============
+        #[tokio::test]
+        async fn test_src20_collateral_not_issued() {
+            let (config, warp_route, ..) = get_collateral_contract_instance().await;
+            let asset = config.asset_id.unwrap();
+
+            // The collateral asset is issued by its own contract, not by the warp route
+            let total_assets = warp_route
+                .methods()
+                .total_assets()
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let name = warp_route.methods().name(asset).call().await.unwrap().value;
+            let metadata = warp_route
+                .methods()
+                .metadata(asset, "bridge".to_string())
+                .call()
+                .await
+                .unwrap()
+                .value;
+
+            assert_eq!(total_assets, 0);
+            assert_eq!(name, None);
+            assert_eq!(metadata, None);
+        }
+
         /// ============ get_all_domains_and_routers ============
         #[tokio::test]
         async fn test_get_all_domains_and_routers() {
@@ -744,6 +890,14 @@ mod warp_route {
             assert_eq!(log.recipient, recipient);
             assert_eq!(log.amount, amount * 10u64.pow(remote_decimals as u32 - 9));
 
+            let logs = call
+                .decode_logs_with_type::<SentTransferRemoteAmountEvent>()
+                .unwrap();
+            assert_eq!(
+                logs[0].amount,
+                U256::from(amount) * U256::exp10(remote_decimals as usize - 9)
+            );
+
             let contract_balance_after =
                 get_contract_balance(provider, warp_route.contract_id(), asset)
                     .await
@@ -757,6 +911,226 @@ mod warp_route {
             assert_eq!(wallet_balance_before - amount, wallet_balance_after);
         }
 
+        /// Deposits `amount` of the collateral asset for a later transfer by the account of `warp_route`
+        async fn deposit(
+            warp_route: &WarpRoute<WalletUnlocked>,
+            asset: AssetId,
+            amount: u64,
+        ) -> std::result::Result<CallResponse<()>, Error> {
+            warp_route
+                .methods()
+                .deposit()
+                .call_params(
+                    CallParameters::default()
+                        .with_amount(amount)
+                        .with_asset_id(asset),
+                )
+                .unwrap()
+                .call()
+                .await
+        }
+
+        /// ============ deposits ============
+        #[tokio::test]
+        async fn test_transfer_remote_frontrun_without_deposit() {
+            let (config, warp_route, warp_route_id, _, mailbox_id, post_dispatch_id, _) =
+                get_collateral_contract_instance().await;
+            let wallet = warp_route.account();
+            let asset = config.asset_id.unwrap();
+            let amount = 123;
+            let contract_ids = [
+                warp_route_id.into(),
+                mailbox_id.into(),
+                post_dispatch_id.into(),
+            ];
+
+            // The tokens and the transfer are sent in two transactions
+            wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    amount,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            // Anyone can use the tokens in between
+            let attacker =
+                funded_wallet_with_private_key(&wallet, TEST_NON_OWNER_PRIVATE_KEY).await;
+            let attacker_recipient = Bits256(attacker.address().hash().into());
+            warp_route
+                .with_account(attacker)
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, attacker_recipient, amount, None, None)
+                .with_contract_ids(&contract_ids)
+                .call()
+                .await
+                .unwrap();
+
+            let call = warp_route
+                .methods()
+                .transfer_remote(
+                    TEST_REMOTE_DOMAIN,
+                    Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+                    amount,
+                    None,
+                    None,
+                )
+                .with_contract_ids(&contract_ids)
+                .call()
+                .await;
+            assert_eq!(
+                get_revert_reason(call.unwrap_err()),
+                "AssetNotReceivedForTransfer"
+            );
+        }
+
+        #[tokio::test]
+        async fn test_deposit_and_transfer_remote() {
+            let (config, warp_route, warp_route_id, _, mailbox_id, post_dispatch_id, _) =
+                get_collateral_contract_instance().await;
+            let wallet = warp_route.account();
+            let owner = Identity::Address(wallet.address().into());
+            let asset = config.asset_id.unwrap();
+            let amount = 123;
+            let contract_ids = [
+                warp_route_id.into(),
+                mailbox_id.into(),
+                post_dispatch_id.into(),
+            ];
+
+            let call = deposit(&warp_route, asset, amount).await.unwrap();
+            let events = call.decode_logs_with_type::<DepositEvent>().unwrap();
+            assert_eq!(
+                events,
+                vec![DepositEvent {
+                    depositor: owner,
+                    amount,
+                }]
+            );
+
+            // The deposit cannot be used by another sender
+            let attacker =
+                funded_wallet_with_private_key(&wallet, TEST_NON_OWNER_PRIVATE_KEY).await;
+            let attacker_recipient = Bits256(attacker.address().hash().into());
+            let call = warp_route
+                .with_account(attacker)
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, attacker_recipient, amount, None, None)
+                .with_contract_ids(&contract_ids)
+                .call()
+                .await;
+            assert_eq!(
+                get_revert_reason(call.unwrap_err()),
+                "AssetNotReceivedForTransfer"
+            );
+
+            warp_route
+                .methods()
+                .transfer_remote(
+                    TEST_REMOTE_DOMAIN,
+                    Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+                    amount,
+                    None,
+                    None,
+                )
+                .with_contract_ids(&contract_ids)
+                .call()
+                .await
+                .unwrap();
+
+            let deposit = warp_route
+                .methods()
+                .deposit_of(owner)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let collateral = warp_route
+                .methods()
+                .collateral_balance()
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(deposit, 0);
+            assert_eq!(collateral, amount);
+        }
+
+        #[tokio::test]
+        async fn test_refund_deposit() {
+            let (config, warp_route, ..) = get_collateral_contract_instance().await;
+            let wallet = warp_route.account();
+            let provider = wallet.provider().unwrap();
+            let owner = Identity::Address(wallet.address().into());
+            let asset = config.asset_id.unwrap();
+            let amount = 123;
+
+            let balance_before = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+
+            deposit(&warp_route, asset, amount).await.unwrap();
+            wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    100,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            // Claiming the balance of the contract leaves the deposits
+            warp_route
+                .methods()
+                .claim(Some(asset))
+                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .call()
+                .await
+                .unwrap();
+
+            let contract_balance = get_contract_balance(provider, warp_route.contract_id(), asset)
+                .await
+                .unwrap();
+            assert_eq!(contract_balance, amount);
+
+            let call = warp_route
+                .methods()
+                .refund_deposit()
+                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .call()
+                .await
+                .unwrap();
+            let events = call
+                .decode_logs_with_type::<DepositRefundedEvent>()
+                .unwrap();
+            assert_eq!(
+                events,
+                vec![DepositRefundedEvent {
+                    depositor: owner,
+                    amount,
+                }]
+            );
+
+            let balance_after = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+            assert_eq!(balance_before, balance_after);
+
+            let call = warp_route.methods().refund_deposit().call().await;
+            assert_eq!(get_revert_reason(call.unwrap_err()), "NoDeposit");
+        }
+
+        #[tokio::test]
+        async fn test_deposit_invalid_asset() {
+            let (_, warp_route, ..) = get_collateral_contract_instance().await;
+
+            let call = deposit(&warp_route, AssetId::BASE, 123).await;
+            assert_eq!(get_revert_reason(call.unwrap_err()), "InvalidAssetSend");
+        }
+
         /// ============ handle_message_collateral ============
         #[tokio::test]
         async fn test_handle_message() {
@@ -845,383 +1219,1741 @@ mod warp_route {
             );
         }
 
-        /// ============ claim_as_owner ============
+        /// ============ set_contract_recipient ============
         #[tokio::test]
-        async fn test_claim_as_owner() {
-            let (config, warp_route, warp_route_id, _, mailbox_id, post_dispatch_id, _) =
-                get_collateral_contract_instance().await;
+        async fn test_set_contract_recipient() {
+            let (_, warp_route, ..) = get_collateral_contract_instance().await;
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
 
-            let wallet = warp_route.account();
-            let provider = wallet.provider().unwrap();
-            let asset = warp_route
+            let is_contract = warp_route
                 .methods()
-                .get_token_info()
+                .is_contract_recipient(recipient)
                 .call()
                 .await
                 .unwrap()
-                .value
-                .asset_id;
-
-            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
-            let amount = 190;
+                .value;
+            assert!(!is_contract);
 
-            let (_, _) = wallet
-                .force_transfer_to_contract(
-                    warp_route.contract_id(),
-                    amount,
-                    asset,
-                    TxPolicies::default(),
-                )
+            let call = warp_route
+                .methods()
+                .set_contract_recipient(recipient, true)
+                .call()
                 .await
                 .unwrap();
+            let events = call
+                .decode_logs_with_type::<ContractRecipientSetEvent>()
+                .unwrap();
+            assert_eq!(
+                events,
+                vec![ContractRecipientSetEvent {
+                    recipient,
+                    is_contract: true,
+                }]
+            );
 
-            // Transfer remote
-            warp_route
+            let is_contract = warp_route
                 .methods()
-                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
-                .with_contract_ids(&[
-                    warp_route_id.into(),
-                    mailbox_id.into(),
-                    post_dispatch_id.into(),
-                ])
+                .is_contract_recipient(recipient)
                 .call()
                 .await
-                .unwrap();
-
-            let contract_balance_before =
-                get_contract_balance(provider, warp_route.contract_id(), asset)
-                    .await
-                    .unwrap();
+                .unwrap()
+                .value;
+            assert!(is_contract);
 
-            // Claim the balance as the owner
             warp_route
                 .methods()
-                .claim(Some(config.asset_id.unwrap()))
-                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .set_contract_recipient(recipient, false)
                 .call()
                 .await
                 .unwrap();
 
-            let contract_balance_after =
-                get_contract_balance(provider, warp_route.contract_id(), asset)
-                    .await
-                    .unwrap();
-
-            assert_eq!(contract_balance_before - amount, contract_balance_after);
-        }
-
-        /// ============ transfer_remote_with_zero_amount ============
-        #[tokio::test]
-        async fn test_zero_and_negative_amount_transfer_remote() {
-            let (_, warp_route, .., mailbox_id, post_dispatch_id, _) =
-                get_collateral_contract_instance().await;
-
-            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
-            let _asset = warp_route
+            let is_contract = warp_route
                 .methods()
-                .get_token_info()
+                .is_contract_recipient(recipient)
                 .call()
                 .await
                 .unwrap()
-                .value
-                .asset_id;
+                .value;
+            assert!(!is_contract);
+        }
 
-            // Test with a zero transfer
-            let amount_zero = 0u64;
+        /// ============ set_contract_recipient_unauthorized ============
+        #[tokio::test]
+        async fn test_set_contract_recipient_unauthorized() {
+            let (_, warp_route, ..) = get_collateral_contract_instance().await;
+            let non_owner_wallet =
+                funded_wallet_with_private_key(&warp_route.account(), TEST_NON_OWNER_PRIVATE_KEY)
+                    .await;
 
-            let call_zero = warp_route
+            let call = warp_route
+                .with_account(non_owner_wallet)
                 .methods()
-                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount_zero, None, None)
-                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
-                .with_contract_ids(&[mailbox_id.into(), post_dispatch_id.into()])
+                .set_contract_recipient(Bits256::from_hex_str(TEST_RECIPIENT).unwrap(), true)
                 .call()
                 .await;
 
-            assert!(call_zero.is_err());
-            assert_eq!(
-                get_revert_reason(call_zero.unwrap_err()),
-                "TransferZeroCoins"
-            );
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
         }
 
-        /// ============ pause_and_unpause ============
+        /// ============ handle_message_collateral_contract_recipient ============
         #[tokio::test]
-        async fn test_pause_and_unpause() {
-            let (_, warp_route, warp_route_id, _, mailbox_id, post_dispatch_id, _) =
-                get_collateral_contract_instance().await;
+        async fn test_handle_message_contract_recipient() {
+            let (
+                config,
+                warp_route,
+                warp_route_id,
+                mailbox,
+                mailbox_id,
+                post_dispatch_id,
+                default_ism_id,
+            ) = get_collateral_contract_instance().await;
 
             let wallet = warp_route.account();
-            let asset = warp_route
+            let asset = config.asset_id.unwrap();
+            let recipient_id = register_contract_recipient(&warp_route).await;
+
+            let local_decimals = warp_route
                 .methods()
                 .get_token_info()
                 .call()
                 .await
                 .unwrap()
                 .value
-                .asset_id;
-
-            // Pause the contract
-            warp_route.methods().pause().call().await.unwrap();
-
-            let is_paused = warp_route.methods().is_paused().call().await.unwrap().value;
-            assert!(is_paused);
-
-            let amount = 1;
-            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
-
-            // Attempt an operation that should fail when paused
-            let call = warp_route
-                .methods()
-                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
-                .call()
-                .await;
-            assert_eq!(get_revert_reason(call.unwrap_err()), "Paused");
+                .decimals as u32;
 
-            // Unpause the contract
-            warp_route.methods().unpause().call().await.unwrap();
+            let amount = 2 * 10u64.pow(REMOTE_DECIMALS as u32);
+            let local_amount = amount / 10u64.pow(REMOTE_DECIMALS as u32 - local_decimals);
 
-            let (_, _) = wallet
+            wallet
                 .force_transfer_to_contract(
                     warp_route.contract_id(),
-                    amount,
+                    local_amount,
                     asset,
                     TxPolicies::default(),
                 )
                 .await
                 .unwrap();
 
-            let call = warp_route
-                .methods()
-                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
-                .with_contract_ids(&[
+            trigger_handle_by_sending_message_from_mailbox(
+                &mailbox,
+                &warp_route,
+                vec![
                     warp_route_id.into(),
                     mailbox_id.into(),
                     post_dispatch_id.into(),
-                ])
-                .call()
-                .await;
+                    default_ism_id.into(),
+                    recipient_id.into(),
+                ],
+                amount,
+                Some(Bits256(recipient_id.into())),
+            )
+            .await;
 
-            assert!(call.is_ok());
+            let provider = wallet.provider().unwrap();
+            let recipient_balance = get_contract_balance(provider, &recipient_id.into(), asset)
+                .await
+                .unwrap();
+            assert_eq!(recipient_balance, local_amount);
         }
-    }
-
-    /// SYNTHETIC Token Mode Test Cases
-    #[cfg(test)]
-    mod synthetic {
-        use super::*;
-
-        static SYNTHETIC_CONFIG: Lazy<Mutex<WarpRouteConfig>> = Lazy::new(|| {
-            Mutex::new(WarpRouteConfig {
-                token_mode: WarpRouteTokenMode::SYNTHETIC,
-                token_name: Some(TOKEN_NAME.to_string()),
-                token_symbol: Some(TOKEN_SYMBOL.to_string()),
-                decimals: Some(DECIMALS),
-                total_supply: Some(TOTAL_SUPPLY),
-                asset_id: Some(get_native_asset()),
-            })
-        });
 
-        async fn get_synthetic_contract_instance() -> (
-            WarpRouteConfig,
-            WarpRoute<WalletUnlocked>,
-            ContractId,
-            Mailbox<WalletUnlocked>,
-            ContractId,
-            ContractId,
-            ContractId,
-            ContractId,
-        ) {
-            let (
-                warp_route,
-                contract_id,
-                mailbox,
-                mailbox_id,
-                post_dispatch_id,
-                recipient_id,
-                ism_id,
-                _,
-            ) = get_contract_instance(&SYNTHETIC_CONFIG).await;
+        /// ============ set_rate_limit ============
+        #[tokio::test]
+        async fn test_set_rate_limit() {
+            let (_, warp_route, ..) = get_collateral_contract_instance().await;
 
-            let token_info = warp_route
+            let rate_limit = warp_route
                 .methods()
-                .get_token_info()
+                .rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Outbound)
                 .call()
                 .await
                 .unwrap()
                 .value;
+            assert!(rate_limit.is_none());
 
-            let mut config = SYNTHETIC_CONFIG.lock().await.clone();
-            config.asset_id = Some(token_info.asset_id);
+            let call = warp_route
+                .methods()
+                .set_rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Outbound, 1_000, 10)
+                .call()
+                .await
+                .unwrap();
+            let events = call.decode_logs_with_type::<RateLimitSetEvent>().unwrap();
+            assert_eq!(
+                events,
+                vec![RateLimitSetEvent {
+                    domain: TEST_REMOTE_DOMAIN,
+                    direction: RateLimitDirection::Outbound,
+                    capacity: 1_000,
+                    refill_rate: 10,
+                }]
+            );
 
-            (
-                config,
-                warp_route,
-                contract_id,
-                mailbox,
-                mailbox_id,
-                post_dispatch_id,
-                recipient_id,
-                ism_id,
-            )
-        }
+            let rate_limit = warp_route
+                .methods()
+                .rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Outbound)
+                .call()
+                .await
+                .unwrap()
+                .value
+                .unwrap();
+            assert_eq!(rate_limit.capacity, 1_000);
+            assert_eq!(rate_limit.refill_rate, 10);
+            assert_eq!(rate_limit.level, 1_000);
 
-        /// ============ get_token_info ============
-        #[tokio::test]
-        async fn test_get_token_info() {
-            let (mut config, warp_route, ..) = get_synthetic_contract_instance().await;
+            // Limits are set per direction
+            let inbound = warp_route
+                .methods()
+                .rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Inbound)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert!(inbound.is_none());
 
-            let token_info = warp_route
+            warp_route
                 .methods()
-                .get_token_info()
+                .set_rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Outbound, 0, 0)
+                .call()
+                .await
+                .unwrap();
+
+            let rate_limit = warp_route
+                .methods()
+                .rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Outbound)
                 .call()
                 .await
                 .unwrap()
                 .value;
+            assert!(rate_limit.is_none());
+        }
 
-            config.asset_id = Some(token_info.asset_id);
+        /// ============ set_rate_limit_unauthorized ============
+        #[tokio::test]
+        async fn test_set_rate_limit_unauthorized() {
+            let (_, warp_route, ..) = get_collateral_contract_instance().await;
+            let non_owner_wallet =
+                funded_wallet_with_private_key(&warp_route.account(), TEST_NON_OWNER_PRIVATE_KEY)
+                    .await;
 
-            assert_eq!(token_info.name, config.token_name.unwrap());
-            assert_eq!(token_info.symbol, config.token_symbol.unwrap());
-            assert_eq!(token_info.decimals, config.decimals.unwrap());
-            assert_eq!(token_info.total_supply, config.total_supply.unwrap());
+            let call = warp_route
+                .with_account(non_owner_wallet)
+                .methods()
+                .set_rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Inbound, 1_000, 10)
+                .call()
+                .await;
+
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
         }
 
-        /// ============ get_token_mode ============
+        /// ============ outbound_rate_limit ============
         #[tokio::test]
-        async fn test_get_token_mode() {
-            let (config, warp_route, ..) = get_synthetic_contract_instance().await;
+        async fn test_outbound_rate_limit() {
+            let (config, warp_route, warp_route_id, _, mailbox_id, post_dispatch_id, _) =
+                get_collateral_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let provider = wallet.provider().unwrap();
+            let asset = config.asset_id.unwrap();
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let capacity = 1_000;
+            let refill_rate = 1;
+            let amount = 400;
+
+            warp_route
+                .methods()
+                .set_rate_limit(
+                    TEST_REMOTE_DOMAIN,
+                    RateLimitDirection::Outbound,
+                    capacity,
+                    refill_rate,
+                )
+                .call()
+                .await
+                .unwrap();
+
+            // Sustained transfers drain the limit faster than it refills
+            let mut results = Vec::new();
+            for _ in 0..3 {
+                wallet
+                    .force_transfer_to_contract(
+                        warp_route.contract_id(),
+                        amount,
+                        asset,
+                        TxPolicies::default(),
+                    )
+                    .await
+                    .unwrap();
+
+                let call = warp_route
+                    .methods()
+                    .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
+                    .with_contract_ids(&[
+                        warp_route_id.into(),
+                        mailbox_id.into(),
+                        post_dispatch_id.into(),
+                    ])
+                    .call()
+                    .await;
+                results.push(call);
+            }
+
+            let error = results.pop().unwrap().unwrap_err();
+            assert!(results.iter().all(|result| result.is_ok()));
+
+            assert_eq!(get_revert_reason(error), "RateLimitExceeded");
+
+            // The limit refills over the following blocks
+            provider
+                .produce_blocks((amount / refill_rate) as u32, None)
+                .await
+                .unwrap();
+
+            let rate_limit = warp_route
+                .methods()
+                .rate_limit(TEST_REMOTE_DOMAIN, RateLimitDirection::Outbound)
+                .call()
+                .await
+                .unwrap()
+                .value
+                .unwrap();
+            assert!(rate_limit.level >= amount);
+            assert!(rate_limit.level <= capacity);
+
+            warp_route
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
+                .with_contract_ids(&[
+                    warp_route_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                ])
+                .call()
+                .await
+                .unwrap();
+        }
+
+        /// ============ inbound_rate_limit ============
+        #[tokio::test]
+        async fn test_inbound_rate_limit() {
+            let (
+                config,
+                warp_route,
+                warp_route_id,
+                mailbox,
+                mailbox_id,
+                post_dispatch_id,
+                default_ism_id,
+            ) = get_collateral_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let provider = wallet.provider().unwrap();
+            let asset = config.asset_id.unwrap();
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let contract_ids = vec![
+                warp_route_id.into(),
+                mailbox_id.into(),
+                post_dispatch_id.into(),
+                default_ism_id.into(),
+            ];
+
+            // Limits are in the local decimals
+            let token = 10u64.pow(DECIMALS as u32);
+            let remote_token = U256::exp10(REMOTE_DECIMALS as usize);
+
+            wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    3 * token,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            warp_route
+                .methods()
+                .set_rate_limit(
+                    TEST_REMOTE_DOMAIN,
+                    RateLimitDirection::Inbound,
+                    2 * token,
+                    token / 100,
+                )
+                .call()
+                .await
+                .unwrap();
+
+            // The transfer consuming the rest of the limit logs that it is reached
+            let call = process_transfer_message(
+                &mailbox,
+                &warp_route,
+                contract_ids.clone(),
+                remote_token * 2,
+                recipient,
+            )
+            .await
+            .unwrap();
+            let events = call
+                .decode_logs_with_type::<RateLimitReachedEvent>()
+                .unwrap();
+            assert_eq!(
+                events,
+                vec![RateLimitReachedEvent {
+                    domain: TEST_REMOTE_DOMAIN,
+                    direction: RateLimitDirection::Inbound,
+                    capacity: 2 * token,
+                }]
+            );
+
+            // The drained limit blocks further deliveries until it refills
+            let error = process_transfer_message(
+                &mailbox,
+                &warp_route,
+                contract_ids.clone(),
+                remote_token,
+                recipient,
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(get_revert_reason(error), "RateLimitExceeded");
+
+            provider.produce_blocks(100, None).await.unwrap();
+
+            process_transfer_message(&mailbox, &warp_route, contract_ids, remote_token, recipient)
+                .await
+                .unwrap();
+
+            let recipient_balance =
+                get_balance(provider, &Address::from(recipient.0).into(), asset)
+                    .await
+                    .unwrap();
+            assert_eq!(recipient_balance, 3 * token);
+        }
+
+        /// ============ transfer_remote_with_payload ============
+        #[tokio::test]
+        async fn test_transfer_remote_with_payload() {
+            let (config, warp_route, warp_route_id, mailbox, _, post_dispatch_id, _) =
+                get_collateral_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let asset = config.asset_id.unwrap();
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let payload = b"deposit".to_vec();
+            let amount = 123;
+
+            // An empty payload is a plain transfer
+            let call = warp_route
+                .methods()
+                .transfer_remote_with_payload(
+                    TEST_REMOTE_DOMAIN,
+                    recipient,
+                    amount,
+                    Bytes(vec![]),
+                    None,
+                    None,
+                )
+                .call()
+                .await;
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.unwrap_err()), "EmptyPayload");
+
+            wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    amount,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            let call = warp_route
+                .methods()
+                .transfer_remote_with_payload(
+                    TEST_REMOTE_DOMAIN,
+                    recipient,
+                    amount,
+                    Bytes(payload.clone()),
+                    None,
+                    None,
+                )
+                .with_contracts(&[&mailbox])
+                .with_contract_ids(&[warp_route_id.into(), post_dispatch_id.into()])
+                .call()
+                .await
+                .unwrap();
+
+            let messages = dispatched_messages(&call).unwrap();
+            assert_eq!(messages.len(), 1);
+
+            let remote_amount =
+                U256::from(amount) * U256::exp10((REMOTE_DECIMALS - DECIMALS) as usize);
+            // The sender of the transfer precedes the payload
+            let sender = Bits256(wallet.address().hash().into());
+            let expected_body = build_message_body(
+                recipient,
+                remote_amount,
+                &transfer_payload(sender, &payload),
+            );
+            assert_eq!(messages[0].body, expected_body.0);
+        }
+
+        /// ============ claim_as_owner ============
+        #[tokio::test]
+        async fn test_claim_as_owner() {
+            let (config, warp_route, warp_route_id, _, mailbox_id, post_dispatch_id, _) =
+                get_collateral_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let provider = wallet.provider().unwrap();
+            let asset = warp_route
+                .methods()
+                .get_token_info()
+                .call()
+                .await
+                .unwrap()
+                .value
+                .asset_id;
+
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let amount = 190;
+
+            let (_, _) = wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    amount,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            // Transfer remote
+            warp_route
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
+                .with_contract_ids(&[
+                    warp_route_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                ])
+                .call()
+                .await
+                .unwrap();
+
+            let contract_balance_before =
+                get_contract_balance(provider, warp_route.contract_id(), asset)
+                    .await
+                    .unwrap();
+
+            // Claim the balance as the owner
+            warp_route
+                .methods()
+                .claim(Some(config.asset_id.unwrap()))
+                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .call()
+                .await
+                .unwrap();
+
+            let contract_balance_after =
+                get_contract_balance(provider, warp_route.contract_id(), asset)
+                    .await
+                    .unwrap();
+
+            assert_eq!(contract_balance_before - amount, contract_balance_after);
+        }
+
+        /// ============ reconcile_not_supported ============
+        #[tokio::test]
+        async fn test_reconcile_not_supported() {
+            let (_, warp_route, ..) = get_collateral_contract_instance().await;
+
+            let call = warp_route.methods().reconcile().call().await;
+            assert!(call.is_err());
+            assert_eq!(
+                get_revert_reason(call.unwrap_err()),
+                "ReconcileNotSupported"
+            );
+        }
+
+        /// ============ transfer_remote_with_zero_amount ============
+        #[tokio::test]
+        async fn test_zero_and_negative_amount_transfer_remote() {
+            let (_, warp_route, .., mailbox_id, post_dispatch_id, _) =
+                get_collateral_contract_instance().await;
+
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let _asset = warp_route
+                .methods()
+                .get_token_info()
+                .call()
+                .await
+                .unwrap()
+                .value
+                .asset_id;
+
+            // Test with a zero transfer
+            let amount_zero = 0u64;
+
+            let call_zero = warp_route
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount_zero, None, None)
+                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .with_contract_ids(&[mailbox_id.into(), post_dispatch_id.into()])
+                .call()
+                .await;
+
+            // Zero amounts are rejected before anything is dispatched
+            assert!(call_zero.is_err());
+            assert_eq!(
+                get_revert_reason(call_zero.unwrap_err()),
+                "AmountRoundsToZero"
+            );
+
+            // Also when no scaling is needed
+            warp_route
+                .methods()
+                .set_remote_router_decimals(
+                    Bits256::from_hex_str(REMOTE_ROUTER_ADDRESS).unwrap(),
+                    DECIMALS,
+                )
+                .call()
+                .await
+                .unwrap();
+
+            let call_zero = warp_route
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount_zero, None, None)
+                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .with_contract_ids(&[mailbox_id.into(), post_dispatch_id.into()])
+                .call()
+                .await;
+
+            assert!(call_zero.is_err());
+            assert_eq!(
+                get_revert_reason(call_zero.unwrap_err()),
+                "AmountRoundsToZero"
+            );
+        }
+
+        /// ============ pause_and_unpause ============
+        #[tokio::test]
+        async fn test_pause_and_unpause() {
+            let (_, warp_route, warp_route_id, _, mailbox_id, post_dispatch_id, _) =
+                get_collateral_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let asset = warp_route
+                .methods()
+                .get_token_info()
+                .call()
+                .await
+                .unwrap()
+                .value
+                .asset_id;
+
+            // Pause the contract
+            warp_route.methods().pause().call().await.unwrap();
+
+            let is_paused = warp_route.methods().is_paused().call().await.unwrap().value;
+            assert!(is_paused);
+
+            let amount = 1;
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+
+            // Attempt an operation that should fail when paused
+            let call = warp_route
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
+                .call()
+                .await;
+            assert_eq!(get_revert_reason(call.unwrap_err()), "Paused");
+
+            // Unpause the contract
+            warp_route.methods().unpause().call().await.unwrap();
+
+            let (_, _) = wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    amount,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            let call = warp_route
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
+                .with_contract_ids(&[
+                    warp_route_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                ])
+                .call()
+                .await;
+
+            assert!(call.is_ok());
+        }
+    }
+
+    /// COLLATERAL_FEE Token Mode Test Cases
+    #[cfg(test)]
+    mod collateral_fee {
+        use super::*;
+
+        static COLLATERAL_FEE_CONFIG: Lazy<Mutex<WarpRouteConfig>> = Lazy::new(|| {
+            Mutex::new(WarpRouteConfig {
+                token_mode: WarpRouteTokenMode::COLLATERAL_FEE,
+                token_name: Some(TOKEN_NAME.to_string()),
+                token_symbol: Some(TOKEN_SYMBOL.to_string()),
+                decimals: Some(DECIMALS),
+                total_supply: Some(TOTAL_SUPPLY),
+                asset_id: Some(get_collateral_asset()),
+            })
+        });
+
+        const FEE_BPS: u64 = 100;
+
+        async fn get_collateral_fee_contract_instance() -> (
+            AssetId,
+            WarpRoute<WalletUnlocked>,
+            Vec<Bech32ContractId>,
+            Mailbox<WalletUnlocked>,
+        ) {
+            let (
+                warp_route,
+                contract_id,
+                mailbox,
+                mailbox_id,
+                post_dispatch_id,
+                _,
+                default_ism_id,
+                collateral_token_id,
+            ) = get_contract_instance(&COLLATERAL_FEE_CONFIG).await;
+
+            warp_route.methods().set_fee(FEE_BPS).call().await.unwrap();
+
+            let contract_ids = vec![
+                contract_id.into(),
+                mailbox_id.into(),
+                post_dispatch_id.into(),
+                default_ism_id.into(),
+            ];
+
+            (collateral_token_id, warp_route, contract_ids, mailbox)
+        }
+
+        /// Deposits `amount` and transfers it to the remote domain
+        async fn deposit_and_transfer_remote(
+            warp_route: &WarpRoute<WalletUnlocked>,
+            contract_ids: &[Bech32ContractId],
+            asset: AssetId,
+            amount: u64,
+        ) -> CallResponse<Bits256> {
+            warp_route
+                .account()
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    amount,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            warp_route
+                .methods()
+                .transfer_remote(
+                    TEST_REMOTE_DOMAIN,
+                    Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+                    amount,
+                    None,
+                    None,
+                )
+                .with_contract_ids(contract_ids)
+                .call()
+                .await
+                .unwrap()
+        }
+
+        async fn accounted_balances(warp_route: &WarpRoute<WalletUnlocked>) -> (u64, u64) {
+            let collateral = warp_route
+                .methods()
+                .collateral_balance()
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let fees = warp_route
+                .methods()
+                .accrued_fees()
+                .call()
+                .await
+                .unwrap()
+                .value;
+            (collateral, fees)
+        }
+
+        /// ============ set_fee ============
+        #[tokio::test]
+        async fn test_set_fee() {
+            let (_, warp_route, ..) = get_collateral_fee_contract_instance().await;
+
+            let fee = warp_route.methods().fee().call().await.unwrap().value;
+            assert_eq!(fee, FEE_BPS);
+
+            let call = warp_route.methods().set_fee(250).call().await.unwrap();
+            let events = call.decode_logs_with_type::<FeeSetEvent>().unwrap();
+            assert_eq!(events, vec![FeeSetEvent { fee_bps: 250 }]);
+
+            let fee = warp_route.methods().fee().call().await.unwrap().value;
+            assert_eq!(fee, 250);
+
+            // A fee of the whole amount would bridge nothing
+            let call = warp_route.methods().set_fee(10_000).call().await;
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.unwrap_err()), "FeeTooHigh");
+
+            let non_owner_wallet =
+                funded_wallet_with_private_key(&warp_route.account(), TEST_NON_OWNER_PRIVATE_KEY)
+                    .await;
+            let call = warp_route
+                .with_account(non_owner_wallet)
+                .methods()
+                .set_fee(0)
+                .call()
+                .await;
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.unwrap_err()), "NotOwner");
+        }
+
+        /// ============ transfer_remote_retains_fee ============
+        #[tokio::test]
+        async fn test_transfer_remote_retains_fee() {
+            let (asset, warp_route, contract_ids, _) = get_collateral_fee_contract_instance().await;
+
+            let amount = 10_000;
+            let fee = amount * FEE_BPS / 10_000;
+
+            let call = deposit_and_transfer_remote(&warp_route, &contract_ids, asset, amount).await;
+
+            let events = call
+                .decode_logs_with_type::<TransferFeeRetainedEvent>()
+                .unwrap();
+            assert_eq!(
+                events,
+                vec![TransferFeeRetainedEvent {
+                    destination: TEST_REMOTE_DOMAIN,
+                    fee,
+                }]
+            );
+
+            // Only the amount after the fee is bridged
+            let sent = call
+                .decode_logs_with_type::<SentTransferRemoteAmountEvent>()
+                .unwrap();
+            assert_eq!(
+                sent[0].amount,
+                U256::from(amount - fee) * U256::exp10((REMOTE_DECIMALS - DECIMALS) as usize)
+            );
+
+            assert_eq!(accounted_balances(&warp_route).await, (amount - fee, fee));
+
+            // Fees round down in favour of the sender
+            let small_amount = 10_000 / FEE_BPS - 1;
+            let call =
+                deposit_and_transfer_remote(&warp_route, &contract_ids, asset, small_amount).await;
+            assert!(call
+                .decode_logs_with_type::<TransferFeeRetainedEvent>()
+                .unwrap()
+                .is_empty());
+            assert_eq!(
+                accounted_balances(&warp_route).await,
+                (amount - fee + small_amount, fee)
+            );
+        }
+
+        /// ============ claim_fees ============
+        #[tokio::test]
+        async fn test_claim_fees() {
+            let (asset, warp_route, contract_ids, _) = get_collateral_fee_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let provider = wallet.provider().unwrap();
+            let amount = 50_000;
+            let fee = amount * FEE_BPS / 10_000;
+
+            deposit_and_transfer_remote(&warp_route, &contract_ids, asset, amount).await;
+
+            let beneficiary_balance_before = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+
+            let call = warp_route
+                .methods()
+                .claim(Some(asset))
+                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .call()
+                .await
+                .unwrap();
+
+            let events = call.decode_logs_with_type::<ClaimEvent>().unwrap();
+            assert_eq!(events[0].amount, fee);
+
+            let beneficiary_balance_after = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+            assert_eq!(beneficiary_balance_after - beneficiary_balance_before, fee);
+
+            // The collateral stays locked
+            let contract_balance = get_contract_balance(provider, warp_route.contract_id(), asset)
+                .await
+                .unwrap();
+            assert_eq!(contract_balance, amount - fee);
+            assert_eq!(accounted_balances(&warp_route).await, (amount - fee, 0));
+        }
+
+        /// ============ handle_message_keeps_fees ============
+        #[tokio::test]
+        async fn test_handle_message_keeps_fees() {
+            let (asset, warp_route, contract_ids, mailbox) =
+                get_collateral_fee_contract_instance().await;
+
+            let amount = 10_000;
+            let fee = amount * FEE_BPS / 10_000;
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let scale = U256::exp10((REMOTE_DECIMALS - DECIMALS) as usize);
+
+            deposit_and_transfer_remote(&warp_route, &contract_ids, asset, amount).await;
+
+            // Paying out the whole balance would use the retained fees
+            let call = process_transfer_message(
+                &mailbox,
+                &warp_route,
+                contract_ids.clone(),
+                U256::from(amount) * scale,
+                recipient,
+            )
+            .await;
+            assert!(call.is_err());
+            assert_eq!(
+                get_revert_reason(call.unwrap_err()),
+                "InsufficientCollateral"
+            );
+
+            process_transfer_message(
+                &mailbox,
+                &warp_route,
+                contract_ids,
+                U256::from(amount - fee) * scale,
+                recipient,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(accounted_balances(&warp_route).await, (0, fee));
+        }
+
+        /// ============ reconcile_unsolicited_deposit ============
+        #[tokio::test]
+        async fn test_reconcile_unsolicited_deposit() {
+            let (asset, warp_route, contract_ids, _) = get_collateral_fee_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let amount = 10_000;
+            let fee = amount * FEE_BPS / 10_000;
+            let pending = 500;
+
+            deposit_and_transfer_remote(&warp_route, &contract_ids, asset, amount).await;
+
+            // Assets sent for a pending transfer are not accounted as collateral or fees
+            wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    pending,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(accounted_balances(&warp_route).await, (amount - fee, fee));
+
+            // The surplus is reported but not swept into the fees
+            let call = warp_route.methods().reconcile().call().await.unwrap();
+            let events = call
+                .decode_logs_with_type::<BalanceReconciledEvent>()
+                .unwrap();
+            assert_eq!(
+                events,
+                vec![BalanceReconciledEvent {
+                    surplus: pending,
+                    shortfall: 0,
+                }]
+            );
+            assert_eq!(accounted_balances(&warp_route).await, (amount - fee, fee));
+
+            // Claiming only takes the accrued fees
+            let call = warp_route
+                .methods()
+                .claim(Some(asset))
+                .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                .call()
+                .await
+                .unwrap();
+            let events = call.decode_logs_with_type::<ClaimEvent>().unwrap();
+            assert_eq!(events[0].amount, fee);
+
+            // The pending transfer can still be sent
+            let pending_fee = pending * FEE_BPS / 10_000;
+            warp_route
+                .methods()
+                .transfer_remote(
+                    TEST_REMOTE_DOMAIN,
+                    Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+                    pending,
+                    None,
+                    None,
+                )
+                .with_contract_ids(&contract_ids)
+                .call()
+                .await
+                .unwrap();
+            assert_eq!(
+                accounted_balances(&warp_route).await,
+                (amount - fee + pending - pending_fee, pending_fee)
+            );
+        }
+
+        /// ============ reconcile_unauthorized ============
+        #[tokio::test]
+        async fn test_reconcile_unauthorized() {
+            let (_, warp_route, ..) = get_collateral_fee_contract_instance().await;
+
+            let non_owner_wallet =
+                funded_wallet_with_private_key(&warp_route.account(), TEST_NON_OWNER_PRIVATE_KEY)
+                    .await;
+            let call = warp_route
+                .with_account(non_owner_wallet)
+                .methods()
+                .reconcile()
+                .call()
+                .await;
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.unwrap_err()), "NotOwner");
+        }
+    }
+
+    /// SYNTHETIC Token Mode Test Cases
+    #[cfg(test)]
+    mod synthetic {
+        use super::*;
+
+        static SYNTHETIC_CONFIG: Lazy<Mutex<WarpRouteConfig>> = Lazy::new(|| {
+            Mutex::new(WarpRouteConfig {
+                token_mode: WarpRouteTokenMode::SYNTHETIC,
+                token_name: Some(TOKEN_NAME.to_string()),
+                token_symbol: Some(TOKEN_SYMBOL.to_string()),
+                decimals: Some(DECIMALS),
+                total_supply: Some(TOTAL_SUPPLY),
+                asset_id: Some(get_native_asset()),
+            })
+        });
+
+        async fn get_synthetic_contract_instance() -> (
+            WarpRouteConfig,
+            WarpRoute<WalletUnlocked>,
+            ContractId,
+            Mailbox<WalletUnlocked>,
+            ContractId,
+            ContractId,
+            ContractId,
+            ContractId,
+        ) {
+            let (
+                warp_route,
+                contract_id,
+                mailbox,
+                mailbox_id,
+                post_dispatch_id,
+                recipient_id,
+                ism_id,
+                _,
+            ) = get_contract_instance(&SYNTHETIC_CONFIG).await;
+
+            let token_info = warp_route
+                .methods()
+                .get_token_info()
+                .call()
+                .await
+                .unwrap()
+                .value;
+
+            let mut config = SYNTHETIC_CONFIG.lock().await.clone();
+            config.asset_id = Some(token_info.asset_id);
+
+            (
+                config,
+                warp_route,
+                contract_id,
+                mailbox,
+                mailbox_id,
+                post_dispatch_id,
+                recipient_id,
+                ism_id,
+            )
+        }
+
+        /// ============ get_token_info ============
+        #[tokio::test]
+        async fn test_get_token_info() {
+            let (mut config, warp_route, ..) = get_synthetic_contract_instance().await;
+
+            let token_info = warp_route
+                .methods()
+                .get_token_info()
+                .call()
+                .await
+                .unwrap()
+                .value;
+
+            config.asset_id = Some(token_info.asset_id);
+
+            assert_eq!(token_info.name, config.token_name.unwrap());
+            assert_eq!(token_info.symbol, config.token_symbol.unwrap());
+            assert_eq!(token_info.decimals, config.decimals.unwrap());
+            assert_eq!(token_info.total_supply, config.total_supply.unwrap());
+        }
+
+        /// ============ get_token_mode ============
+        #[tokio::test]
+        async fn test_get_token_mode() {
+            let (config, warp_route, ..) = get_synthetic_contract_instance().await;
+
+            let token_mode = warp_route
+                .methods()
+                .get_token_mode()
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(token_mode, config.token_mode);
+        }
+
+        /// ============ SRC-20 ============
+        #[tokio::test]
+        async fn test_src20_metadata() {
+            let (config, warp_route, ..) = get_synthetic_contract_instance().await;
+            let asset = config.asset_id.unwrap();
+
+            let total_assets = warp_route
+                .methods()
+                .total_assets()
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let total_supply = warp_route
+                .methods()
+                .total_supply(asset)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let name = warp_route.methods().name(asset).call().await.unwrap().value;
+            let symbol = warp_route
+                .methods()
+                .symbol(asset)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let decimals = warp_route
+                .methods()
+                .decimals(asset)
+                .call()
+                .await
+                .unwrap()
+                .value;
+
+            assert_eq!(total_assets, 1);
+            assert_eq!(total_supply, config.total_supply);
+            assert_eq!(name, config.token_name);
+            assert_eq!(symbol, config.token_symbol);
+            assert_eq!(decimals, config.decimals);
+
+            // Assets which are not issued by the warp route have no metadata
+            let other_asset = AssetId::BASE;
+            let total_supply = warp_route
+                .methods()
+                .total_supply(other_asset)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let name = warp_route
+                .methods()
+                .name(other_asset)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let decimals = warp_route
+                .methods()
+                .decimals(other_asset)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(total_supply, None);
+            assert_eq!(name, None);
+            assert_eq!(decimals, None);
+        }
+
+        #[tokio::test]
+        async fn test_src20_total_supply_tracks_transfers() {
+            let (config, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+            let asset = config.asset_id.unwrap();
+            let amount = 10u64.pow(DECIMALS as u32);
+
+            process_transfer_message(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                ],
+                U256::from(amount) * U256::exp10((REMOTE_DECIMALS - DECIMALS) as usize),
+                Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            )
+            .await
+            .unwrap();
+
+            let total_supply = warp_route
+                .methods()
+                .total_supply(asset)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(total_supply, Some(config.total_supply.unwrap() + amount));
+        }
+
+        /// ============ SRC-7 ============
+        #[tokio::test]
+        async fn test_src7_metadata() {
+            let (config, warp_route, ..) = get_synthetic_contract_instance().await;
+            let asset = config.asset_id.unwrap();
+            let origin_token = Bits256::from_hex_str(REMOTE_ROUTER_ADDRESS).unwrap();
+
+            let bridge = warp_route
+                .methods()
+                .metadata(asset, "bridge".to_string())
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(bridge, Some(Metadata::String("Hyperlane".to_string())));
+
+            // The origin is unknown until it is set by the owner
+            let origin_chain = warp_route
+                .methods()
+                .metadata(asset, "origin_chain".to_string())
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(origin_chain, None);
+
+            let call = warp_route
+                .methods()
+                .set_origin_token(TEST_REMOTE_DOMAIN, origin_token)
+                .call()
+                .await
+                .unwrap();
+            let events = call.decode_logs_with_type::<OriginTokenSetEvent>().unwrap();
+            assert_eq!(
+                events,
+                vec![OriginTokenSetEvent {
+                    domain: TEST_REMOTE_DOMAIN,
+                    token: origin_token,
+                }]
+            );
+
+            let origin_chain = warp_route
+                .methods()
+                .metadata(asset, "origin_chain".to_string())
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let token = warp_route
+                .methods()
+                .metadata(asset, "origin_token".to_string())
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let unknown = warp_route
+                .methods()
+                .metadata(asset, "unknown".to_string())
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let other_asset = warp_route
+                .methods()
+                .metadata(AssetId::BASE, "origin_token".to_string())
+                .call()
+                .await
+                .unwrap()
+                .value;
+
+            assert_eq!(origin_chain, Some(Metadata::Int(TEST_REMOTE_DOMAIN as u64)));
+            assert_eq!(token, Some(Metadata::B256(origin_token)));
+            assert_eq!(unknown, None);
+            assert_eq!(other_asset, None);
+        }
+
+        #[tokio::test]
+        async fn test_set_origin_token_unauthorized() {
+            let (_, warp_route, ..) = get_synthetic_contract_instance().await;
+            let non_owner_wallet =
+                funded_wallet_with_private_key(&warp_route.account(), TEST_NON_OWNER_PRIVATE_KEY)
+                    .await;
+
+            let call = warp_route
+                .with_account(non_owner_wallet)
+                .methods()
+                .set_origin_token(TEST_REMOTE_DOMAIN, Bits256::zeroed())
+                .call()
+                .await;
+
+            assert_eq!(get_revert_reason(call.unwrap_err()), "NotOwner");
+        }
+
+        // ============ transfer_remote ============
+        #[tokio::test]
+        async fn test_transfer_remote() {
+            let (
+                config,
+                warp_route,
+                _,
+                mailbox,
+                mailbox_id,
+                post_dispatch_id,
+                recipient_id,
+                ism_id,
+            ) = get_synthetic_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let provider = wallet.provider().unwrap();
+            let asset = config.asset_id.unwrap();
+
+            let local_decimals = config.decimals.unwrap() as u32;
+            let remote_decimals = REMOTE_DECIMALS as u32;
+            let remote_decimal_amount = 10_u64.pow(remote_decimals);
+
+            let amount = 10_u64.pow(remote_decimals - local_decimals);
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+
+            let wallet_balance_before_mint = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+
+            assert_eq!(wallet_balance_before_mint, config.total_supply.unwrap());
+
+            // For testing the synthetic asset, we actually need to have the tokens
+            // Since the asset is managed by the warp route - we can mock the asset minting with handle call
+            // in order to have `amount` we need to send `remote_decimal_amount` message to local warp route
+
+            trigger_handle_by_sending_message_from_mailbox(
+                &mailbox,
+                &warp_route,
+                vec![
+                    warp_route.contract_id().into(),
+                    mailbox.contract_id().into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                    recipient_id.into(),
+                ],
+                remote_decimal_amount,
+                Some(Bits256(Address::from(wallet.address()).into())),
+            )
+            .await;
+
+            let wallet_balance = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+
+            assert_eq!(wallet_balance_before_mint + amount, wallet_balance);
+
+            let (_, _) = wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    amount,
+                    asset,
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            let call = warp_route
+                .methods()
+                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
+                .with_contract_ids(&[mailbox_id.into(), post_dispatch_id.into()])
+                .call()
+                .await
+                .unwrap();
+
+            let logs = call
+                .decode_logs_with_type::<SentTransferRemoteEvent>()
+                .unwrap();
+            let log = logs[0].clone();
+            assert_eq!(log.destination, TEST_REMOTE_DOMAIN);
+            assert_eq!(log.recipient, recipient);
+
+            let wallet_balance_after = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+
+            assert_eq!(wallet_balance - amount, wallet_balance_after);
+        }
+
+        /// ============ handle_message ============
+        #[tokio::test]
+        async fn test_handle_message() {
+            // Get the contract instance and the config
+            let (config, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let sender = Bits256::from_hex_str(REMOTE_ROUTER_ADDRESS).unwrap();
+            let amount = 100_000_000_000_000_000;
+
+            let recipient_address = Address::from_str(TEST_RECIPIENT).unwrap();
+
+            let remote_decimals = warp_route
+                .methods()
+                .remote_router_decimals(sender)
+                .call()
+                .await
+                .unwrap()
+                .value as u32;
+
+            let local_decimals = config.decimals.unwrap() as u32;
+
+            let provider = wallet.provider().unwrap();
+            let recipient_balance_before = get_balance(
+                provider,
+                &recipient_address.into(),
+                config.asset_id.unwrap(),
+            )
+            .await
+            .unwrap();
+
+            trigger_handle_by_sending_message_from_mailbox(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                ],
+                amount,
+                None,
+            )
+            .await;
+
+            let recipient_balance = get_balance(
+                provider,
+                &recipient_address.into(),
+                config.asset_id.unwrap(),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                recipient_balance_before + amount / 10u64.pow(remote_decimals - local_decimals),
+                recipient_balance
+            );
+        }
+
+        /// ============ handle_message_contract_recipient ============
+        #[tokio::test]
+        async fn test_handle_message_contract_recipient() {
+            let (config, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let asset = config.asset_id.unwrap();
+            let recipient_id = register_contract_recipient(&warp_route).await;
+            let amount = 100_000_000_000_000_000;
+
+            trigger_handle_by_sending_message_from_mailbox(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                    recipient_id.into(),
+                ],
+                amount,
+                Some(Bits256(recipient_id.into())),
+            )
+            .await;
+
+            let provider = wallet.provider().unwrap();
+            let recipient_balance = get_contract_balance(provider, &recipient_id.into(), asset)
+                .await
+                .unwrap();
+            assert_eq!(
+                recipient_balance,
+                amount / 10u64.pow(REMOTE_DECIMALS as u32 - DECIMALS as u32)
+            );
+        }
+
+        /// ============ handle_message_amount_above_u64 ============
+        #[tokio::test]
+        async fn test_handle_message_amount_above_u64() {
+            let (config, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let provider = wallet.provider().unwrap();
+            let asset = config.asset_id.unwrap();
+            let recipient = Bits256(Address::from(wallet.address()).into());
+
+            // 100 tokens in 18 decimals do not fit in a u64
+            let amount = U256::from(100) * U256::exp10(REMOTE_DECIMALS as usize);
+            assert!(amount > U256::from(u64::MAX));
+
+            let balance_before = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+
+            process_transfer_message(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                ],
+                amount,
+                recipient,
+            )
+            .await
+            .unwrap();
+
+            let balance_after = get_balance(provider, wallet.address(), asset)
+                .await
+                .unwrap();
+            assert_eq!(
+                balance_after - balance_before,
+                100 * 10u64.pow(DECIMALS as u32)
+            );
+        }
+
+        /// ============ handle_message_dust ============
+        #[tokio::test]
+        async fn test_handle_message_dust() {
+            let (_, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let recipient = Bits256(Address::from(wallet.address()).into());
+
+            // Less than one unit in the local decimals
+            let amount = U256::exp10((REMOTE_DECIMALS - DECIMALS) as usize) - 1;
+
+            let call = process_transfer_message(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                ],
+                amount,
+                recipient,
+            )
+            .await;
+
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.unwrap_err()), "AmountRoundsToZero");
+        }
+
+        /// ============ handle_message_exceeding_local_range ============
+        #[tokio::test]
+        async fn test_handle_message_exceeding_local_range() {
+            let (_, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let recipient = Bits256(Address::from(wallet.address()).into());
+
+            // One more than u64::MAX after scaling to the local decimals
+            let amount =
+                (U256::from(u64::MAX) + 1) * U256::exp10((REMOTE_DECIMALS - DECIMALS) as usize);
+
+            let call = process_transfer_message(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                ],
+                amount,
+                recipient,
+            )
+            .await;
 
-            let token_mode = warp_route
-                .methods()
-                .get_token_mode()
-                .call()
-                .await
-                .unwrap()
-                .value;
-            assert_eq!(token_mode, config.token_mode);
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.unwrap_err()), "AmountNotConvertible");
         }
 
-        // ============ transfer_remote ============
+        /// ============ handle_message_with_payload ============
         #[tokio::test]
-        async fn test_transfer_remote() {
-            let (
-                config,
-                warp_route,
-                _,
-                mailbox,
-                mailbox_id,
-                post_dispatch_id,
-                recipient_id,
-                ism_id,
-            ) = get_synthetic_contract_instance().await;
+        async fn test_handle_message_with_payload() {
+            let (config, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
 
             let wallet = warp_route.account();
             let provider = wallet.provider().unwrap();
             let asset = config.asset_id.unwrap();
+            let recipient = register_token_recipient(&warp_route).await;
+            let recipient_id = ContractId::from(recipient.contract_id());
+            let sender = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let payload = b"deposit".to_vec();
+            let local_amount = 10u64.pow(DECIMALS as u32);
 
-            let local_decimals = config.decimals.unwrap() as u32;
-            let remote_decimals = REMOTE_DECIMALS as u32;
-            let remote_decimal_amount = 10_u64.pow(remote_decimals);
-
-            let amount = 10_u64.pow(remote_decimals - local_decimals);
-            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
-
-            let wallet_balance_before_mint = get_balance(provider, wallet.address(), asset)
-                .await
-                .unwrap();
-
-            assert_eq!(wallet_balance_before_mint, config.total_supply.unwrap());
-
-            // For testing the synthetic asset, we actually need to have the tokens
-            // Since the asset is managed by the warp route - we can mock the asset minting with handle call
-            // in order to have `amount` we need to send `remote_decimal_amount` message to local warp route
-
-            trigger_handle_by_sending_message_from_mailbox(
+            process_transfer_message_with_payload(
                 &mailbox,
                 &warp_route,
                 vec![
-                    warp_route.contract_id().into(),
-                    mailbox.contract_id().into(),
+                    contract_id.into(),
+                    mailbox_id.into(),
                     post_dispatch_id.into(),
                     ism_id.into(),
                     recipient_id.into(),
                 ],
-                remote_decimal_amount,
-                Some(Bits256(Address::from(wallet.address()).into())),
+                U256::exp10(REMOTE_DECIMALS as usize),
+                Bits256(recipient_id.into()),
+                &transfer_payload(sender, &payload),
             )
-            .await;
-
-            let wallet_balance = get_balance(provider, wallet.address(), asset)
-                .await
-                .unwrap();
-
-            assert_eq!(wallet_balance_before_mint + amount, wallet_balance);
-
-            let (_, _) = wallet
-                .force_transfer_to_contract(
-                    warp_route.contract_id(),
-                    amount,
-                    asset,
-                    TxPolicies::default(),
-                )
-                .await
-                .unwrap();
+            .await
+            .unwrap();
 
-            let call = warp_route
-                .methods()
-                .transfer_remote(TEST_REMOTE_DOMAIN, recipient, amount, None, None)
-                .with_contract_ids(&[mailbox_id.into(), post_dispatch_id.into()])
-                .call()
+            let recipient_balance = get_contract_balance(provider, recipient.contract_id(), asset)
                 .await
                 .unwrap();
+            assert_eq!(recipient_balance, local_amount);
 
-            let logs = call
-                .decode_logs_with_type::<SentTransferRemoteEvent>()
-                .unwrap();
-            let log = logs[0].clone();
-            assert_eq!(log.destination, TEST_REMOTE_DOMAIN);
-            assert_eq!(log.recipient, recipient);
-
-            let wallet_balance_after = get_balance(provider, wallet.address(), asset)
-                .await
-                .unwrap();
+            let methods = recipient.methods();
+            assert_eq!(methods.handled().call().await.unwrap().value, 1);
+            // The callback receives the sender of the transfer, not the remote router
+            assert_eq!(methods.last_sender().call().await.unwrap().value, sender);
+            assert_eq!(
+                methods.last_amount().call().await.unwrap().value,
+                local_amount
+            );
+            assert_eq!(
+                methods.last_payload().call().await.unwrap().value,
+                Some(Bytes(payload))
+            );
 
-            assert_eq!(wallet_balance - amount, wallet_balance_after);
+            // The tokens are credited before the callback
+            assert_eq!(
+                methods.balance_on_handle().call().await.unwrap().value,
+                local_amount
+            );
         }
 
-        /// ============ handle_message ============
+        /// ============ handle_message_with_payload_rejected ============
         #[tokio::test]
-        async fn test_handle_message() {
-            // Get the contract instance and the config
+        async fn test_handle_message_with_payload_rejected() {
             let (config, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
                 get_synthetic_contract_instance().await;
 
             let wallet = warp_route.account();
-            let sender = Bits256::from_hex_str(REMOTE_ROUTER_ADDRESS).unwrap();
-            let amount = 100_000_000_000_000_000;
+            let provider = wallet.provider().unwrap();
+            let asset = config.asset_id.unwrap();
+            let recipient = register_token_recipient(&warp_route).await;
+            let recipient_id = ContractId::from(recipient.contract_id());
+            let contract_ids: Vec<Bech32ContractId> = vec![
+                contract_id.into(),
+                mailbox_id.into(),
+                post_dispatch_id.into(),
+                ism_id.into(),
+                recipient_id.into(),
+            ];
 
-            let recipient_address = Address::from_str(TEST_RECIPIENT).unwrap();
+            let sender = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
 
-            let remote_decimals = warp_route
-                .methods()
-                .remote_router_decimals(sender)
-                .call()
+            recipient.methods().set_rejects(true).call().await.unwrap();
+
+            // A reverting callback reverts the whole delivery, including the mint
+            let call = process_transfer_message_with_payload(
+                &mailbox,
+                &warp_route,
+                contract_ids.clone(),
+                U256::exp10(REMOTE_DECIMALS as usize),
+                Bits256(recipient_id.into()),
+                &transfer_payload(sender, b"deposit"),
+            )
+            .await;
+            assert!(call.is_err());
+
+            let recipient_balance = get_contract_balance(provider, recipient.contract_id(), asset)
                 .await
-                .unwrap()
-                .value as u32;
+                .unwrap();
+            assert_eq!(recipient_balance, 0);
+            assert_eq!(recipient.methods().handled().call().await.unwrap().value, 0);
 
-            let local_decimals = config.decimals.unwrap() as u32;
+            // The transfer goes through once the recipient accepts it
+            recipient.methods().set_rejects(false).call().await.unwrap();
 
-            let provider = wallet.provider().unwrap();
-            let recipient_balance_before = get_balance(
-                provider,
-                &recipient_address.into(),
-                config.asset_id.unwrap(),
+            process_transfer_message_with_payload(
+                &mailbox,
+                &warp_route,
+                contract_ids,
+                U256::exp10(REMOTE_DECIMALS as usize),
+                Bits256(recipient_id.into()),
+                &transfer_payload(sender, b"deposit"),
             )
             .await
             .unwrap();
 
-            trigger_handle_by_sending_message_from_mailbox(
+            assert_eq!(recipient.methods().handled().call().await.unwrap().value, 1);
+        }
+
+        /// ============ handle_message_with_payload_to_address ============
+        #[tokio::test]
+        async fn test_handle_message_with_payload_to_address() {
+            let (config, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+
+            let provider = warp_route.account().provider().unwrap().clone();
+            let asset = config.asset_id.unwrap();
+            let recipient = Bits256::from_hex_str(TEST_RECIPIENT).unwrap();
+            let sender = Bits256(warp_route.account().address().hash().into());
+
+            // The tokens are delivered to a recipient which is not a registered contract,
+            // without calling it back
+            let call = process_transfer_message_with_payload(
                 &mailbox,
                 &warp_route,
                 vec![
@@ -1230,23 +2962,54 @@ mod warp_route {
                     post_dispatch_id.into(),
                     ism_id.into(),
                 ],
-                amount,
-                None,
-            )
-            .await;
-
-            let recipient_balance = get_balance(
-                provider,
-                &recipient_address.into(),
-                config.asset_id.unwrap(),
+                U256::exp10(REMOTE_DECIMALS as usize),
+                recipient,
+                &transfer_payload(sender, b"deposit"),
             )
             .await
             .unwrap();
 
+            let events = call.decode_logs_with_type::<PayloadDroppedEvent>().unwrap();
             assert_eq!(
-                recipient_balance_before + amount / 10u64.pow(remote_decimals - local_decimals),
-                recipient_balance
+                events,
+                vec![PayloadDroppedEvent {
+                    origin: TEST_REMOTE_DOMAIN,
+                    sender,
+                    recipient,
+                }]
             );
+
+            let recipient_address = Bech32Address::from(Address::from(recipient.0));
+            let recipient_balance = get_balance(&provider, &recipient_address, asset)
+                .await
+                .unwrap();
+            assert_eq!(recipient_balance, 10u64.pow(DECIMALS as u32));
+        }
+
+        /// ============ handle_message_with_truncated_payload ============
+        #[tokio::test]
+        async fn test_handle_message_with_truncated_payload() {
+            let (_, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, _, ism_id) =
+                get_synthetic_contract_instance().await;
+
+            // A payload has to follow the sender
+            let call = process_transfer_message_with_payload(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                ],
+                U256::exp10(REMOTE_DECIMALS as usize),
+                Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+                &[1u8; 32],
+            )
+            .await;
+
+            assert!(call.is_err());
+            assert_eq!(get_revert_reason(call.unwrap_err()), "InvalidMessageBody");
         }
 
         /// ============ sending_more_than_minted ============
@@ -1368,6 +3131,14 @@ mod warp_route {
                 amount * 10u64.pow(remote_decimals as u32 - DECIMALS as u32)
             );
 
+            let logs = call
+                .decode_logs_with_type::<SentTransferRemoteAmountEvent>()
+                .unwrap();
+            assert_eq!(
+                logs[0].amount,
+                U256::from(amount) * U256::exp10(remote_decimals as usize - DECIMALS as usize)
+            );
+
             let contract_balance_after =
                 get_contract_balance(provider, warp_route.contract_id(), asset)
                     .await
@@ -1438,6 +3209,50 @@ mod warp_route {
             );
         }
 
+        /// ============ handle_message_native_contract_recipient ============
+        #[tokio::test]
+        async fn test_handle_message_native_contract_recipient() {
+            let (_, warp_route, contract_id, mailbox, mailbox_id, post_dispatch_id, ism_id) =
+                get_native_contract_instance().await;
+
+            let wallet = warp_route.account();
+            let recipient_id = register_contract_recipient(&warp_route).await;
+            let amount = 181_555_123_444_000_000;
+            let local_amount = amount / 10u64.pow(REMOTE_DECIMALS as u32 - DECIMALS as u32);
+
+            wallet
+                .force_transfer_to_contract(
+                    warp_route.contract_id(),
+                    local_amount,
+                    get_native_asset(),
+                    TxPolicies::default(),
+                )
+                .await
+                .unwrap();
+
+            trigger_handle_by_sending_message_from_mailbox(
+                &mailbox,
+                &warp_route,
+                vec![
+                    contract_id.into(),
+                    mailbox_id.into(),
+                    post_dispatch_id.into(),
+                    ism_id.into(),
+                    recipient_id.into(),
+                ],
+                amount,
+                Some(Bits256(recipient_id.into())),
+            )
+            .await;
+
+            let provider = wallet.provider().unwrap();
+            let recipient_balance =
+                get_contract_balance(provider, &recipient_id.into(), get_native_asset())
+                    .await
+                    .unwrap();
+            assert_eq!(recipient_balance, local_amount);
+        }
+
         /// ============ claim_native ============  
         #[tokio::test]
         async fn test_claim_native() {
@@ -1493,4 +3308,129 @@ mod warp_route {
             );
         }
     }
+
+    mod simulator {
+        use super::*;
+        use test_utils::fixtures::{
+            Chain, HookConfig, Simulator, SimulatorConfig, TopologyConfig, WarpRouteConfig,
+            SECOND_LOCAL_DOMAIN,
+        };
+
+        async fn native_to_synthetic_simulator() -> Simulator {
+            Simulator::launch(
+                SimulatorConfig::default()
+                    .with_chain_b(
+                        TopologyConfig::default()
+                            .with_local_domain(SECOND_LOCAL_DOMAIN)
+                            .with_token_decimals(REMOTE_DECIMALS),
+                    )
+                    .with_warp_routes(WarpRouteConfig::Native, WarpRouteConfig::Synthetic),
+            )
+            .await
+        }
+
+        /// ============ fuel_to_fuel_transfer ============
+        #[tokio::test]
+        async fn test_fuel_to_fuel_transfer() {
+            let simulator = native_to_synthetic_simulator().await;
+            let wallet = &simulator.chain_b.wallet;
+            let synthetic_asset = simulator.chain_b.warp_route.as_ref().unwrap().asset_id;
+            let recipient = Bits256(Address::from(wallet.address()).into());
+            let amount = 1_000;
+
+            let balance_before = wallet.get_asset_balance(&synthetic_asset).await.unwrap();
+
+            let messages = simulator
+                .transfer_remote(Chain::A, recipient, amount)
+                .await
+                .unwrap();
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].origin, simulator.chain_a.config.local_domain);
+            assert_eq!(messages[0].destination, SECOND_LOCAL_DOMAIN);
+
+            simulator.relay(&messages).await.unwrap();
+
+            // The amount is scaled from the 9 native decimals to the 18 synthetic decimals
+            let balance_after = wallet.get_asset_balance(&synthetic_asset).await.unwrap();
+            assert_eq!(
+                balance_after - balance_before,
+                amount * 10u64.pow((REMOTE_DECIMALS - DECIMALS) as u32)
+            );
+        }
+
+        /// ============ fuel_to_fuel_round_trip ============
+        #[tokio::test]
+        async fn test_fuel_to_fuel_round_trip() {
+            let simulator = native_to_synthetic_simulator().await;
+            let wallet = &simulator.chain_a.wallet;
+            let provider = wallet.provider().unwrap();
+            let native_router = &simulator.chain_a.warp_route.as_ref().unwrap().instance;
+            let recipient = Bits256(Address::from(wallet.address()).into());
+            let amount = 1_000;
+
+            let messages = simulator
+                .transfer_remote(Chain::A, recipient, amount)
+                .await
+                .unwrap();
+            simulator.relay(&messages).await.unwrap();
+
+            let locked = provider
+                .get_contract_asset_balance(native_router.contract_id(), AssetId::BASE)
+                .await
+                .unwrap();
+            assert_eq!(locked, amount);
+
+            let messages = simulator
+                .transfer_remote(
+                    Chain::B,
+                    recipient,
+                    amount * 10u64.pow((REMOTE_DECIMALS - DECIMALS) as u32),
+                )
+                .await
+                .unwrap();
+            simulator.relay(&messages).await.unwrap();
+
+            let locked = provider
+                .get_contract_asset_balance(native_router.contract_id(), AssetId::BASE)
+                .await
+                .unwrap();
+            assert_eq!(locked, 0);
+        }
+
+        /// ============ fuel_to_fuel_gas_payment ============
+        #[tokio::test]
+        async fn test_fuel_to_fuel_gas_payment() {
+            let simulator = Simulator::launch(
+                SimulatorConfig::default()
+                    .with_chain_a(TopologyConfig::default().with_default_hook(HookConfig::Igp))
+                    .with_separate_nodes(),
+            )
+            .await;
+            let igp = &simulator.chain_a.igp;
+            let provider = simulator.chain_a.wallet.provider().unwrap();
+            let recipient = &simulator.chain_b.recipient;
+
+            let messages = simulator
+                .dispatch(Chain::A, recipient.contract_id().into(), b"hello".to_vec())
+                .await
+                .unwrap();
+
+            let igp_balance = provider
+                .get_contract_asset_balance(igp.contract_id(), AssetId::BASE)
+                .await
+                .unwrap();
+            assert!(igp_balance > 0);
+
+            simulator.relay(&messages).await.unwrap();
+
+            let handled = recipient
+                .methods()
+                .handled()
+                .simulate(Execution::StateReadOnly)
+                .await
+                .unwrap()
+                .value;
+            assert!(handled);
+        }
+    }
 }
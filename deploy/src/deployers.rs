@@ -9,7 +9,9 @@ use fuels::{
     },
 };
 
-use crate::{abis::*, get_deployment_config};
+use fuel_hyperlane_sdk::abis::*;
+
+use crate::get_deployment_config;
 
 pub async fn deploy_mailbox(
     domain: u32,
@@ -367,7 +369,7 @@ pub async fn deploy_gas_oracle(wallet_bits: Bits256, wallet: &WalletUnlocked) ->
 }
 
 pub async fn deploy_igp(wallet_bits: Bits256, wallet: &WalletUnlocked) -> Bech32ContractId {
-    let igp_configurables = GasPaymasterConfigurables::default()
+    let igp_configurables = InterchainGasPaymasterConfigurables::default()
         .with_TOKEN_EXCHANGE_RATE_SCALE(15_000_000_000_000)
         .unwrap()
         .with_DEFAULT_GAS_AMOUNT(5000)
@@ -620,7 +622,7 @@ pub async fn deploy_mainnet_hook_setup(
         .call()
         .await
         .unwrap();
-    let gas_paymaster = GasPaymaster::new(gas_paymaster_id.clone(), wallet.clone());
+    let gas_paymaster = InterchainGasPaymaster::new(gas_paymaster_id.clone(), wallet.clone());
     gas_paymaster
         .methods()
         .initialize(wallet_identity, wallet_identity)
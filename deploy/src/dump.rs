@@ -45,6 +45,8 @@ pub struct ContractAddresses {
     warp_route_synthetic: String,
     #[serde(rename = "warpRouteCollateral")]
     warp_route_collateral: String,
+    #[serde(rename = "warpRouteMultiAsset")]
+    warp_route_multi_asset: String,
     #[serde(rename = "collateralTokenContract")]
     collateral_asset_contract_id: String,
     #[serde(rename = "testCollateralAsset")]
@@ -78,6 +80,7 @@ impl ContractAddresses {
         warp_route_native: ContractId,
         warp_route_synthetic: ContractId,
         warp_route_collateral: ContractId,
+        warp_route_multi_asset: ContractId,
         collateral_asset_contract_id: ContractId,
         collateral_asset_id: AssetId,
         aggregation_hook: ContractId,
@@ -103,6 +106,7 @@ impl ContractAddresses {
             warp_route_native: format!("0x{}", warp_route_native),
             warp_route_synthetic: format!("0x{}", warp_route_synthetic),
             warp_route_collateral: format!("0x{}", warp_route_collateral),
+            warp_route_multi_asset: format!("0x{}", warp_route_multi_asset),
             collateral_asset_id: format!("0x{}", collateral_asset_id),
             collateral_asset_contract_id: format!("0x{}", collateral_asset_contract_id),
             aggregation_hook: format!("0x{}", aggregation_hook),
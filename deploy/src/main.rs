@@ -13,14 +13,13 @@ use rand::{thread_rng, Rng};
 use std::str::FromStr;
 use std::{collections::HashMap, env as std_env};
 
-mod abis;
 mod deployers;
 mod dump;
 mod env;
-use abis::*;
 use deployers::*;
 use dump::*;
 use env::*;
+use fuel_hyperlane_sdk::abis::*;
 
 async fn update_domain_hooks(fuel_wallet: &WalletUnlocked, wallet_bits: Bits256) {
     // let domain = 84532; base
@@ -64,7 +63,7 @@ async fn update_domain_hooks(fuel_wallet: &WalletUnlocked, wallet_bits: Bits256)
         },
     }];
     let gas_oracle = GasOracle::new(gas_oracle_id, fuel_wallet.clone());
-    let gas_paymaster = GasPaymaster::new(gas_paymaster_id, fuel_wallet.clone());
+    let gas_paymaster = InterchainGasPaymaster::new(gas_paymaster_id, fuel_wallet.clone());
 
     // set on oracle
     gas_oracle
@@ -266,7 +265,7 @@ async fn main() {
 
     let domain_routing_ism_id = deploy_domain_routing_ism(wallet_bits, &fuel_wallet).await;
 
-    let configurables = FallbackDomainRoutingISMConfigurables::default()
+    let configurables = DefaultFallbackDomainRoutingISMConfigurables::default()
         .with_EXPECTED_OWNER(wallet_bits)
         .unwrap();
 
@@ -426,24 +425,47 @@ async fn main() {
         ContractId::from(warp_route_synthetic_id.clone())
     );
 
+    // Multi Asset WR
+    let multi_asset_wr_configurables = MultiAssetWarpRouteConfigurables::default()
+        .with_EXPECTED_OWNER(wallet_bits)
+        .unwrap();
+    let warp_route_multi_asset_id = Contract::load_from(
+        "../contracts/multi-asset-warp-route/out/debug/multi-asset-warp-route.bin",
+        config
+            .clone()
+            .with_configurables(multi_asset_wr_configurables),
+    )
+    .unwrap()
+    .deploy(&fuel_wallet, TxPolicies::default())
+    .await
+    .unwrap();
+
+    println!(
+        "warpRouteMultiAsset: 0x{}",
+        ContractId::from(warp_route_multi_asset_id.clone())
+    );
+
     ///////////////////////////
     // Instantiate Contracts //
     ///////////////////////////
 
-    let post_dispatch_mock = PostDispatch::new(post_dispatch_mock_id.clone(), fuel_wallet.clone());
+    let post_dispatch_mock =
+        PostDispatchMock::new(post_dispatch_mock_id.clone(), fuel_wallet.clone());
     let mailbox = Mailbox::new(mailbox_contract_id.clone(), fuel_wallet.clone());
     let merkle_tree_hook = MerkleTreeHook::new(merkle_tree_id.clone(), fuel_wallet.clone());
     let aggregation_hook = AggregationHook::new(aggregation_hook_id.clone(), fuel_wallet.clone());
     let pausable_hook = PausableHook::new(pausable_hook_id.clone(), fuel_wallet.clone());
     let protocol_fee_hook = ProtocolFee::new(protocol_fee_hook_id.clone(), fuel_wallet.clone());
     let gas_oracle = GasOracle::new(gas_oracle_id.clone(), fuel_wallet.clone());
-    let igp = GasPaymaster::new(igp_id.clone(), fuel_wallet.clone());
+    let igp = InterchainGasPaymaster::new(igp_id.clone(), fuel_wallet.clone());
     let test_recipient = TestRecipient::new(recipient_id.clone(), fuel_wallet.clone());
     let aggregation_ism = AggregationISM::new(aggregation_ism_id.clone(), fuel_wallet.clone());
     let domain_routing_ism =
         DomainRoutingISM::new(domain_routing_ism_id.clone(), fuel_wallet.clone());
-    let fallback_domain_routing_ism =
-        FallbackDomainRoutingISM::new(fallback_domain_routing_ism_id.clone(), fuel_wallet.clone());
+    let fallback_domain_routing_ism = DefaultFallbackDomainRoutingISM::new(
+        fallback_domain_routing_ism_id.clone(),
+        fuel_wallet.clone(),
+    );
     let message_id_multisig_ism_1 =
         MessageIdMultisigISM::new(message_id_multisig_ism_id_1.clone(), fuel_wallet.clone());
     let merkle_root_multisig_ism_1 =
@@ -456,6 +478,8 @@ async fn main() {
     let warp_route_synthetic = WarpRoute::new(warp_route_synthetic_id.clone(), fuel_wallet.clone());
     let warp_route_collateral =
         WarpRoute::new(warp_route_collateral_id.clone(), fuel_wallet.clone());
+    let warp_route_multi_asset =
+        MultiAssetWarpRoute::new(warp_route_multi_asset_id.clone(), fuel_wallet.clone());
 
     let wallet_identity = Identity::from(fuel_wallet.address());
     let test_ism_address = Bits256(ContractId::from(test_ism_id.clone()).into());
@@ -797,6 +821,22 @@ async fn main() {
         "Failed to initialize Warp Route Collateral."
     );
 
+    let multi_asset_init_res = warp_route_multi_asset
+        .methods()
+        .initialize(
+            wallet_identity,
+            Bits256(mailbox_contract_id.hash().into()),
+            post_dispatch_mock_address,
+            test_ism_address,
+        )
+        .call()
+        .await;
+
+    assert!(
+        multi_asset_init_res.is_ok(),
+        "Failed to initialize Warp Route Multi Asset."
+    );
+
     /////////////////////////////
     // Save contract addresses //
     /////////////////////////////
@@ -820,6 +860,7 @@ async fn main() {
         warp_route_native_id.into(),
         warp_route_synthetic_id.into(),
         warp_route_collateral_id.into(),
+        warp_route_multi_asset_id.into(),
         collateral_asset_contract_id.into(),
         collateral_asset_id,
         aggregation_hook_id.into(),
@@ -220,4 +220,6 @@ async fn asset_send_claim_gas() -> Result<f64, String> {
 
 pub fn test() -> TestCase {
     TestCase::new("asset_send_with_gas", asset_send_claim_gas)
+        .depends_on(&["set_gas_configs"])
+        .isolated()
 }
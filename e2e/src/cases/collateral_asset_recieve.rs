@@ -8,6 +8,7 @@ use crate::{
     utils::{
         get_evm_domain, get_fuel_domain, get_fuel_test_recipient,
         local_contracts::{get_contract_address_from_yaml, load_remote_wr_addresses},
+        retry::DeliveryPolicy,
         token::{get_contract_balance, send_asset_to_contract},
     },
 };
@@ -133,9 +134,7 @@ async fn collateral_asset_recieve() -> Result<f64, String> {
         return Err("Failed to deliver message".to_string());
     }
 
-    let res = monitor_fuel_for_delivery(mailbox_instance, msg_id).await;
-
-    assert!(res, "Failed to recieve message from remote");
+    monitor_fuel_for_delivery(mailbox_instance, msg_id, DeliveryPolicy::default()).await?;
 
     let amount_18dec_to_local = amount / 10u64.pow(18 - 9);
 
@@ -1,11 +1,12 @@
 use crate::{
-    cases::TestCase,
+    cases::{fixtures::post_dispatch_required_hook, TestCase},
     evm::{get_evm_wallet, monitor_evm_for_delivery, SepoliaContracts},
     setup::{abis::WarpRoute, get_loaded_wallet},
     utils::{
         get_evm_domain, get_fuel_domain, get_remote_test_recipient,
         get_remote_test_recipient_address,
         local_contracts::*,
+        retry::DeliveryPolicy,
         token::{get_contract_balance, send_asset_to_contract},
     },
 };
@@ -143,7 +144,7 @@ async fn collateral_asset_send() -> Result<f64, String> {
     )
     .await;
 
-    let _ = warp_route_instance
+    let message_id = warp_route_instance
         .methods()
         .transfer_remote(evm_domain, test_recipient, amount, None, None)
         .call_params(CallParameters::new(quote.value, base_asset, 20_000_000))
@@ -157,7 +158,8 @@ async fn collateral_asset_send() -> Result<f64, String> {
         ])
         .call()
         .await
-        .map_err(|e| format!("Failed to transfer remote message: {:?}", e))?;
+        .map_err(|e| format!("Failed to transfer remote message: {:?}", e))?
+        .value;
 
     let warp_base_balance_after = get_contract_balance(
         wallet.provider(),
@@ -175,7 +177,12 @@ async fn collateral_asset_send() -> Result<f64, String> {
     .await
     .unwrap();
 
-    monitor_evm_for_delivery(*evm_wr_instance.address()).await;
+    monitor_evm_for_delivery(
+        &remote_contracts.mailbox,
+        FixedBytes::from(message_id.0),
+        DeliveryPolicy::default(),
+    )
+    .await?;
 
     if warp_base_balance_after != warp_base_balance_before + quote.value {
         return Err(format!(
@@ -234,4 +241,7 @@ async fn collateral_asset_send() -> Result<f64, String> {
 
 pub fn test() -> TestCase {
     TestCase::new("collateral_asset_send", collateral_asset_send)
+        .depends_on(&["set_gas_configs"])
+        .with_setup(post_dispatch_required_hook)
+        .isolated()
 }
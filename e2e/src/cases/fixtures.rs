@@ -0,0 +1,22 @@
+use crate::{
+    setup::{abis::Mailbox, get_loaded_wallet},
+    utils::local_contracts::{get_contract_address_from_json, get_contract_address_from_yaml},
+};
+
+/// Sets the mock post dispatch hook as the mailbox required hook.
+/// Combine with `TestCase::isolated` so the previous hook is restored afterwards.
+pub async fn post_dispatch_required_hook() -> Result<(), String> {
+    let wallet = get_loaded_wallet().await;
+
+    let fuel_mailbox_id = get_contract_address_from_json("fueltest1", "mailbox");
+    let post_dispatch_hook_id = get_contract_address_from_yaml("postDispatch");
+
+    Mailbox::new(fuel_mailbox_id, wallet)
+        .methods()
+        .set_required_hook(post_dispatch_hook_id)
+        .call()
+        .await
+        .map_err(|e| format!("Failed to set required hook to mailbox: {:?}", e))?;
+
+    Ok(())
+}
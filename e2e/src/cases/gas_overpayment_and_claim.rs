@@ -6,7 +6,7 @@ use fuels::{
 };
 
 use crate::{
-    cases::TestCase,
+    cases::{fixtures::post_dispatch_required_hook, TestCase},
     //evm::monitor_sepolia_for_delivery,
     setup::{
         abis::{GasOracle, InterchainGasPaymaster, Mailbox},
@@ -61,13 +61,6 @@ async fn gas_overpayment_and_claim() -> Result<f64, String> {
     .await
     .unwrap();
 
-    fuel_mailbox_instance
-        .methods()
-        .set_required_hook(post_dispatch_hook_id)
-        .call()
-        .await
-        .unwrap();
-
     let metadata = create_mock_metadata(&wallet);
 
     let send_message_response = fuel_mailbox_instance
@@ -185,4 +178,7 @@ pub fn test() -> TestCase {
     TestCase::new("gas_overpayment_and_claim", || async move {
         gas_overpayment_and_claim().await
     })
+    .depends_on(&["set_gas_configs"])
+    .with_setup(post_dispatch_required_hook)
+    .isolated()
 }
@@ -176,4 +176,6 @@ pub fn test() -> TestCase {
         "send_message_with_aggregation_and_protocol_fee_hook",
         send_message_with_aggregation_and_protocol_fee_hook,
     )
+    .depends_on(&["set_gas_configs"])
+    .isolated()
 }
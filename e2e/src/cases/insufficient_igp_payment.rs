@@ -0,0 +1,63 @@
+use fuels::{
+    accounts::ViewOnlyAccount,
+    programs::calls::CallParameters,
+    types::{AssetId, Bits256, Identity},
+};
+use tokio::time::Instant;
+
+use crate::{
+    cases::TestCase,
+    setup::{abis::InterchainGasPaymaster, get_loaded_wallet},
+    utils::{expect_revert, get_evm_domain, local_contracts::get_contract_address_from_yaml},
+};
+
+const GAS_AMOUNT: u64 = 5000;
+
+async fn insufficient_igp_payment() -> Result<f64, String> {
+    let start = Instant::now();
+    let wallet = get_loaded_wallet().await;
+
+    let evm_domain = get_evm_domain();
+    let igp_id = get_contract_address_from_yaml("interchainGasPaymaster");
+    let gas_oracle_id = get_contract_address_from_yaml("gasOracle");
+
+    let fuel_igp_instance = InterchainGasPaymaster::new(igp_id, wallet.clone());
+
+    let quote = fuel_igp_instance
+        .methods()
+        .quote_gas_payment(evm_domain, GAS_AMOUNT)
+        .with_contract_ids(&[gas_oracle_id.into()])
+        .call()
+        .await
+        .map_err(|e| format!("Failed to get quote: {:?}", e))?
+        .value;
+
+    if quote == 0 {
+        return Err("Expected a non zero gas quote".to_string());
+    }
+
+    let payment = fuel_igp_instance
+        .methods()
+        .pay_for_gas(
+            Bits256([1u8; 32]),
+            evm_domain,
+            GAS_AMOUNT,
+            Identity::from(wallet.address()),
+        )
+        .call_params(CallParameters::new(quote - 1, AssetId::BASE, 10_000_000))
+        .unwrap()
+        .with_contract_ids(&[gas_oracle_id.into()])
+        .call()
+        .await;
+
+    expect_revert(payment, "InsufficientGasPayment")?;
+
+    println!("✅ insufficient_igp_payment passed");
+
+    Ok(start.elapsed().as_secs_f64())
+}
+
+pub fn test() -> TestCase {
+    TestCase::new("insufficient_igp_payment", insufficient_igp_payment)
+        .depends_on(&["set_gas_configs"])
+}
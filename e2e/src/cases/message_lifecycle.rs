@@ -0,0 +1,105 @@
+use hyperlane_core::H256;
+use tokio::time::Instant;
+
+use crate::{
+    cases::TestCase,
+    evm::{get_evm_wallet, monitor_fuel_for_delivery, SepoliaContracts},
+    explorer::{explore, lifecycle, EvmChain, FuelChain, EVM_CHAIN_NAME, FUEL_CHAIN_NAME},
+    setup::{abis::Mailbox, get_loaded_wallet, get_provider},
+    utils::{
+        get_fuel_domain, get_fuel_test_recipient, get_remote_msg_body,
+        local_contracts::get_contract_address_from_json, retry::DeliveryPolicy,
+    },
+};
+
+async fn message_lifecycle() -> Result<f64, String> {
+    let start = Instant::now();
+
+    let fuel_mailbox_id = get_contract_address_from_json("fueltest1", "mailbox");
+    let fuel_mailbox_instance = Mailbox::new(fuel_mailbox_id, get_loaded_wallet().await);
+
+    let contracts = SepoliaContracts::initialize(get_evm_wallet().await).await;
+    let remote_mailbox = contracts.mailbox;
+
+    let fuel_domain = get_fuel_domain();
+    let recipient = get_fuel_test_recipient();
+    let body = get_remote_msg_body();
+
+    // The start of the Fuel scan, so the delivery is found without scanning the whole chain
+    let fuel_block = get_provider()
+        .await
+        .latest_block_height()
+        .await
+        .map_err(|e| format!("Failed to get Fuel block height: {:?}", e))?;
+
+    let quote_dispatch = remote_mailbox
+        .quoteDispatch_1(fuel_domain, recipient, body.clone())
+        .call()
+        .await
+        .unwrap()
+        .fee;
+
+    let receipt = remote_mailbox
+        .dispatch_2(fuel_domain, recipient, body.clone())
+        .value(quote_dispatch)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to dispatch on EVM: {:?}", e))?
+        .get_receipt()
+        .await
+        .map_err(|e| format!("Failed to get dispatch receipt: {:?}", e))?;
+
+    let msg_id = remote_mailbox.latestDispatchedId().call().await.unwrap()._0;
+    let message_id = H256(msg_id.0);
+
+    let fuel = FuelChain::connect(fuel_block).await;
+    let evm = EvmChain::connect().await;
+
+    let lifecycles = explore(&fuel, &evm, H256(receipt.transaction_hash.0)).await?;
+    let dispatched = lifecycles
+        .iter()
+        .find(|lifecycle| lifecycle.message_id == message_id)
+        .ok_or(format!(
+            "Explorer did not find {:?} in the dispatch tx",
+            message_id
+        ))?;
+
+    let dispatch = dispatched
+        .dispatch
+        .as_ref()
+        .ok_or("Explorer did not find the dispatch")?;
+    if dispatch.chain != EVM_CHAIN_NAME || dispatch.message.destination != fuel_domain {
+        return Err(format!(
+            "Expected a dispatch from {} to {}, got: {} to {}",
+            EVM_CHAIN_NAME, fuel_domain, dispatch.chain, dispatch.message.destination
+        ));
+    }
+    if dispatch.merkle_index.is_none() {
+        return Err("Explorer did not find the merkle tree insertion".to_string());
+    }
+
+    monitor_fuel_for_delivery(fuel_mailbox_instance, msg_id, DeliveryPolicy::default()).await?;
+
+    let delivered = lifecycle(&fuel, &evm, message_id).await?;
+    let delivery = delivered
+        .deliveries
+        .iter()
+        .find(|delivery| delivery.chain == FUEL_CHAIN_NAME)
+        .ok_or("Explorer did not check the delivery on Fuel")?;
+    if !delivery.delivered || delivery.processed.is_none() {
+        return Err(format!(
+            "Expected the explorer to find the processing tx, got: {:?}",
+            delivery
+        ));
+    }
+
+    delivered.print();
+
+    println!("✅ message_lifecycle test passed");
+
+    Ok(start.elapsed().as_secs_f64())
+}
+
+pub fn test() -> TestCase {
+    TestCase::new("message_lifecycle", message_lifecycle).depends_on(&["message_recieve"])
+}
@@ -7,7 +7,7 @@ use crate::{
     setup::{abis::Mailbox, get_loaded_wallet},
     utils::{
         get_fuel_domain, get_fuel_test_recipient, get_remote_msg_body,
-        local_contracts::get_contract_address_from_json,
+        local_contracts::get_contract_address_from_json, retry::DeliveryPolicy,
     },
 };
 
@@ -49,9 +49,7 @@ async fn message_recieve() -> Result<f64, String> {
         return Err("Failed to deliver message".to_string());
     }
 
-    let res = monitor_fuel_for_delivery(fuel_mailbox_instance, msg_id).await;
-
-    assert!(res, "Failed to recieve message from remote");
+    monitor_fuel_for_delivery(fuel_mailbox_instance, msg_id, DeliveryPolicy::default()).await?;
 
     println!("✅ message_recieve test passed");
 
@@ -6,7 +6,7 @@ use fuels::{
 };
 
 use crate::{
-    cases::TestCase,
+    cases::{fixtures::post_dispatch_required_hook, TestCase},
     setup::{
         abis::{GasOracle, InterchainGasPaymaster, Mailbox},
         get_loaded_wallet,
@@ -30,7 +30,6 @@ async fn send_message_with_gas() -> Result<f64, String> {
     let fuel_mailbox_id = get_contract_address_from_json("fueltest1", "mailbox");
     let igp_id = get_contract_address_from_yaml("interchainGasPaymaster");
     let gas_oracle_id = get_contract_address_from_yaml("gasOracle");
-    let post_dispatch_hook_id = get_contract_address_from_yaml("postDispatch");
 
     let fuel_mailbox_instance = Mailbox::new(fuel_mailbox_id, wallet.clone());
     let fuel_igp_instance = InterchainGasPaymaster::new(igp_id, wallet.clone());
@@ -50,13 +49,6 @@ async fn send_message_with_gas() -> Result<f64, String> {
     .await
     .unwrap();
 
-    fuel_mailbox_instance
-        .methods()
-        .set_required_hook(post_dispatch_hook_id)
-        .call()
-        .await
-        .unwrap();
-
     let quote = fuel_mailbox_instance
         .methods()
         .quote_dispatch(
@@ -145,4 +137,7 @@ pub fn test() -> TestCase {
     TestCase::new("send_message_with_gas", || async move {
         send_message_with_gas().await
     })
+    .depends_on(&["set_gas_configs"])
+    .with_setup(post_dispatch_required_hook)
+    .isolated()
 }
@@ -0,0 +1,46 @@
+use fuels::types::Bytes;
+use hyperlane_core::RawHyperlaneMessage;
+use tokio::time::Instant;
+
+use crate::{
+    cases::TestCase,
+    setup::{abis::Mailbox, deploy::deploy_recipient_with_pausable_ism, get_loaded_wallet},
+    utils::{
+        expect_revert, get_evm_domain, inbound_message,
+        local_contracts::get_contract_address_from_json,
+    },
+};
+
+async fn message_wrong_destination() -> Result<f64, String> {
+    let start = Instant::now();
+    let wallet = get_loaded_wallet().await;
+
+    let fuel_mailbox_id = get_contract_address_from_json("fueltest1", "mailbox");
+    let fuel_mailbox_instance = Mailbox::new(fuel_mailbox_id, wallet.clone());
+
+    let (recipient_id, ism_id) = deploy_recipient_with_pausable_ism().await?;
+
+    let wrong_destination = get_evm_domain();
+    let mut message = inbound_message(recipient_id);
+    message.destination = wrong_destination;
+
+    let process = fuel_mailbox_instance
+        .methods()
+        .process(Bytes(vec![]), Bytes(RawHyperlaneMessage::from(&message)))
+        .with_contract_ids(&[recipient_id.into(), ism_id.into()])
+        .call()
+        .await;
+
+    expect_revert(
+        process,
+        &format!("UnexpectedDestination({})", wrong_destination),
+    )?;
+
+    println!("✅ message_wrong_destination passed");
+
+    Ok(start.elapsed().as_secs_f64())
+}
+
+pub fn test() -> TestCase {
+    TestCase::new("message_wrong_destination", message_wrong_destination)
+}
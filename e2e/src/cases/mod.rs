@@ -1,24 +1,41 @@
 mod asset_send_with_gas;
 mod collateral_asset_recieve;
 mod collateral_asset_send;
+mod fixtures;
 mod gas_overpayment_and_claim;
 mod hooks_setup;
+mod insufficient_igp_payment;
+mod message_lifecycle;
 mod message_recieve;
+mod message_replay;
 mod message_send_with_gas;
+mod message_wrong_destination;
+mod message_wrong_version;
 mod native_asset_recieve;
 mod native_asset_send;
+mod paused_ism_process;
 mod remote_mailbox;
 mod set_gas_configs;
 mod synthetic_asset_recieve;
 mod synthetic_asset_send;
+mod warp_route_paused;
+mod warp_route_unenrolled_domain;
 
-use std::{future::Future, pin::Pin};
+use std::{collections::HashSet, future::Future, pin::Pin, time::Duration};
+
+use crate::setup::{config::get_case_timeout, snapshot::ConfigSnapshot};
 
 type TestFn = Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<f64, String>>>>>;
+type HookFn = Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
 
 pub struct TestCase {
     name: String,
     test: TestFn,
+    setup: Option<HookFn>,
+    teardown: Option<HookFn>,
+    dependencies: Vec<String>,
+    isolated: bool,
+    timeout: Duration,
 }
 
 impl TestCase {
@@ -30,16 +47,142 @@ impl TestCase {
         Self {
             name: name.to_string(),
             test: Box::new(move || Box::pin(test())),
+            setup: None,
+            teardown: None,
+            dependencies: vec![],
+            isolated: false,
+            timeout: get_case_timeout(),
         }
     }
 
+    /// Runs `setup` before the test body. A failing setup fails the case.
+    pub fn with_setup<F, Fut>(mut self, setup: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.setup = Some(Box::new(move || Box::pin(setup())));
+        self
+    }
+
+    /// Runs `teardown` after the test body, even if the body failed.
+    pub fn with_teardown<F, Fut>(mut self, teardown: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.teardown = Some(Box::new(move || Box::pin(teardown())));
+        self
+    }
+
+    /// Declares cases which have to pass before this one is run.
+    pub fn depends_on(mut self, names: &[&str]) -> Self {
+        self.dependencies
+            .extend(names.iter().map(|name| name.to_string()));
+        self
+    }
+
+    /// Snapshots the shared contract configuration before the case
+    /// and restores it afterwards.
+    pub fn isolated(mut self) -> Self {
+        self.isolated = true;
+        self
+    }
+
+    /// Overrides the maximum time the setup and test body may take.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     pub async fn run(self) -> Result<f64, String> {
-        (self.test)().await
+        let snapshot = if self.isolated {
+            Some(ConfigSnapshot::take().await?)
+        } else {
+            None
+        };
+
+        let body = async {
+            if let Some(setup) = &self.setup {
+                setup().await.map_err(|e| format!("Setup failed: {}", e))?;
+            }
+            (self.test)().await
+        };
+
+        let result = tokio::time::timeout(self.timeout, body)
+            .await
+            .unwrap_or_else(|_| {
+                Err(format!(
+                    "Timed out after {:.0} sec",
+                    self.timeout.as_secs_f64()
+                ))
+            });
+
+        let mut cleanup_errors = vec![];
+
+        if let Some(teardown) = &self.teardown {
+            if let Err(e) = teardown().await {
+                cleanup_errors.push(format!("Teardown failed: {}", e));
+            }
+        }
+
+        if let Some(snapshot) = snapshot {
+            if let Err(e) = snapshot.restore().await {
+                cleanup_errors.push(format!("Restoring config failed: {}", e));
+            }
+        }
+
+        match result {
+            Ok(_) if !cleanup_errors.is_empty() => Err(cleanup_errors.join("; ")),
+            Err(e) if !cleanup_errors.is_empty() => {
+                Err(format!("{}; {}", e, cleanup_errors.join("; ")))
+            }
+            result => result,
+        }
     }
 
     pub fn name(&self) -> String {
         self.name.clone()
     }
+
+    pub fn dependencies(&self) -> &[String] {
+        &self.dependencies
+    }
+}
+
+/// Orders the cases so every case runs after its dependencies,
+/// otherwise keeping the order in which they were declared.
+pub fn order_test_cases(cases: Vec<TestCase>) -> Result<Vec<TestCase>, String> {
+    let names: HashSet<String> = cases.iter().map(|case| case.name()).collect();
+
+    for case in &cases {
+        if let Some(missing) = case.dependencies.iter().find(|d| !names.contains(*d)) {
+            return Err(format!(
+                "Test case {} depends on unknown case {}",
+                case.name, missing
+            ));
+        }
+    }
+
+    let mut pending = cases;
+    let mut ordered: Vec<TestCase> = vec![];
+    let mut scheduled: HashSet<String> = HashSet::new();
+
+    while !pending.is_empty() {
+        let next = pending
+            .iter()
+            .position(|case| case.dependencies.iter().all(|d| scheduled.contains(d)))
+            .ok_or_else(|| {
+                let cycle: Vec<String> = pending.iter().map(|case| case.name()).collect();
+                format!("Cyclic dependency between test cases: {:?}", cycle)
+            })?;
+
+        let case = pending.remove(next);
+        scheduled.insert(case.name());
+        ordered.push(case);
+    }
+
+    Ok(ordered)
 }
 
 pub struct FailedTestCase {
@@ -57,6 +200,8 @@ impl FailedTestCase {
     }
 }
 
+/// Cases are listed in their preferred order; `order_test_cases` moves a case
+/// behind its dependencies when needed.
 pub fn pull_test_cases() -> Vec<TestCase> {
     vec![
         set_gas_configs::test(),
@@ -69,8 +214,135 @@ pub fn pull_test_cases() -> Vec<TestCase> {
         gas_overpayment_and_claim::test(),
         asset_send_with_gas::test(),
         message_recieve::test(),
+        message_lifecycle::test(),
         synthetic_asset_recieve::test(),
         native_asset_recieve::test(),
         hooks_setup::test(),
+        // Negative paths
+        message_replay::test(),
+        message_wrong_destination::test(),
+        message_wrong_version::test(),
+        paused_ism_process::test(),
+        warp_route_unenrolled_domain::test(),
+        warp_route_paused::test(),
+        insufficient_igp_payment::test(),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    fn case(name: &str, dependencies: &[&str]) -> TestCase {
+        TestCase::new(name, || async { Ok(0.0) }).depends_on(dependencies)
+    }
+
+    fn names(cases: &[TestCase]) -> Vec<String> {
+        cases.iter().map(|case| case.name()).collect()
+    }
+
+    #[test]
+    fn test_order_keeps_declaration_order() {
+        let cases = vec![case("a", &[]), case("b", &["a"]), case("c", &[])];
+
+        let ordered = order_test_cases(cases).unwrap();
+
+        assert_eq!(names(&ordered), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_order_moves_case_behind_dependencies() {
+        let cases = vec![case("a", &["c"]), case("b", &[]), case("c", &["b"])];
+
+        let ordered = order_test_cases(cases).unwrap();
+
+        assert_eq!(names(&ordered), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_order_unknown_dependency() {
+        let cases = vec![case("a", &[]), case("b", &["missing"])];
+
+        let error = order_test_cases(cases).err().unwrap();
+
+        assert_eq!(error, "Test case b depends on unknown case missing");
+    }
+
+    #[test]
+    fn test_order_cyclic_dependency() {
+        let cases = vec![case("a", &[]), case("b", &["c"]), case("c", &["b"])];
+
+        let error = order_test_cases(cases).err().unwrap();
+
+        assert_eq!(error, r#"Cyclic dependency between test cases: ["b", "c"]"#);
+    }
+
+    #[tokio::test]
+    async fn test_run_passes() {
+        let result = case("a", &[])
+            .with_teardown(|| async { Ok(()) })
+            .run()
+            .await;
+
+        assert_eq!(result, Ok(0.0));
+    }
+
+    #[tokio::test]
+    async fn test_run_teardown_error_fails_passing_body() {
+        let result = case("a", &[])
+            .with_teardown(|| async { Err("reset".to_string()) })
+            .run()
+            .await;
+
+        assert_eq!(result, Err("Teardown failed: reset".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_run_merges_body_and_teardown_errors() {
+        let torn_down = Rc::new(Cell::new(false));
+        let teardown_flag = torn_down.clone();
+
+        let result = TestCase::new("a", || async { Err("body".to_string()) })
+            .with_teardown(move || {
+                let torn_down = teardown_flag.clone();
+                async move {
+                    torn_down.set(true);
+                    Err("reset".to_string())
+                }
+            })
+            .run()
+            .await;
+
+        assert!(torn_down.get());
+        assert_eq!(result, Err("body; Teardown failed: reset".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_run_setup_error_skips_body() {
+        let result = TestCase::new("a", || async { Ok(1.0) })
+            .with_setup(|| async { Err("deploy".to_string()) })
+            .run()
+            .await;
+
+        assert_eq!(result, Err("Setup failed: deploy".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_run_timeout_still_tears_down() {
+        let result = TestCase::new("a", || async {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            Ok(0.0)
+        })
+        .with_timeout(Duration::from_millis(10))
+        .with_teardown(|| async { Err("reset".to_string()) })
+        .run()
+        .await;
+
+        assert_eq!(
+            result,
+            Err("Timed out after 0 sec; Teardown failed: reset".to_string())
+        );
+    }
+}
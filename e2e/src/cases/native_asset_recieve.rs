@@ -8,6 +8,7 @@ use crate::{
     utils::{
         get_evm_domain, get_fuel_domain, get_fuel_test_recipient,
         local_contracts::{get_contract_address_from_yaml, load_remote_wr_addresses},
+        retry::DeliveryPolicy,
         token::{get_contract_balance, send_asset_to_contract},
     },
 };
@@ -109,9 +110,7 @@ async fn native_asset_recieve() -> Result<f64, String> {
         return Err("Failed to deliver message".to_string());
     }
 
-    let res = monitor_fuel_for_delivery(mailbox_instance, msg_id).await;
-
-    assert!(res, "Failed to recieve message from remote");
+    monitor_fuel_for_delivery(mailbox_instance, msg_id, DeliveryPolicy::default()).await?;
 
     let contract_final_balance = get_contract_balance(
         wallet.provider(),
@@ -1,5 +1,5 @@
 use crate::{
-    cases::TestCase,
+    cases::{fixtures::post_dispatch_required_hook, TestCase},
     setup::{abis::WarpRoute, get_loaded_wallet},
     utils::{
         get_evm_domain, get_remote_test_recipient, local_contracts::*, token::get_contract_balance,
@@ -111,4 +111,7 @@ async fn native_asset_send() -> Result<f64, String> {
 
 pub fn test() -> TestCase {
     TestCase::new("native_asset_send", native_asset_send)
+        .depends_on(&["set_gas_configs"])
+        .with_setup(post_dispatch_required_hook)
+        .isolated()
 }
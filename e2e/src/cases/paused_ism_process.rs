@@ -0,0 +1,49 @@
+use fuels::types::Bytes;
+use hyperlane_core::RawHyperlaneMessage;
+use tokio::time::Instant;
+
+use crate::{
+    cases::TestCase,
+    setup::{
+        abis::{Mailbox, PausableISM},
+        deploy::deploy_recipient_with_pausable_ism,
+        get_loaded_wallet,
+    },
+    utils::{expect_revert, inbound_message, local_contracts::get_contract_address_from_json},
+};
+
+async fn paused_ism_process() -> Result<f64, String> {
+    let start = Instant::now();
+    let wallet = get_loaded_wallet().await;
+
+    let fuel_mailbox_id = get_contract_address_from_json("fueltest1", "mailbox");
+    let fuel_mailbox_instance = Mailbox::new(fuel_mailbox_id, wallet.clone());
+
+    let (recipient_id, ism_id) = deploy_recipient_with_pausable_ism().await?;
+
+    PausableISM::new(ism_id, wallet.clone())
+        .methods()
+        .pause()
+        .call()
+        .await
+        .map_err(|e| format!("Failed to pause ISM: {:?}", e))?;
+
+    let message = RawHyperlaneMessage::from(&inbound_message(recipient_id));
+
+    let process = fuel_mailbox_instance
+        .methods()
+        .process(Bytes(vec![]), Bytes(message))
+        .with_contract_ids(&[recipient_id.into(), ism_id.into()])
+        .call()
+        .await;
+
+    expect_revert(process, "Paused")?;
+
+    println!("✅ paused_ism_process passed");
+
+    Ok(start.elapsed().as_secs_f64())
+}
+
+pub fn test() -> TestCase {
+    TestCase::new("paused_ism_process", paused_ism_process)
+}
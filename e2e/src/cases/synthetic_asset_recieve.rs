@@ -8,6 +8,7 @@ use crate::{
     utils::{
         get_fuel_domain, get_fuel_test_recipient,
         local_contracts::{get_contract_address_from_yaml, load_remote_wr_addresses},
+        retry::DeliveryPolicy,
     },
 };
 use alloy::primitives::{FixedBytes, U256};
@@ -106,9 +107,7 @@ async fn synthetic_asset_recieve() -> Result<f64, String> {
         return Err("Failed to deliver message".to_string());
     }
 
-    let res = monitor_fuel_for_delivery(mailbox_instance.clone(), msg_id).await;
-
-    assert!(res, "Failed to recieve message from remote");
+    monitor_fuel_for_delivery(mailbox_instance.clone(), msg_id, DeliveryPolicy::default()).await?;
 
     let amount_18dec_to_local = amount / 10u64.pow((18 - decimals).into());
 
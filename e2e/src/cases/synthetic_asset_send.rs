@@ -1,5 +1,5 @@
 use crate::{
-    cases::TestCase,
+    cases::{fixtures::post_dispatch_required_hook, TestCase},
     evm::{get_evm_wallet, monitor_fuel_for_delivery, SepoliaContracts},
     setup::{
         abis::{Mailbox, WarpRoute},
@@ -8,6 +8,7 @@ use crate::{
     utils::{
         get_evm_domain, get_fuel_domain,
         local_contracts::{get_contract_address_from_yaml, load_remote_wr_addresses},
+        retry::DeliveryPolicy,
         token::{get_balance, get_contract_balance, send_asset_to_contract},
         TEST_RECIPIENT,
     },
@@ -126,9 +127,7 @@ async fn synthetic_asset_send() -> Result<f64, String> {
         return Err("Failed to deliver message".to_string());
     }
 
-    let res = monitor_fuel_for_delivery(mailbox_instance.clone(), msg_id).await;
-
-    assert!(res, "Failed to recieve message from remote");
+    monitor_fuel_for_delivery(mailbox_instance.clone(), msg_id, DeliveryPolicy::default()).await?;
 
     let remote_adjusted_amount = amount / 10u64.pow((18 - local_decimals).into());
 
@@ -213,4 +212,7 @@ async fn synthetic_asset_send() -> Result<f64, String> {
 
 pub fn test() -> TestCase {
     TestCase::new("synthetic_asset_send", synthetic_asset_send)
+        .depends_on(&["set_gas_configs"])
+        .with_setup(post_dispatch_required_hook)
+        .isolated()
 }
@@ -0,0 +1,76 @@
+use fuels::{programs::calls::CallParameters, types::AssetId};
+use tokio::time::Instant;
+
+use crate::{
+    cases::TestCase,
+    setup::{abis::WarpRoute, get_loaded_wallet},
+    utils::{
+        expect_revert, get_evm_domain, get_remote_test_recipient,
+        local_contracts::get_contract_address_from_yaml,
+    },
+};
+
+async fn unpause_warp_route() -> Result<(), String> {
+    let wallet = get_loaded_wallet().await;
+    let warp_route_id = get_contract_address_from_yaml("warpRouteNative");
+    let warp_route_instance = WarpRoute::new(warp_route_id, wallet);
+
+    let paused = warp_route_instance
+        .methods()
+        .is_paused()
+        .call()
+        .await
+        .map_err(|e| format!("Failed to read paused state: {:?}", e))?
+        .value;
+
+    if paused {
+        warp_route_instance
+            .methods()
+            .unpause()
+            .call()
+            .await
+            .map_err(|e| format!("Failed to unpause warp route: {:?}", e))?;
+    }
+
+    Ok(())
+}
+
+async fn warp_route_paused() -> Result<f64, String> {
+    let start = Instant::now();
+    let wallet = get_loaded_wallet().await;
+
+    let amount = 1000;
+    let warp_route_id = get_contract_address_from_yaml("warpRouteNative");
+    let warp_route_instance = WarpRoute::new(warp_route_id, wallet.clone());
+
+    warp_route_instance
+        .methods()
+        .pause()
+        .call()
+        .await
+        .map_err(|e| format!("Failed to pause warp route: {:?}", e))?;
+
+    let transfer = warp_route_instance
+        .methods()
+        .transfer_remote(
+            get_evm_domain(),
+            get_remote_test_recipient(),
+            amount,
+            None,
+            None,
+        )
+        .call_params(CallParameters::new(amount, AssetId::BASE, 20_000_000))
+        .unwrap()
+        .call()
+        .await;
+
+    expect_revert(transfer, "Paused")?;
+
+    println!("✅ warp_route_paused passed");
+
+    Ok(start.elapsed().as_secs_f64())
+}
+
+pub fn test() -> TestCase {
+    TestCase::new("warp_route_paused", warp_route_paused).with_teardown(unpause_warp_route)
+}
@@ -0,0 +1,63 @@
+use fuels::{
+    programs::calls::CallParameters,
+    types::{AssetId, Bits256},
+};
+use tokio::time::Instant;
+
+use crate::{
+    cases::TestCase,
+    setup::{abis::WarpRoute, get_loaded_wallet},
+    utils::{
+        expect_revert, get_remote_test_recipient, local_contracts::get_contract_address_from_yaml,
+    },
+};
+
+const UNENROLLED_DOMAIN: u32 = 0xdead;
+
+async fn warp_route_unenrolled_domain() -> Result<f64, String> {
+    let start = Instant::now();
+    let wallet = get_loaded_wallet().await;
+
+    let amount = 1000;
+    let warp_route_id = get_contract_address_from_yaml("warpRouteNative");
+    let warp_route_instance = WarpRoute::new(warp_route_id, wallet.clone());
+
+    let router = warp_route_instance
+        .methods()
+        .router(UNENROLLED_DOMAIN)
+        .call()
+        .await
+        .map_err(|e| format!("Failed to get router: {:?}", e))?
+        .value;
+
+    if router != Bits256::zeroed() {
+        return Err(format!(
+            "Expected no router for domain {}, got: {:?}",
+            UNENROLLED_DOMAIN, router
+        ));
+    }
+
+    let transfer = warp_route_instance
+        .methods()
+        .transfer_remote(
+            UNENROLLED_DOMAIN,
+            get_remote_test_recipient(),
+            amount,
+            None,
+            None,
+        )
+        .call_params(CallParameters::new(amount, AssetId::BASE, 20_000_000))
+        .unwrap()
+        .call()
+        .await;
+
+    expect_revert(transfer, "RouterNotSet")?;
+
+    println!("✅ warp_route_unenrolled_domain passed");
+
+    Ok(start.elapsed().as_secs_f64())
+}
+
+pub fn test() -> TestCase {
+    TestCase::new("warp_route_unenrolled_domain", warp_route_unenrolled_domain)
+}
@@ -15,12 +15,11 @@ use alloy_provider::{
     },
     Identity, Provider, RootProvider,
 };
-use alloy_rpc_types::{BlockNumberOrTag, Filter};
-use futures_util::StreamExt;
 use sepolia_warp_route_native::SepoliaWarpRouteNative::SepoliaWarpRouteNativeInstance;
 use serde::Deserialize;
 use std::fs;
 use std::{collections::HashMap, env};
+use tokio::time::Instant;
 use SepoliaCollateralERC20::SepoliaCollateralERC20Instance;
 
 use sepolia_warp_route_collateral::SepoliaWarpRouteCollateral::SepoliaWarpRouteCollateralInstance;
@@ -30,11 +29,23 @@ use SepoliaRecipient::SepoliaRecipientInstance;
 
 use crate::{
     setup::abis::Mailbox,
-    utils::local_contracts::{
-        get_value_from_agent_config_json, load_remote_wr_addresses, load_remote_wr_token_address,
+    utils::{
+        local_contracts::{
+            get_value_from_agent_config_json, load_remote_wr_addresses,
+            load_remote_wr_token_address,
+        },
+        retry::{retry_rpc, DeliveryPolicy, TransientError},
     },
 };
-use fuels::{accounts::wallet::Wallet, programs::calls::Execution, types::Bits256};
+use fuel_hyperlane_sdk::{
+    events::{ContractKind, EventCursor, EventSubscription},
+    fuels::{prelude::Provider as SdkProvider, types::ContractId as SdkContractId},
+};
+use fuels::{
+    accounts::{wallet::Wallet, ViewOnlyAccount},
+    programs::calls::Execution,
+    types::Bits256,
+};
 
 sol!(
     #[allow(missing_docs)]
@@ -261,47 +272,173 @@ pub async fn get_evm_provider(wallet: EthereumWallet) -> EvmProvider {
         .unwrap()
 }
 
+/// Waits for the `ProcessIdEvent` of `message_id` in the blocks of the Fuel mailbox.
+///
+/// Only blocks produced after the call are scanned, so the mailbox is checked once
+/// beforehand for a message delivered before the subscription started.
+///
+/// ### Errors
+///
+/// * If the message is not delivered within `policy.timeout`. The error contains the
+///   message id and the last known mailbox state.
+/// * If an RPC call fails with a non transient error, or keeps failing after
+///   `policy.rpc_retries` retries.
 pub async fn monitor_fuel_for_delivery(
     mailbox_instance: Mailbox<Wallet>,
     message_id: FixedBytes<32>,
-) -> bool {
-    let message_id = Bits256(message_id.0);
-    let mut notified = false;
+    policy: DeliveryPolicy,
+) -> Result<(), String> {
+    let id = Bits256(message_id.0);
+    let start = Instant::now();
+    let provider = mailbox_instance.account().provider().clone();
+
+    let cursor = retry_rpc(
+        "Fuel latest block height",
+        policy.rpc_retries,
+        policy.backoff,
+        || provider.latest_block_height(),
+    )
+    .await
+    .map(|height| EventCursor::from_block(height + 1))?;
+
+    let delivered = retry_rpc(
+        "Fuel `delivered` call",
+        policy.rpc_retries,
+        policy.backoff,
+        || async {
+            mailbox_instance
+                .methods()
+                .delivered(id)
+                .simulate(Execution::state_read_only())
+                .await
+        },
+    )
+    .await?
+    .value;
+    if delivered {
+        return Ok(());
+    }
+
+    // The SDK is built against the `fuels` version of the workspace, so the subscription
+    // connects its own provider to the same node and takes the mailbox ID as bytes
+    let sdk_provider = retry_rpc(
+        "Fuel provider connection",
+        policy.rpc_retries,
+        policy.backoff,
+        || SdkProvider::connect(provider.url()),
+    )
+    .await?;
+    let mailbox_id = SdkContractId::new(*mailbox_instance.contract_id().hash());
+
+    println!("Waiting for message delivery");
+    let mut subscription =
+        EventSubscription::new(sdk_provider, cursor).watch(mailbox_id, ContractKind::Mailbox);
+    let mut attempt = 0;
+    let mut delay = policy.backoff.initial;
 
     loop {
-        let delivered_res = mailbox_instance
-            .methods()
-            .delivered(message_id)
-            .simulate(Execution::state_read_only())
+        let remaining = policy.timeout.saturating_sub(start.elapsed());
+        // The cursor only advances past fully fetched blocks, so a failed poll resumes where it stopped
+        match subscription
+            .wait_for_process(id, policy.backoff.initial, remaining)
             .await
-            .unwrap();
-
-        if delivered_res.value {
-            return true;
-        } else if !notified {
-            println!("Waiting for message delivery");
-            notified = true;
+        {
+            Ok(Some(_)) => return Ok(()),
+            Ok(None) => break,
+            Err(e) if e.is_transient() && attempt < policy.rpc_retries => {
+                attempt += 1;
+                println!(
+                    "Fuel event poll failed, retrying ({}/{}): {}",
+                    attempt, policy.rpc_retries, e
+                );
+                tokio::time::sleep(delay).await;
+                delay = policy.backoff.next(delay);
+            }
+            Err(e) if e.is_transient() => {
+                return Err(format!(
+                    "Fuel event poll failed after {} retries: {}",
+                    policy.rpc_retries, e
+                ))
+            }
+            Err(e) => return Err(format!("Fuel event poll failed: {}", e)),
         }
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
     }
-}
 
-pub async fn monitor_evm_for_delivery(wr_address: Address) {
-    let provider = ProviderBuilder::new()
-        .on_builtin("ws://localhost:8545")
+    let nonce = mailbox_instance
+        .methods()
+        .nonce()
+        .simulate(Execution::state_read_only())
         .await
-        .unwrap();
-
-    let filter = Filter::new()
-        .address(wr_address.to_owned())
-        .event("ReceivedTransferRemote(uint32,bytes32,uint256)")
-        .from_block(BlockNumberOrTag::Latest);
+        .map(|res| res.value.to_string())
+        .unwrap_or_else(|e| format!("unknown ({:?})", e));
+
+    Err(format!(
+        "Message {} was not delivered on Fuel within {:.0} sec. Last known mailbox state: delivered: false, nonce: {}, last scanned block: {}",
+        message_id,
+        policy.timeout.as_secs_f64(),
+        nonce,
+        subscription.cursor().next_block.saturating_sub(1)
+    ))
+}
 
-    let sub = provider.subscribe_logs(&filter).await.unwrap();
-    let mut stream = sub.into_stream();
+/// Polls the EVM mailbox until `message_id` is delivered, backing off between polls.
+///
+/// ### Errors
+///
+/// * If the message is not delivered within `policy.timeout`. The error contains the
+///   message id and the last known mailbox state.
+/// * If an RPC call fails with a non transient error, or keeps failing after
+///   `policy.rpc_retries` retries.
+pub async fn monitor_evm_for_delivery(
+    mailbox: &SepoliaMailboxInstance<BoxTransport, EvmProvider>,
+    message_id: FixedBytes<32>,
+    policy: DeliveryPolicy,
+) -> Result<(), String> {
+    let start = Instant::now();
+    let mut delay = policy.backoff.initial;
 
     println!("Waiting for EVM contract to receive assets...");
-    stream.next().await;
+
+    loop {
+        let delivered = retry_rpc(
+            "EVM `delivered` call",
+            policy.rpc_retries,
+            policy.backoff,
+            || async { mailbox.delivered(message_id).call().await },
+        )
+        .await?
+        ._0;
+
+        if delivered {
+            return Ok(());
+        }
+
+        if start.elapsed() >= policy.timeout {
+            let nonce = mailbox
+                .nonce()
+                .call()
+                .await
+                .map(|res| res._0.to_string())
+                .unwrap_or_else(|e| format!("unknown ({:?})", e));
+            let block = mailbox
+                .provider()
+                .get_block_number()
+                .await
+                .map(|block| block.to_string())
+                .unwrap_or_else(|e| format!("unknown ({:?})", e));
+
+            return Err(format!(
+                "Message {} was not delivered on EVM within {:.0} sec. Last known mailbox state: delivered: false, nonce: {}, block: {}",
+                message_id,
+                policy.timeout.as_secs_f64(),
+                nonce,
+                block
+            ));
+        }
+
+        tokio::time::sleep(delay.min(policy.timeout.saturating_sub(start.elapsed()))).await;
+        delay = policy.backoff.next(delay);
+    }
 }
 
 impl SepoliaContracts {
@@ -0,0 +1,273 @@
+use alloy::{
+    primitives::{Address, B256},
+    rpc::types::{Filter, Log, TransactionReceipt},
+    sol_types::SolEvent,
+    transports::BoxTransport,
+};
+use alloy_provider::Provider;
+use hyperlane_core::{HyperlaneMessage, H256};
+
+use super::{Delivery, Dispatch, GasPayment, EVM_CHAIN_NAME};
+use crate::{
+    evm::{
+        get_evm_provider, get_evm_wallet, EvmProvider,
+        SepoliaMailbox::{self, SepoliaMailboxInstance},
+    },
+    utils::{
+        get_evm_domain,
+        local_contracts::{get_contract_name_from_json, get_value_from_agent_config_json},
+    },
+};
+
+/// Hook events and calls which are not part of the mailbox ABI.
+mod hooks {
+    use alloy::sol;
+
+    sol! {
+        event InsertedIntoTree(bytes32 messageId, uint32 index);
+
+        event GasPayment(
+            bytes32 indexed messageId,
+            uint32 indexed destinationDomain,
+            uint256 gasAmount,
+            uint256 payment
+        );
+
+        #[sol(rpc)]
+        contract SepoliaMerkleTreeHook {
+            function latestCheckpoint() external view returns (bytes32, uint32);
+        }
+    }
+}
+
+/// The EVM side of the explorer, which finds messages through the mailbox logs.
+pub struct EvmChain {
+    pub domain: u32,
+    provider: EvmProvider,
+    mailbox: SepoliaMailboxInstance<BoxTransport, EvmProvider>,
+}
+
+impl EvmChain {
+    pub async fn connect() -> Self {
+        let provider = get_evm_provider(get_evm_wallet().await).await;
+        let mailbox_address: Address = get_value_from_agent_config_json(EVM_CHAIN_NAME, "mailbox")
+            .and_then(|address| address.as_str().map(str::to_owned))
+            .expect("Failed to get mailbox address")
+            .parse()
+            .expect("Invalid address format");
+
+        Self {
+            domain: get_evm_domain(),
+            mailbox: SepoliaMailbox::new(mailbox_address, provider.clone()),
+            provider,
+        }
+    }
+
+    /// Ids of the messages dispatched or processed in the transaction `tx_id`,
+    /// empty if it is not an EVM transaction.
+    pub async fn messages_in_tx(&self, tx_id: H256) -> Result<Vec<H256>, String> {
+        let Some(receipt) = self.receipt(B256::from(tx_id.0)).await? else {
+            return Ok(vec![]);
+        };
+
+        Ok(self
+            .mailbox_logs(&receipt)
+            .filter_map(|log| {
+                let dispatched = log
+                    .log_decode::<SepoliaMailbox::DispatchId>()
+                    .map(|log| log.inner.data.messageId);
+                let processed = log
+                    .log_decode::<SepoliaMailbox::ProcessId>()
+                    .map(|log| log.inner.data.messageId);
+                dispatched.or(processed).ok()
+            })
+            .map(|id| H256(id.0))
+            .collect())
+    }
+
+    /// The dispatch of `message_id`, if it was dispatched by the EVM mailbox.
+    pub async fn dispatch(&self, message_id: H256) -> Result<Option<Dispatch>, String> {
+        let id = B256::from(message_id.0);
+        let filter = Filter::new()
+            .address(*self.mailbox.address())
+            .event_signature(SepoliaMailbox::DispatchId::SIGNATURE_HASH)
+            .topic1(id)
+            .from_block(0);
+
+        let Some(tx_hash) = self
+            .logs(&filter)
+            .await?
+            .first()
+            .and_then(|log| log.transaction_hash)
+        else {
+            return Ok(None);
+        };
+        let Some(receipt) = self.receipt(tx_hash).await? else {
+            return Ok(None);
+        };
+
+        let Some(message) = self
+            .mailbox_logs(&receipt)
+            .filter_map(|log| log.log_decode::<SepoliaMailbox::Dispatch>().ok())
+            .map(|log| HyperlaneMessage::from(log.inner.data.message.to_vec()))
+            .find(|message| message.id() == message_id)
+        else {
+            return Ok(None);
+        };
+
+        let logs = receipt.inner.logs();
+
+        let gas_payments = logs
+            .iter()
+            .filter_map(|log| log.log_decode::<hooks::GasPayment>().ok())
+            .map(|log| log.inner.data)
+            .filter(|payment| payment.messageId == id)
+            .map(|payment| GasPayment {
+                destination: payment.destinationDomain,
+                gas_amount: payment.gasAmount.to_string(),
+                payment: payment.payment.to_string(),
+            })
+            .collect();
+
+        let insertion = logs
+            .iter()
+            .filter_map(|log| log.log_decode::<hooks::InsertedIntoTree>().ok())
+            .find(|log| log.inner.data.messageId == id);
+
+        // The emitter of the insertion is the merkle tree hook the validators sign
+        let latest_checkpoint = match &insertion {
+            Some(log) => hooks::SepoliaMerkleTreeHook::new(log.address(), self.provider.clone())
+                .latestCheckpoint()
+                .call()
+                .await
+                .map(|res| res._1)
+                .ok(),
+            None => None,
+        };
+
+        let mut contracts: Vec<String> = vec![];
+        for log in logs.iter() {
+            let contract = label(&log.address());
+            if !contracts.contains(&contract) {
+                contracts.push(contract);
+            }
+        }
+
+        let required_hook = self
+            .mailbox
+            .requiredHook()
+            .call()
+            .await
+            .map_err(|e| format!("Failed to read EVM required hook: {:?}", e))?
+            ._0;
+        let default_hook = self
+            .mailbox
+            .defaultHook()
+            .call()
+            .await
+            .map_err(|e| format!("Failed to read EVM default hook: {:?}", e))?
+            ._0;
+
+        Ok(Some(Dispatch {
+            chain: EVM_CHAIN_NAME,
+            message,
+            block: receipt.block_number.unwrap_or_default(),
+            tx_id: tx_hash.to_string(),
+            required_hook: label(&required_hook),
+            default_hook: label(&default_hook),
+            contracts,
+            gas_payments,
+            merkle_index: insertion.map(|log| log.inner.data.index),
+            latest_checkpoint,
+        }))
+    }
+
+    /// Whether `message_id` was delivered by the EVM mailbox, by whom and in which transaction.
+    pub async fn delivery(&self, message_id: H256) -> Result<Delivery, String> {
+        let id = B256::from(message_id.0);
+        let delivered = self
+            .mailbox
+            .delivered(id)
+            .call()
+            .await
+            .map_err(|e| format!("Failed to read EVM delivered: {:?}", e))?
+            ._0;
+
+        let processed = if delivered {
+            let block = self
+                .mailbox
+                .processedAt(id)
+                .call()
+                .await
+                .map_err(|e| format!("Failed to read EVM processedAt: {:?}", e))?
+                ._0
+                .to::<u64>();
+
+            let filter = Filter::new()
+                .address(*self.mailbox.address())
+                .event_signature(SepoliaMailbox::ProcessId::SIGNATURE_HASH)
+                .topic1(id)
+                .from_block(block)
+                .to_block(block);
+
+            self.logs(&filter)
+                .await?
+                .first()
+                .and_then(|log| log.transaction_hash)
+                .map(|tx_hash| (block, tx_hash.to_string()))
+        } else {
+            None
+        };
+
+        let processor = if delivered {
+            let processor = self
+                .mailbox
+                .processor(id)
+                .call()
+                .await
+                .map_err(|e| format!("Failed to read EVM processor: {:?}", e))?
+                ._0;
+            Some(label(&processor))
+        } else {
+            None
+        };
+
+        Ok(Delivery {
+            chain: EVM_CHAIN_NAME,
+            delivered,
+            processor,
+            processed,
+        })
+    }
+
+    async fn receipt(&self, tx_hash: B256) -> Result<Option<TransactionReceipt>, String> {
+        self.provider
+            .get_transaction_receipt(tx_hash)
+            .await
+            .map_err(|e| format!("Failed to read EVM transaction receipt: {:?}", e))
+    }
+
+    async fn logs(&self, filter: &Filter) -> Result<Vec<Log>, String> {
+        self.provider
+            .get_logs(filter)
+            .await
+            .map_err(|e| format!("Failed to read EVM logs: {:?}", e))
+    }
+
+    fn mailbox_logs<'a>(&self, receipt: &'a TransactionReceipt) -> impl Iterator<Item = &'a Log> {
+        let mailbox = *self.mailbox.address();
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter(move |log| log.address() == mailbox)
+    }
+}
+
+/// The address, followed by its name in the agent config if it has one.
+fn label(address: &Address) -> String {
+    match get_contract_name_from_json(EVM_CHAIN_NAME, &address.to_string()) {
+        Some(name) => format!("{} ({})", address, name),
+        None => address.to_string(),
+    }
+}
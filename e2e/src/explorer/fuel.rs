@@ -0,0 +1,347 @@
+use fuels::{
+    accounts::wallet::Wallet,
+    core::{
+        codec::LogDecoder,
+        traits::{Parameterize, Tokenizable},
+    },
+    prelude::Provider,
+    programs::calls::Execution,
+    tx::Receipt,
+    types::{Bits256, Bytes32, ContractId, Identity},
+};
+use hyperlane_core::{HyperlaneMessage, H256};
+
+use super::{Delivery, Dispatch, GasPayment, FUEL_CHAIN_NAME};
+use crate::{
+    setup::{
+        abis::{
+            DispatchEvent, DispatchIdEvent, GasPaymentEvent, InsertedIntoTreeEvent,
+            InterchainGasPaymaster, Mailbox, MerkleTreeHook, ProcessIdEvent,
+        },
+        get_loaded_wallet, get_provider,
+    },
+    utils::{
+        get_fuel_domain,
+        local_contracts::{get_contract_address_from_json, get_contract_name_from_json},
+    },
+};
+
+/// The Fuel side of the explorer. Fuel has no log filters,
+/// so messages are found by scanning the blocks from `from_block`.
+pub struct FuelChain {
+    pub domain: u32,
+    provider: Provider,
+    mailbox: Mailbox<Wallet>,
+    merkle_tree_hook: MerkleTreeHook<Wallet>,
+    igp_decoder: LogDecoder,
+    igp_id: ContractId,
+    from_block: u32,
+}
+
+/// A successful Fuel transaction.
+struct FuelTx {
+    block: u32,
+    tx_id: Bytes32,
+    receipts: Vec<Receipt>,
+}
+
+impl FuelChain {
+    pub async fn connect(from_block: u32) -> Self {
+        let wallet = get_loaded_wallet().await;
+        let igp_id = get_contract_address_from_json(FUEL_CHAIN_NAME, "interchainGasPaymaster");
+
+        Self {
+            domain: get_fuel_domain(),
+            provider: get_provider().await,
+            mailbox: Mailbox::new(
+                get_contract_address_from_json(FUEL_CHAIN_NAME, "mailbox"),
+                wallet.clone(),
+            ),
+            merkle_tree_hook: MerkleTreeHook::new(
+                get_contract_address_from_json(FUEL_CHAIN_NAME, "merkleTreeHook"),
+                wallet.clone(),
+            ),
+            igp_decoder: InterchainGasPaymaster::new(igp_id, wallet).log_decoder(),
+            igp_id,
+            from_block,
+        }
+    }
+
+    /// Ids of the messages dispatched or processed in the transaction `tx_id`,
+    /// empty if it is not a Fuel transaction.
+    pub async fn messages_in_tx(&self, tx_id: H256) -> Result<Vec<H256>, String> {
+        let Some(tx) = self.tx(Bytes32::new(tx_id.0)).await? else {
+            return Ok(vec![]);
+        };
+
+        let dispatched = self.mailbox_logs::<DispatchIdEvent>(&tx.receipts);
+        let processed = self.mailbox_logs::<ProcessIdEvent>(&tx.receipts);
+        Ok(dispatched
+            .iter()
+            .map(|event| event.message_id)
+            .chain(processed.iter().map(|event| event.message_id))
+            .map(|id| H256(id.0))
+            .collect())
+    }
+
+    /// The dispatch of `message_id`, if it was dispatched by the Fuel mailbox.
+    pub async fn dispatch(&self, message_id: H256) -> Result<Option<Dispatch>, String> {
+        let id = Bits256(message_id.0);
+        let latest = self
+            .provider
+            .latest_block_height()
+            .await
+            .map_err(|e| format!("Failed to read Fuel block height: {:?}", e))?;
+
+        let Some(tx) = self
+            .find_tx(self.from_block, latest, |receipts| {
+                self.mailbox_logs::<DispatchIdEvent>(receipts)
+                    .iter()
+                    .any(|event| event.message_id == id)
+            })
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let Some(message) = self
+            .mailbox_logs::<DispatchEvent>(&tx.receipts)
+            .into_iter()
+            .map(|event| HyperlaneMessage::from(event.message.bytes.0))
+            .find(|message| message.id() == message_id)
+        else {
+            return Ok(None);
+        };
+
+        let gas_payments = logs::<GasPaymentEvent>(&tx.receipts, &self.igp_id, &self.igp_decoder)
+            .into_iter()
+            .filter(|payment| payment.message_id == id)
+            .map(|payment| GasPayment {
+                destination: payment.destination_domain,
+                gas_amount: payment.gas_amount.to_string(),
+                payment: payment.payment.to_string(),
+            })
+            .collect();
+
+        let merkle_index = logs::<InsertedIntoTreeEvent>(
+            &tx.receipts,
+            &self.merkle_tree_hook.contract_id().into(),
+            &self.merkle_tree_hook.log_decoder(),
+        )
+        .into_iter()
+        .find(|event| event.message_id == id)
+        .map(|event| event.index);
+
+        let mut contracts: Vec<String> = vec![];
+        for receipt in tx.receipts.iter() {
+            if let Receipt::Call { to, .. } = receipt {
+                let contract = label(to);
+                if !contracts.contains(&contract) {
+                    contracts.push(contract);
+                }
+            }
+        }
+
+        let latest_checkpoint = self
+            .merkle_tree_hook
+            .methods()
+            .latest_checkpoint()
+            .simulate(Execution::state_read_only())
+            .await
+            .map(|res| res.value.1)
+            .ok();
+
+        Ok(Some(Dispatch {
+            chain: FUEL_CHAIN_NAME,
+            message,
+            block: tx.block.into(),
+            tx_id: format!("{:#x}", tx.tx_id),
+            required_hook: label(&self.required_hook().await?),
+            default_hook: label(&self.default_hook().await?),
+            contracts,
+            gas_payments,
+            merkle_index,
+            latest_checkpoint,
+        }))
+    }
+
+    /// Whether `message_id` was delivered by the Fuel mailbox, by whom and in which transaction.
+    pub async fn delivery(&self, message_id: H256) -> Result<Delivery, String> {
+        let id = Bits256(message_id.0);
+        let delivered = self
+            .mailbox
+            .methods()
+            .delivered(id)
+            .simulate(Execution::state_read_only())
+            .await
+            .map_err(|e| format!("Failed to read Fuel delivered: {:?}", e))?
+            .value;
+
+        if !delivered {
+            return Ok(Delivery {
+                chain: FUEL_CHAIN_NAME,
+                delivered,
+                processor: None,
+                processed: None,
+            });
+        }
+
+        let processor = self
+            .mailbox
+            .methods()
+            .processor(id)
+            .simulate(Execution::state_read_only())
+            .await
+            .map_err(|e| format!("Failed to read Fuel processor: {:?}", e))?
+            .value;
+        let processed_at = self
+            .mailbox
+            .methods()
+            .processed_at(id)
+            .simulate(Execution::state_read_only())
+            .await
+            .map_err(|e| format!("Failed to read Fuel processed_at: {:?}", e))?
+            .value;
+
+        // Only the processing block has to be scanned for the transaction
+        let processed = match processed_at {
+            Some(block) => self
+                .find_tx(block, block, |receipts| {
+                    self.mailbox_logs::<ProcessIdEvent>(receipts)
+                        .iter()
+                        .any(|event| event.message_id == id)
+                })
+                .await?
+                .map(|tx| (tx.block.into(), format!("{:#x}", tx.tx_id))),
+            None => None,
+        };
+
+        Ok(Delivery {
+            chain: FUEL_CHAIN_NAME,
+            delivered,
+            processor: processor.map(|processor| match processor {
+                Identity::Address(address) => format!("0x{}", hex::encode(address)),
+                Identity::ContractId(contract_id) => label(&contract_id),
+            }),
+            processed,
+        })
+    }
+
+    async fn required_hook(&self) -> Result<ContractId, String> {
+        self.mailbox
+            .methods()
+            .required_hook()
+            .simulate(Execution::state_read_only())
+            .await
+            .map(|res| res.value)
+            .map_err(|e| format!("Failed to read Fuel required hook: {:?}", e))
+    }
+
+    async fn default_hook(&self) -> Result<ContractId, String> {
+        self.mailbox
+            .methods()
+            .default_hook()
+            .simulate(Execution::state_read_only())
+            .await
+            .map(|res| res.value)
+            .map_err(|e| format!("Failed to read Fuel default hook: {:?}", e))
+    }
+
+    /// The successful transaction `tx_id`, `None` if it does not exist.
+    async fn tx(&self, tx_id: Bytes32) -> Result<Option<FuelTx>, String> {
+        let Some(block) = self
+            .provider
+            .get_transaction_by_id(&tx_id)
+            .await
+            .map_err(|e| format!("Failed to read Fuel transaction: {:?}", e))?
+            .and_then(|tx| tx.block_height)
+        else {
+            return Ok(None);
+        };
+
+        self.successful_tx(*block, tx_id).await
+    }
+
+    /// The first successful transaction between `from` and `to` whose receipts match `predicate`.
+    async fn find_tx(
+        &self,
+        from: u32,
+        to: u32,
+        predicate: impl Fn(&[Receipt]) -> bool,
+    ) -> Result<Option<FuelTx>, String> {
+        for height in from..=to {
+            let Some(block) = self
+                .provider
+                .block_by_height(height.into())
+                .await
+                .map_err(|e| format!("Failed to read Fuel block {}: {:?}", height, e))?
+            else {
+                continue;
+            };
+
+            for tx_id in block.transactions {
+                if let Some(tx) = self.successful_tx(height, tx_id).await? {
+                    if predicate(&tx.receipts) {
+                        return Ok(Some(tx));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    async fn successful_tx(&self, block: u32, tx_id: Bytes32) -> Result<Option<FuelTx>, String> {
+        let status = self
+            .provider
+            .tx_status(&tx_id)
+            .await
+            .map_err(|e| format!("Failed to read Fuel transaction status: {:?}", e))?;
+
+        if status.check(None).is_err() {
+            return Ok(None);
+        }
+
+        Ok(Some(FuelTx {
+            block,
+            tx_id,
+            receipts: status.take_receipts().to_vec(),
+        }))
+    }
+
+    fn mailbox_logs<T: Tokenizable + Parameterize + 'static>(
+        &self,
+        receipts: &[Receipt],
+    ) -> Vec<T> {
+        logs(
+            receipts,
+            &self.mailbox.contract_id().into(),
+            &self.mailbox.log_decoder(),
+        )
+    }
+}
+
+/// The logs of type `T` emitted by `contract_id`.
+fn logs<T: Tokenizable + Parameterize + 'static>(
+    receipts: &[Receipt],
+    contract_id: &ContractId,
+    decoder: &LogDecoder,
+) -> Vec<T> {
+    let receipts: Vec<Receipt> = receipts
+        .iter()
+        .filter(|receipt| matches!(receipt, Receipt::LogData { id, .. } if id == contract_id))
+        .cloned()
+        .collect();
+
+    decoder
+        .decode_logs_with_type::<T>(&receipts)
+        .unwrap_or_default()
+}
+
+/// The contract id, followed by its name in the agent config if it has one.
+fn label(contract_id: &ContractId) -> String {
+    let address = format!("0x{}", hex::encode(contract_id));
+    match get_contract_name_from_json(FUEL_CHAIN_NAME, &address) {
+        Some(name) => format!("{} ({})", address, name),
+        None => address,
+    }
+}
@@ -0,0 +1,241 @@
+//! Shows the lifecycle of a Hyperlane message between the local Fuel and EVM chains:
+//! the dispatch on the origin, its merkle tree index and validator checkpoint coverage,
+//! and the delivery on the destination.
+//!
+//! Usage, against the chains started by `infra/run.sh`:
+//!   cargo run -- explore <message id or transaction id> [--from-block <fuel block>]
+//!
+//! A transaction id shows every message dispatched or processed in that transaction.
+//! Fuel has no log filters, so Fuel blocks are scanned from `--from-block`, which defaults to 0.
+
+mod evm;
+mod fuel;
+
+use std::{fs, process};
+
+use hyperlane_core::{HyperlaneMessage, H256};
+
+pub use evm::EvmChain;
+pub use fuel::FuelChain;
+
+pub const FUEL_CHAIN_NAME: &str = "fueltest1";
+pub const EVM_CHAIN_NAME: &str = "test1";
+
+/// An IGP payment made for a dispatched message.
+#[derive(Debug, Clone)]
+pub struct GasPayment {
+    pub destination: u32,
+    pub gas_amount: String,
+    pub payment: String,
+}
+
+/// A message dispatch on its origin chain.
+#[derive(Debug, Clone)]
+pub struct Dispatch {
+    pub chain: &'static str,
+    pub message: HyperlaneMessage,
+    pub block: u64,
+    pub tx_id: String,
+    /// Current required and default hooks of the origin mailbox.
+    pub required_hook: String,
+    pub default_hook: String,
+    /// Contracts called or emitting logs in the dispatch transaction, i.e. the hooks used.
+    pub contracts: Vec<String>,
+    pub gas_payments: Vec<GasPayment>,
+    pub merkle_index: Option<u32>,
+    /// Index of the latest checkpoint of the merkle tree hook the message was inserted into.
+    pub latest_checkpoint: Option<u32>,
+}
+
+/// The state of a message on its destination chain.
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    pub chain: &'static str,
+    pub delivered: bool,
+    /// Account which processed the message.
+    pub processor: Option<String>,
+    /// Block and transaction in which the message was processed.
+    pub processed: Option<(u64, String)>,
+}
+
+/// Everything known about a message on both chains.
+#[derive(Debug, Clone)]
+pub struct Lifecycle {
+    pub message_id: H256,
+    pub dispatch: Option<Dispatch>,
+    /// Latest checkpoint index signed by the validator of the origin chain.
+    pub validator_checkpoint: Option<u32>,
+    pub deliveries: Vec<Delivery>,
+}
+
+impl Lifecycle {
+    /// Whether the validator of the origin chain signed a checkpoint including the message.
+    pub fn checkpoint_covered(&self) -> Option<bool> {
+        let index = self.dispatch.as_ref()?.merkle_index?;
+        Some(self.validator_checkpoint? >= index)
+    }
+
+    pub fn print(&self) {
+        println!("Message {:?}", self.message_id);
+
+        match &self.dispatch {
+            Some(dispatch) => {
+                let message = &dispatch.message;
+                println!("  Dispatch on {}", dispatch.chain);
+                println!(
+                    "    {} -> {}, nonce: {}",
+                    message.origin, message.destination, message.nonce
+                );
+                println!("    sender: {:?}", message.sender);
+                println!("    recipient: {:?}", message.recipient);
+                println!("    block: {}, tx: {}", dispatch.block, dispatch.tx_id);
+                println!("    required hook: {}", dispatch.required_hook);
+                println!("    default hook: {}", dispatch.default_hook);
+                println!("    contracts used: {}", dispatch.contracts.join(", "));
+                if dispatch.gas_payments.is_empty() {
+                    println!("    gas payment: none");
+                }
+                for payment in dispatch.gas_payments.iter() {
+                    println!(
+                        "    gas payment: {} for a gas limit of {} on {}",
+                        payment.payment, payment.gas_amount, payment.destination
+                    );
+                }
+                match dispatch.merkle_index {
+                    Some(index) => println!("    merkle tree index: {}", index),
+                    None => println!("    merkle tree index: not inserted"),
+                }
+
+                println!("  Checkpoints");
+                println!(
+                    "    latest merkle tree checkpoint: {}",
+                    optional(dispatch.latest_checkpoint)
+                );
+                println!(
+                    "    latest validator checkpoint: {}",
+                    optional(self.validator_checkpoint)
+                );
+                match self.checkpoint_covered() {
+                    Some(true) => println!("    covered by a validator checkpoint"),
+                    Some(false) => println!("    not yet covered by a validator checkpoint"),
+                    None => println!("    coverage unknown"),
+                }
+            }
+            None => println!(
+                "  Dispatch not found on {} or {}",
+                FUEL_CHAIN_NAME, EVM_CHAIN_NAME
+            ),
+        }
+
+        for delivery in self.deliveries.iter() {
+            println!("  Delivery on {}", delivery.chain);
+            println!("    delivered: {}", delivery.delivered);
+            if let Some(processor) = &delivery.processor {
+                println!("    processor: {}", processor);
+            }
+            if let Some((block, tx_id)) = &delivery.processed {
+                println!("    processed in block: {}, tx: {}", block, tx_id);
+            }
+        }
+    }
+}
+
+/// Entry point of the `explore` command.
+pub async fn run(args: &[String]) {
+    let Some(id) = args.first().and_then(|id| parse_id(id)) else {
+        eprintln!("Usage: explore <message id or transaction id> [--from-block <fuel block>]");
+        process::exit(1);
+    };
+    let from_block = args
+        .iter()
+        .position(|arg| arg == "--from-block")
+        .and_then(|i| args.get(i + 1))
+        .map(|block| block.parse().expect("Invalid --from-block"))
+        .unwrap_or(0);
+
+    let fuel = FuelChain::connect(from_block).await;
+    let evm = EvmChain::connect().await;
+
+    match explore(&fuel, &evm, id).await {
+        Ok(lifecycles) => lifecycles.iter().for_each(Lifecycle::print),
+        Err(e) => {
+            eprintln!("Failed to explore {:?}: {}", id, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// The lifecycles of the message `id`, or of the messages dispatched or processed
+/// in the transaction `id` on either chain.
+pub async fn explore(fuel: &FuelChain, evm: &EvmChain, id: H256) -> Result<Vec<Lifecycle>, String> {
+    let mut message_ids = fuel.messages_in_tx(id).await?;
+    if message_ids.is_empty() {
+        message_ids = evm.messages_in_tx(id).await?;
+    }
+    if message_ids.is_empty() {
+        message_ids.push(id);
+    }
+
+    let mut lifecycles = vec![];
+    for message_id in message_ids {
+        lifecycles.push(lifecycle(fuel, evm, message_id).await?);
+    }
+    Ok(lifecycles)
+}
+
+/// The lifecycle of the message `message_id`. Only the destination chain is checked
+/// for the delivery when the dispatch is found.
+pub async fn lifecycle(
+    fuel: &FuelChain,
+    evm: &EvmChain,
+    message_id: H256,
+) -> Result<Lifecycle, String> {
+    let dispatch = match fuel.dispatch(message_id).await? {
+        Some(dispatch) => Some(dispatch),
+        None => evm.dispatch(message_id).await?,
+    };
+
+    let validator_checkpoint = dispatch
+        .as_ref()
+        .and_then(|dispatch| validator_checkpoint_index(dispatch.chain));
+
+    let destination = dispatch
+        .as_ref()
+        .map(|dispatch| dispatch.message.destination);
+    let mut deliveries = vec![];
+    if destination.is_none() || destination == Some(fuel.domain) {
+        deliveries.push(fuel.delivery(message_id).await?);
+    }
+    if destination.is_none() || destination == Some(evm.domain) {
+        deliveries.push(evm.delivery(message_id).await?);
+    }
+
+    Ok(Lifecycle {
+        message_id,
+        dispatch,
+        validator_checkpoint,
+        deliveries,
+    })
+}
+
+/// Latest checkpoint index written by the validator of `chain_name`
+/// to its local checkpoint syncer directory, see `run_validator` in `infra/run.sh`.
+fn validator_checkpoint_index(chain_name: &str) -> Option<u32> {
+    let path = format!(
+        "../infra/output/hyperlane-validator-signatures-{}/index.json",
+        chain_name
+    );
+    let data = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+fn parse_id(id: &str) -> Option<H256> {
+    let bytes = hex::decode(id.trim_start_matches("0x")).ok()?;
+    (bytes.len() == 32).then(|| H256::from_slice(&bytes))
+}
+
+fn optional(value: Option<u32>) -> String {
+    value
+        .map(|value| value.to_string())
+        .unwrap_or("unknown".to_owned())
+}
@@ -1,29 +1,54 @@
 mod cases;
 mod evm;
+mod explorer;
 mod setup;
 mod utils;
 
-use cases::{pull_test_cases, FailedTestCase};
+use cases::{order_test_cases, pull_test_cases, FailedTestCase};
 use dotenv::dotenv;
+use std::{collections::HashSet, env};
 use tokio::{sync::mpsc, time::Instant};
 use utils::summary;
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
     dotenv().ok();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("explore") {
+        explorer::run(&args[1..]).await;
+        return;
+    }
+
     println!("\nRunning E2E tests\n");
     let start = Instant::now();
 
-    let all_test_cases = pull_test_cases();
+    let all_test_cases = order_test_cases(pull_test_cases()).unwrap();
     let mut failed_test_cases: Vec<FailedTestCase> = vec![];
     let test_amount = all_test_cases.len();
 
     let (tx, mut rx) = mpsc::channel(test_amount);
 
+    let mut unsuccessful: HashSet<String> = HashSet::new();
+
     for test in all_test_cases {
         let tx = tx.clone();
         let test_name = test.name().clone();
-        let result = test.run().await;
+
+        let failed_dependency = test
+            .dependencies()
+            .iter()
+            .find(|dependency| unsuccessful.contains(*dependency))
+            .cloned();
+
+        let result = match failed_dependency {
+            Some(dependency) => Err(format!("Skipped, dependency {} did not pass", dependency)),
+            None => test.run().await,
+        };
+
+        if result.is_err() {
+            unsuccessful.insert(test_name.clone());
+        }
         tx.send((test_name, result)).await.unwrap();
     }
     drop(tx);
@@ -65,4 +65,8 @@ abigen!(
         name = "PausableHook",
         abi = "contracts/hooks/pausable-hook/out/debug/pausable-hook-abi.json",
     ),
+    Contract(
+        name = "PausableISM",
+        abi = "contracts/ism/pausable-ism/out/debug/pausable-ism-abi.json",
+    ),
 );
@@ -1,6 +1,6 @@
 use dotenv::dotenv;
 use fuels::crypto::SecretKey;
-use std::{env, str::FromStr};
+use std::{env, str::FromStr, time::Duration};
 
 #[derive(Debug)]
 pub enum EnvE2E {
@@ -49,3 +49,29 @@ pub fn get_loaded_private_key() -> SecretKey {
     });
     SecretKey::from_str(&private_key).unwrap()
 }
+
+/// Maximum time to wait for a message to be delivered on the destination chain.
+pub fn get_delivery_timeout() -> Duration {
+    let secs = env::var("E2E_DELIVERY_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(120);
+    Duration::from_secs(secs)
+}
+
+/// Maximum time a single test case may run, unless overridden by the case itself.
+pub fn get_case_timeout() -> Duration {
+    let secs = env::var("E2E_CASE_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(300);
+    Duration::from_secs(secs)
+}
+
+/// Number of retries for RPC calls which fail while waiting for a delivery.
+pub fn get_rpc_retries() -> u32 {
+    env::var("E2E_RPC_RETRIES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(3)
+}
@@ -0,0 +1,71 @@
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::*,
+    types::{Bits256, ContractId, Identity, Salt},
+};
+use rand::{thread_rng, Rng};
+
+use crate::setup::{
+    abis::{MsgRecipient, PausableISM, PausableISMConfigurables},
+    get_loaded_wallet,
+};
+
+fn random_salt() -> Salt {
+    Salt::from(thread_rng().gen::<[u8; 32]>())
+}
+
+/// Deploys a fresh `PausableISM` owned by the loaded wallet and a test recipient
+/// using it as its ISM. While the ISM is not paused, any metadata verifies,
+/// so messages to the recipient can be processed without validator signatures.
+///
+/// ### Returns
+///
+/// * `(recipient, ism)` - The contract ids of the recipient and the ISM.
+pub async fn deploy_recipient_with_pausable_ism() -> Result<(ContractId, ContractId), String> {
+    let wallet = get_loaded_wallet().await;
+    let wallet_bits = Bits256(wallet.address().hash().into());
+
+    let configurables = PausableISMConfigurables::default()
+        .with_EXPECTED_OWNER(wallet_bits)
+        .map_err(|e| format!("Failed to set PausableISM configurables: {:?}", e))?;
+
+    let ism_id: ContractId = Contract::load_from(
+        "../contracts/ism/pausable-ism/out/debug/pausable-ism.bin",
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(random_salt()),
+    )
+    .map_err(|e| format!("Failed to load PausableISM: {:?}", e))?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| format!("Failed to deploy PausableISM: {:?}", e))?
+    .contract_id
+    .into();
+
+    PausableISM::new(ism_id, wallet.clone())
+        .methods()
+        .initialize_ownership(Identity::from(wallet.address()))
+        .call()
+        .await
+        .map_err(|e| format!("Failed to initialize PausableISM ownership: {:?}", e))?;
+
+    let recipient_id: ContractId = Contract::load_from(
+        "../contracts/test/msg-recipient-test/out/debug/msg-recipient-test.bin",
+        LoadConfiguration::default().with_salt(random_salt()),
+    )
+    .map_err(|e| format!("Failed to load test recipient: {:?}", e))?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| format!("Failed to deploy test recipient: {:?}", e))?
+    .contract_id
+    .into();
+
+    MsgRecipient::new(recipient_id, wallet.clone())
+        .methods()
+        .set_ism(ism_id)
+        .call()
+        .await
+        .map_err(|e| format!("Failed to set ISM on test recipient: {:?}", e))?;
+
+    Ok((recipient_id, ism_id))
+}
@@ -1,5 +1,7 @@
 pub mod abis;
 pub mod config;
+pub mod deploy;
+pub mod snapshot;
 
 use config::{get_e2e_env, get_loaded_private_key, get_node_url, EnvE2E};
 use fuels::{accounts::signers::private_key::PrivateKeySigner, prelude::*};
@@ -0,0 +1,160 @@
+use fuels::{programs::calls::Execution, types::ContractId};
+
+use crate::{
+    setup::{
+        abis::{DomainGasConfig, InterchainGasPaymaster, Mailbox},
+        get_loaded_wallet,
+    },
+    utils::{
+        get_evm_domain,
+        local_contracts::{get_contract_address_from_json, get_contract_address_from_yaml},
+    },
+};
+
+/// Configuration of the shared local deployment which test cases are allowed to mutate.
+/// Taken before an isolated test case runs and restored once it finishes,
+/// so the next case starts from the same state regardless of execution order.
+///
+/// An ISM or hook which was not set, i.e. the zero address, is recorded as `None`
+/// and left alone on restore, as the Mailbox rejects setting the zero address.
+#[derive(Debug, Clone)]
+pub struct ConfigSnapshot {
+    pub default_ism: Option<ContractId>,
+    pub default_hook: Option<ContractId>,
+    pub required_hook: Option<ContractId>,
+    pub gas_configs: Vec<(u32, DomainGasConfig)>,
+}
+
+impl ConfigSnapshot {
+    /// Domains whose IGP gas config is captured in the snapshot.
+    fn tracked_domains() -> Vec<u32> {
+        vec![get_evm_domain()]
+    }
+
+    pub async fn take() -> Result<Self, String> {
+        let wallet = get_loaded_wallet().await;
+
+        let mailbox_id = get_contract_address_from_json("fueltest1", "mailbox");
+        let igp_id = get_contract_address_from_yaml("interchainGasPaymaster");
+
+        let mailbox = Mailbox::new(mailbox_id, wallet.clone());
+        let igp = InterchainGasPaymaster::new(igp_id, wallet.clone());
+
+        let default_ism = mailbox
+            .methods()
+            .default_ism()
+            .simulate(Execution::state_read_only())
+            .await
+            .map_err(|e| format!("Failed to read default ism: {:?}", e))?
+            .value;
+
+        let default_hook = mailbox
+            .methods()
+            .default_hook()
+            .simulate(Execution::state_read_only())
+            .await
+            .map_err(|e| format!("Failed to read default hook: {:?}", e))?
+            .value;
+
+        let required_hook = mailbox
+            .methods()
+            .required_hook()
+            .simulate(Execution::state_read_only())
+            .await
+            .map_err(|e| format!("Failed to read required hook: {:?}", e))?
+            .value;
+
+        let mut gas_configs = vec![];
+        for domain in Self::tracked_domains() {
+            let config = igp
+                .methods()
+                .get_domain_gas_config(domain)
+                .simulate(Execution::state_read_only())
+                .await
+                .map_err(|e| format!("Failed to read gas config for {}: {:?}", domain, e))?
+                .value;
+            gas_configs.push((domain, config));
+        }
+
+        Ok(Self {
+            default_ism: non_zero(default_ism),
+            default_hook: non_zero(default_hook),
+            required_hook: non_zero(required_hook),
+            gas_configs,
+        })
+    }
+
+    /// Writes back every value which differs from the snapshot, except the unset ones.
+    pub async fn restore(&self) -> Result<(), String> {
+        let current = Self::take().await?;
+        let wallet = get_loaded_wallet().await;
+
+        let mailbox_id = get_contract_address_from_json("fueltest1", "mailbox");
+        let igp_id = get_contract_address_from_yaml("interchainGasPaymaster");
+
+        let mailbox = Mailbox::new(mailbox_id, wallet.clone());
+        let igp = InterchainGasPaymaster::new(igp_id, wallet.clone());
+
+        if let Some(default_ism) = self
+            .default_ism
+            .filter(|id| current.default_ism != Some(*id))
+        {
+            mailbox
+                .methods()
+                .set_default_ism(default_ism)
+                .call()
+                .await
+                .map_err(|e| format!("Failed to restore default ism: {:?}", e))?;
+        }
+
+        if let Some(default_hook) = self
+            .default_hook
+            .filter(|id| current.default_hook != Some(*id))
+        {
+            mailbox
+                .methods()
+                .set_default_hook(default_hook)
+                .call()
+                .await
+                .map_err(|e| format!("Failed to restore default hook: {:?}", e))?;
+        }
+
+        if let Some(required_hook) = self
+            .required_hook
+            .filter(|id| current.required_hook != Some(*id))
+        {
+            mailbox
+                .methods()
+                .set_required_hook(required_hook)
+                .call()
+                .await
+                .map_err(|e| format!("Failed to restore required hook: {:?}", e))?;
+        }
+
+        let (domains, configs): (Vec<u32>, Vec<DomainGasConfig>) = self
+            .gas_configs
+            .iter()
+            .filter(|(domain, config)| {
+                !current
+                    .gas_configs
+                    .iter()
+                    .any(|(d, c)| d == domain && c == config)
+            })
+            .cloned()
+            .unzip();
+
+        if !domains.is_empty() {
+            igp.methods()
+                .set_destination_gas_config(domains, configs)
+                .call()
+                .await
+                .map_err(|e| format!("Failed to restore gas configs: {:?}", e))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn non_zero(id: ContractId) -> Option<ContractId> {
+    (id != ContractId::zeroed()).then_some(id)
+}
@@ -84,3 +84,20 @@ pub fn get_contract_address_from_json(chain_name: &str, contract_name: &str) ->
 
     ContractId::from_str(address).unwrap()
 }
+
+/// The key of the contract deployed at `address` on `chain_name` in the agent config,
+/// e.g. `mailbox`. Addresses are compared case-insensitively.
+pub fn get_contract_name_from_json(chain_name: &str, address: &str) -> Option<String> {
+    let json_addresses = load_json_addresses();
+    let address = address.to_lowercase();
+
+    json_addresses["chains"][chain_name]
+        .as_object()?
+        .iter()
+        .find(|(_, value)| {
+            value
+                .as_str()
+                .is_some_and(|value| value.to_lowercase() == address)
+        })
+        .map(|(name, _)| name.clone())
+}
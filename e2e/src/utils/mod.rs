@@ -1,4 +1,5 @@
 pub mod local_contracts;
+pub mod retry;
 pub mod token;
 
 use crate::cases::FailedTestCase;
@@ -9,18 +10,18 @@ use fuels::{
     types::{
         bech32::Bech32ContractId,
         errors::{transaction::Reason, Error},
-        Bits256, Bytes, U256,
+        Bits256, Bytes, ContractId, U256,
     },
 };
 use hyperlane_core::{HyperlaneMessage, H256};
 use local_contracts::{get_contract_address_from_yaml, get_value_from_agent_config_json};
 use rand::{thread_rng, Rng};
+use test_utils::hook_metadata::StandardHookMetadata;
 use tokio::time::Instant;
 
 pub const TEST_RECIPIENT: &str =
     "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
 
-const MIN_METADATA_LENGTH: u64 = 98;
 const TEST_GAS_AMOUNT: u64 = 5000;
 
 pub fn summary(test_amount: usize, failed: Vec<FailedTestCase>, start: Instant) {
@@ -63,6 +64,35 @@ pub fn get_revert_reason(call_error: Error) -> String {
     }
 }
 
+/// Asserts that a contract call reverted with `expected` as the revert reason.
+pub fn expect_revert<T: std::fmt::Debug>(
+    result: Result<T, Error>,
+    expected: &str,
+) -> Result<(), String> {
+    match result {
+        Ok(value) => Err(format!(
+            "Expected revert with {}, call succeeded with {:?}",
+            expected, value
+        )),
+        Err(Error::Transaction(Reason::Failure { reason, .. })) if reason == expected => Ok(()),
+        Err(e) => Err(format!("Expected revert with {}, got: {:?}", expected, e)),
+    }
+}
+
+/// Builds a valid message from the EVM chain to a Fuel recipient, with a random nonce.
+/// Cases adjust the fields they want to be invalid.
+pub fn inbound_message(recipient: ContractId) -> HyperlaneMessage {
+    HyperlaneMessage {
+        version: 3u8,
+        nonce: thread_rng().gen_range(0..1000000) as u32,
+        origin: get_evm_domain(),
+        sender: H256::from(get_remote_test_recipient().0),
+        destination: get_fuel_domain(),
+        recipient: H256::from(*recipient),
+        body: get_remote_msg_body().to_vec(),
+    }
+}
+
 #[allow(dead_code)]
 pub fn build_message_body(recipient: Bits256, amount: u64) -> Bytes {
     let mut buffer = Vec::new();
@@ -125,23 +155,14 @@ pub fn get_fuel_test_recipient() -> FixedBytes<32> {
     FixedBytes::from_slice(recipient_address.as_slice())
 }
 
-// variant:        [0:2]     // Set to 1
-// msg_value:      [2:34]    // Left as 0
-// gas_limit:      [34:66]   // Left as 0
-// refund_address: [66:98]   // Set to wallet address
+/// Standard hook metadata paying for `TEST_GAS_AMOUNT` gas, refunded to the wallet.
 pub fn create_mock_metadata(wallet: &Wallet) -> Bytes {
-    let mut metadata = vec![0u8; MIN_METADATA_LENGTH as usize];
-
-    metadata[0] = 0;
-    metadata[1] = 1;
-
-    let mut gas_limit_bytes = [0u8; 32];
-
-    gas_limit_bytes[24..32].copy_from_slice(&TEST_GAS_AMOUNT.to_be_bytes());
-    metadata[34..66].copy_from_slice(&gas_limit_bytes);
-
     let wallet_bytes: [u8; 32] = wallet.address().hash().into();
-    metadata[66..98].copy_from_slice(&wallet_bytes);
 
-    Bytes(metadata)
+    Bytes(
+        StandardHookMetadata::new()
+            .with_gas_limit(TEST_GAS_AMOUNT)
+            .with_refund_address(H256::from(wallet_bytes))
+            .encode(),
+    )
 }
@@ -0,0 +1,253 @@
+use std::{fmt::Debug, future::Future, time::Duration};
+
+use alloy::{contract::Error as EvmContractError, transports::RpcError};
+use fuel_hyperlane_sdk::{fuels::types::errors::Error as SdkFuelsError, SdkError};
+use fuels::types::errors::Error as FuelsError;
+
+use crate::setup::config::{get_delivery_timeout, get_rpc_retries};
+
+/// JSON-RPC error codes returned by rate limited EVM endpoints.
+const EVM_RATE_LIMIT_CODES: [i64; 2] = [429, -32005];
+
+/// Exponential backoff between two polls or retries.
+#[derive(Debug, Clone, Copy)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: u32,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(8),
+            multiplier: 2,
+        }
+    }
+}
+
+impl Backoff {
+    pub fn next(&self, current: Duration) -> Duration {
+        current.saturating_mul(self.multiplier).min(self.max)
+    }
+}
+
+/// How long to wait for a cross-chain message and how to poll for it.
+#[derive(Debug, Clone, Copy)]
+pub struct DeliveryPolicy {
+    pub timeout: Duration,
+    pub backoff: Backoff,
+    /// Number of times an RPC call failing with a transient error is retried before giving up.
+    pub rpc_retries: u32,
+}
+
+impl Default for DeliveryPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: get_delivery_timeout(),
+            backoff: Backoff::default(),
+            rpc_retries: get_rpc_retries(),
+        }
+    }
+}
+
+impl DeliveryPolicy {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Splits RPC errors into transient ones, worth retrying, and the rest.
+pub trait TransientError: Debug {
+    /// Whether the call can succeed when retried, e.g. after a dropped connection.
+    /// Reverts and decoding failures are not transient.
+    fn is_transient(&self) -> bool;
+}
+
+impl TransientError for FuelsError {
+    fn is_transient(&self) -> bool {
+        matches!(self, FuelsError::Provider(_) | FuelsError::IO(_))
+    }
+}
+
+/// The error of the `fuels` version the SDK is built against.
+impl TransientError for SdkFuelsError {
+    fn is_transient(&self) -> bool {
+        matches!(self, SdkFuelsError::Provider(_) | SdkFuelsError::IO(_))
+    }
+}
+
+impl TransientError for SdkError {
+    fn is_transient(&self) -> bool {
+        match self {
+            SdkError::Fuels(e) => e.is_transient(),
+            _ => false,
+        }
+    }
+}
+
+impl TransientError for EvmContractError {
+    fn is_transient(&self) -> bool {
+        match self {
+            EvmContractError::TransportError(RpcError::Transport(_)) => true,
+            EvmContractError::TransportError(RpcError::ErrorResp(payload)) => {
+                EVM_RATE_LIMIT_CODES.contains(&payload.code)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Retries `call` with exponential backoff until it succeeds or `retries` is exhausted.
+/// Only transient errors are retried, any other error is returned right away.
+pub async fn retry_rpc<T, E, F, Fut>(
+    description: &str,
+    retries: u32,
+    backoff: Backoff,
+    call: F,
+) -> Result<T, String>
+where
+    E: TransientError,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut attempt = 0;
+    let mut delay = backoff.initial;
+
+    loop {
+        match call().await {
+            Ok(value) => return Ok(value),
+            Err(e) if e.is_transient() && attempt < retries => {
+                attempt += 1;
+                println!(
+                    "{} failed, retrying ({}/{}): {:?}",
+                    description, attempt, retries, e
+                );
+                tokio::time::sleep(delay).await;
+                delay = backoff.next(delay);
+            }
+            Err(e) if e.is_transient() => {
+                return Err(format!(
+                    "{} failed after {} retries: {:?}",
+                    description, retries, e
+                ))
+            }
+            Err(e) => return Err(format!("{} failed: {:?}", description, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use alloy::transports::TransportErrorKind;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct FakeError {
+        transient: bool,
+    }
+
+    impl TransientError for FakeError {
+        fn is_transient(&self) -> bool {
+            self.transient
+        }
+    }
+
+    fn backoff() -> Backoff {
+        Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(2),
+            multiplier: 2,
+        }
+    }
+
+    /// Fails with a `FakeError` on the first `failures` calls, counting every call.
+    async fn flaky(calls: &Cell<u32>, failures: u32, transient: bool) -> Result<u32, FakeError> {
+        calls.set(calls.get() + 1);
+        if calls.get() <= failures {
+            Err(FakeError { transient })
+        } else {
+            Ok(calls.get())
+        }
+    }
+
+    #[test]
+    fn test_classify_errors() {
+        assert!(FuelsError::Provider("connection reset".to_string()).is_transient());
+        assert!(!FuelsError::Other("decoding failed".to_string()).is_transient());
+
+        assert!(
+            SdkError::Fuels(SdkFuelsError::Provider("connection reset".to_string())).is_transient()
+        );
+        assert!(
+            !SdkError::Fuels(SdkFuelsError::Other("decoding failed".to_string())).is_transient()
+        );
+        assert!(!SdkError::AddressBook("missing mailbox".to_string()).is_transient());
+
+        assert!(
+            EvmContractError::TransportError(TransportErrorKind::custom_str("connection reset"))
+                .is_transient()
+        );
+        assert!(!EvmContractError::UnknownFunction("transfer".to_string()).is_transient());
+    }
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let backoff = Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(300),
+            multiplier: 2,
+        };
+
+        assert_eq!(backoff.next(backoff.initial), Duration::from_millis(200));
+        assert_eq!(
+            backoff.next(Duration::from_millis(200)),
+            Duration::from_millis(300)
+        );
+        assert_eq!(
+            backoff.next(Duration::from_millis(300)),
+            Duration::from_millis(300)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_until_success() {
+        let calls = Cell::new(0);
+
+        let result = retry_rpc("call", 3, backoff(), || flaky(&calls, 2, true)).await;
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_retries() {
+        let calls = Cell::new(0);
+
+        let result = retry_rpc("call", 2, backoff(), || flaky(&calls, 5, true)).await;
+
+        assert_eq!(
+            result,
+            Err("call failed after 2 retries: FakeError { transient: true }".to_string())
+        );
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_fails_fast_on_fatal_error() {
+        let calls = Cell::new(0);
+
+        let result = retry_rpc("call", 3, backoff(), || flaky(&calls, 1, false)).await;
+
+        assert_eq!(
+            result,
+            Err("call failed: FakeError { transient: false }".to_string())
+        );
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -0,0 +1,43 @@
+use std::{env, time::Duration};
+
+pub struct IndexerConfig {
+    pub node_url: String,
+    pub db_path: String,
+    /// `contract_addresses.yaml` written by the `deploy` script.
+    pub address_book_path: String,
+    /// Address book names of the warp routes to index, e.g. `warpRouteNative`.
+    pub warp_routes: Vec<String>,
+    pub poll_interval: Duration,
+}
+
+impl IndexerConfig {
+    pub fn from_env() -> Self {
+        let node_url = env::var("LOCAL_NODE_URL").unwrap_or_else(|_| "127.0.0.1:4000".to_owned());
+        let db_path = env::var("INDEXER_DB_PATH").unwrap_or_else(|_| "./indexer.sqlite".to_owned());
+        let address_book_path = env::var("INDEXER_ADDRESS_BOOK")
+            .unwrap_or_else(|_| "./deployments/local/contract_addresses.yaml".to_owned());
+        let warp_routes = env::var("INDEXER_WARP_ROUTES")
+            .map(|names| {
+                names
+                    .split(',')
+                    .map(|name| name.trim().to_owned())
+                    .filter(|name| !name.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let poll_interval_ms = env::var("INDEXER_POLL_INTERVAL_MS")
+            .map(|ms| {
+                ms.parse()
+                    .unwrap_or_else(|_| panic!("Invalid INDEXER_POLL_INTERVAL_MS: {}", ms))
+            })
+            .unwrap_or(1000);
+
+        Self {
+            node_url,
+            db_path,
+            address_book_path,
+            warp_routes,
+            poll_interval: Duration::from_millis(poll_interval_ms),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Indexes the Fuel Mailbox dispatches and processes, IGP payments, merkle tree insertions
+//! and warp route transfers into a SQLite database, and queries them.
+//!
+//! Usage:
+//!   indexer index [--from <block>] [--once]
+//!   indexer message <message id or transaction id>
+//!   indexer messages [--sender <b256>] [--recipient <b256>] [--domain <u32>]
+//!                    [--since <unix time>] [--until <unix time>] [--limit <n>]
+//!
+//! `index` resumes from the last indexed block, `--from` deletes everything indexed
+//! from that block onwards and reindexes it. The contracts are read from the address book
+//! written by the `deploy` script, e.g. against the node of `infra/fuel-node`:
+//!   cargo run -p fuel-node
+//!   cargo run -p deploy -- LOCAL
+//!   cargo run -p indexer -- index --once
+//!
+//! The tests index a topology deployed to a launched local node, so the contracts
+//! have to be built with `forc build` before `cargo test -p indexer`.
+
+mod config;
+mod store;
+
+use std::{env, process};
+
+use config::IndexerConfig;
+use fuel_hyperlane_sdk::{AddressBook, EventCursor, EventSubscription};
+use fuels::prelude::Provider;
+use store::{MessageFilter, MessageRow, Store, DISPATCHED};
+
+const DEFAULT_LIMIT: u32 = 50;
+
+const USAGE: &str = "Usage:
+  indexer index [--from <block>] [--once]
+  indexer message <message id or transaction id>
+  indexer messages [--sender <b256>] [--recipient <b256>] [--domain <u32>] [--since <unix time>] [--until <unix time>] [--limit <n>]";
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    let config = IndexerConfig::from_env();
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let mut store = Store::open(&config.db_path).unwrap_or_else(|e| {
+        eprintln!("Failed to open {}: {}", config.db_path, e);
+        process::exit(1);
+    });
+
+    match args.first().map(String::as_str) {
+        Some("index") => {
+            let from = flag(&args, "--from").map(|block| parse(&block, "--from"));
+            let once = args.iter().any(|arg| arg == "--once");
+            index(&config, &mut store, from, once).await;
+        }
+        Some("message") => {
+            let Some(id) = args.get(1) else {
+                exit_with_usage();
+            };
+            show_message(&store, id);
+        }
+        Some("messages") => {
+            let filter = MessageFilter {
+                sender: flag(&args, "--sender"),
+                recipient: flag(&args, "--recipient"),
+                domain: flag(&args, "--domain").map(|domain| parse(&domain, "--domain")),
+                since: flag(&args, "--since").map(|since| parse(&since, "--since")),
+                until: flag(&args, "--until").map(|until| parse(&until, "--until")),
+                limit: flag(&args, "--limit")
+                    .map(|limit| parse(&limit, "--limit"))
+                    .unwrap_or(DEFAULT_LIMIT),
+            };
+            let rows = store.messages(&filter).unwrap();
+            for row in rows.iter() {
+                print_message(row);
+            }
+            println!("{} message(s)", rows.len());
+        }
+        _ => exit_with_usage(),
+    }
+}
+
+async fn index(config: &IndexerConfig, store: &mut Store, from: Option<u32>, once: bool) {
+    let provider = Provider::connect(&config.node_url).await.unwrap();
+    let book = AddressBook::from_yaml_file(&config.address_book_path).unwrap();
+    let warp_routes: Vec<&str> = config.warp_routes.iter().map(String::as_str).collect();
+
+    let cursor = match from {
+        Some(block) => {
+            store.reset_to(block).unwrap();
+            EventCursor::from_block(block)
+        }
+        None => store
+            .cursor()
+            .unwrap()
+            .unwrap_or(EventCursor::from_block(0)),
+    };
+    println!(
+        "Indexing {} from block {}",
+        config.node_url, cursor.next_block
+    );
+
+    let mut subscription =
+        EventSubscription::from_address_book(provider, cursor, &book, &warp_routes).unwrap();
+
+    loop {
+        catch_up(&mut subscription, store).await;
+        if once {
+            return;
+        }
+        tokio::time::sleep(config.poll_interval).await;
+    }
+}
+
+/// Polls and stores the events of the subscription until it reaches the latest block.
+async fn catch_up(subscription: &mut EventSubscription, store: &mut Store) {
+    loop {
+        let before = subscription.cursor();
+        let events = subscription.poll().await.unwrap();
+        let after = subscription.cursor();
+        store.apply(&events, after).unwrap();
+
+        if after == before {
+            return;
+        }
+        println!(
+            "Indexed blocks {} to {}, {} event(s)",
+            before.next_block,
+            after.next_block - 1,
+            events.len()
+        );
+    }
+}
+
+fn show_message(store: &Store, id: &str) {
+    let mut rows = store.message(id).unwrap();
+    if rows.is_empty() {
+        rows = store.messages_by_tx(id).unwrap();
+    }
+    if rows.is_empty() {
+        println!("No message or transaction {} indexed", id);
+        return;
+    }
+
+    for row in rows.iter() {
+        print_message(row);
+
+        if row.kind == DISPATCHED {
+            match store.merkle_index(&row.message_id).unwrap() {
+                Some(index) => println!("  merkle tree index: {}", index),
+                None => println!("  merkle tree index: not inserted"),
+            }
+            for payment in store.gas_payments(&row.message_id).unwrap() {
+                println!(
+                    "  gas payment: {} for {} gas to {} (block {}, tx {})",
+                    payment.payment,
+                    payment.gas_amount,
+                    payment.destination,
+                    payment.block_height,
+                    payment.tx_id
+                );
+            }
+        }
+        for transfer in store.warp_transfers(&row.tx_id).unwrap() {
+            println!(
+                "  warp route {} {} {} to {} on {}",
+                transfer.warp_route,
+                transfer.kind,
+                transfer.amount,
+                transfer.recipient,
+                transfer.domain
+            );
+        }
+    }
+}
+
+fn print_message(row: &MessageRow) {
+    let nonce = row.nonce.map(|n| n.to_string()).unwrap_or("-".to_owned());
+    let destination = row
+        .destination
+        .map(|d| d.to_string())
+        .unwrap_or("local".to_owned());
+    let timestamp = row
+        .timestamp
+        .map(|t| t.to_string())
+        .unwrap_or("-".to_owned());
+
+    println!(
+        "{} {} nonce: {} {} -> {} sender: {} recipient: {} block: {} time: {} tx: {}",
+        row.kind,
+        row.message_id,
+        nonce,
+        row.origin,
+        destination,
+        row.sender,
+        row.recipient,
+        row.block_height,
+        timestamp,
+        row.tx_id
+    );
+}
+
+/// The value following `name` in the arguments.
+fn flag(args: &[String], name: &str) -> Option<String> {
+    args.iter().position(|arg| arg == name).map(|i| {
+        args.get(i + 1)
+            .cloned()
+            .unwrap_or_else(|| exit_with_usage())
+    })
+}
+
+fn parse<T: std::str::FromStr>(value: &str, name: &str) -> T {
+    value.parse().unwrap_or_else(|_| {
+        eprintln!("Invalid {}: {}", name, value);
+        process::exit(1);
+    })
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_hyperlane_sdk::events::ContractKind;
+    use fuels::{accounts::ViewOnlyAccount, types::ContractId};
+    use test_utils::fixtures::{HookConfig, Topology, TopologyConfig};
+
+    use super::*;
+    use crate::store::PROCESSED;
+
+    #[tokio::test]
+    async fn test_index_local_node() {
+        let topology =
+            Topology::launch(TopologyConfig::default().with_required_hook(HookConfig::Igp)).await;
+        let provider = topology.wallet.provider().unwrap().clone();
+
+        let dispatched = topology.dispatch(b"outbound".to_vec()).await.unwrap();
+        let inbound = topology.inbound_message(
+            ContractId::from(topology.recipient.contract_id()),
+            b"inbound".to_vec(),
+        );
+        topology.process(&inbound).await.unwrap();
+
+        let mut subscription = EventSubscription::new(provider, EventCursor::from_block(0))
+            .watch(topology.mailbox.contract_id().into(), ContractKind::Mailbox)
+            .watch(
+                topology.merkle_tree_hook.contract_id().into(),
+                ContractKind::MerkleTreeHook,
+            )
+            .watch(topology.igp.contract_id().into(), ContractKind::Igp);
+        let mut store = Store::open(":memory:").unwrap();
+
+        catch_up(&mut subscription, &mut store).await;
+        assert_eq!(store.cursor().unwrap(), Some(subscription.cursor()));
+
+        let dispatched_id = format!("0x{}", hex::encode(dispatched.0));
+        let rows = store.message(&dispatched_id).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].kind, DISPATCHED);
+        assert_eq!(
+            rows[0].body,
+            Some(format!("0x{}", hex::encode(b"outbound")))
+        );
+        assert_eq!(store.merkle_index(&dispatched_id).unwrap(), Some(0));
+        assert_eq!(store.gas_payments(&dispatched_id).unwrap().len(), 1);
+
+        let processed_id = format!("0x{}", hex::encode(inbound.id()));
+        let rows = store.message(&processed_id).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].kind, PROCESSED);
+        assert_eq!(rows[0].origin, inbound.origin);
+
+        // Resuming from the stored cursor does not index the gas payment twice
+        let mut resumed = EventSubscription::new(
+            topology.wallet.provider().unwrap().clone(),
+            store.cursor().unwrap().unwrap(),
+        )
+        .watch(topology.igp.contract_id().into(), ContractKind::Igp);
+        catch_up(&mut resumed, &mut store).await;
+        assert_eq!(store.gas_payments(&dispatched_id).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,719 @@
+use fuel_hyperlane_sdk::{
+    events::{IgpEvent, MailboxEvent, MerkleTreeHookEvent, WarpRouteEvent},
+    ContractEvent, EventCursor, HyperlaneEvent,
+};
+use fuels::types::Bits256;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS cursor (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    next_block INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS messages (
+    message_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    nonce INTEGER,
+    origin INTEGER NOT NULL,
+    sender TEXT NOT NULL,
+    destination INTEGER,
+    recipient TEXT NOT NULL,
+    body TEXT,
+    block_height INTEGER NOT NULL,
+    timestamp INTEGER,
+    tx_id TEXT NOT NULL,
+    PRIMARY KEY (message_id, kind)
+);
+CREATE INDEX IF NOT EXISTS messages_sender ON messages (sender);
+CREATE INDEX IF NOT EXISTS messages_recipient ON messages (recipient);
+CREATE INDEX IF NOT EXISTS messages_timestamp ON messages (timestamp);
+CREATE TABLE IF NOT EXISTS gas_payments (
+    message_id TEXT NOT NULL,
+    destination INTEGER NOT NULL,
+    gas_amount INTEGER NOT NULL,
+    payment INTEGER NOT NULL,
+    block_height INTEGER NOT NULL,
+    tx_id TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS gas_payments_message_id ON gas_payments (message_id);
+CREATE TABLE IF NOT EXISTS merkle_insertions (
+    message_id TEXT NOT NULL,
+    leaf_index INTEGER NOT NULL,
+    block_height INTEGER NOT NULL,
+    tx_id TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS merkle_insertions_message_id ON merkle_insertions (message_id);
+CREATE TABLE IF NOT EXISTS warp_transfers (
+    warp_route TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    domain INTEGER NOT NULL,
+    recipient TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    block_height INTEGER NOT NULL,
+    tx_id TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS warp_transfers_tx_id ON warp_transfers (tx_id);
+";
+
+/// Tables holding rows with a `block_height`, cleared when reindexing.
+const INDEXED_TABLES: [&str; 4] = [
+    "messages",
+    "gas_payments",
+    "merkle_insertions",
+    "warp_transfers",
+];
+
+/// `kind` of a message dispatched by the indexed Mailbox.
+pub const DISPATCHED: &str = "dispatched";
+/// `kind` of a message processed by the indexed Mailbox.
+pub const PROCESSED: &str = "processed";
+
+#[derive(Debug, Clone)]
+pub struct MessageRow {
+    pub message_id: String,
+    pub kind: String,
+    /// Only known for dispatched messages.
+    pub nonce: Option<u32>,
+    pub origin: u32,
+    pub sender: String,
+    /// Only known for dispatched messages, processed messages are destined to the local domain.
+    pub destination: Option<u32>,
+    pub recipient: String,
+    pub body: Option<String>,
+    pub block_height: u32,
+    pub timestamp: Option<i64>,
+    pub tx_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct GasPaymentRow {
+    pub destination: u32,
+    pub gas_amount: u64,
+    pub payment: u64,
+    pub block_height: u32,
+    pub tx_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WarpTransferRow {
+    pub warp_route: String,
+    pub kind: String,
+    pub domain: u32,
+    pub recipient: String,
+    /// Decimal amount, sent amounts are in the decimals of the destination router
+    /// and can exceed a `u64`.
+    pub amount: String,
+    pub block_height: u32,
+    pub tx_id: String,
+}
+
+/// Filters of `Store::messages`, all of them have to match.
+#[derive(Debug, Clone, Default)]
+pub struct MessageFilter {
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    /// Matches the origin or the destination.
+    pub domain: Option<u32>,
+    /// Inclusive bounds on the block timestamp.
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub limit: u32,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &str) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// The cursor to resume indexing from, `None` if nothing was indexed yet.
+    pub fn cursor(&self) -> rusqlite::Result<Option<EventCursor>> {
+        self.conn
+            .query_row("SELECT next_block FROM cursor WHERE id = 0", [], |row| {
+                row.get(0)
+            })
+            .optional()
+            .map(|next_block| next_block.map(EventCursor::from_block))
+    }
+
+    /// Deletes everything indexed from `block` onwards and moves the cursor back to it.
+    pub fn reset_to(&mut self, block: u32) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        for table in INDEXED_TABLES {
+            tx.execute(
+                &format!("DELETE FROM {} WHERE block_height >= ?1", table),
+                [block],
+            )?;
+        }
+        set_cursor(&tx, EventCursor::from_block(block))?;
+        tx.commit()
+    }
+
+    /// Stores the events of a poll and the cursor after it atomically,
+    /// so a crash never leaves events without the matching cursor.
+    pub fn apply(&mut self, events: &[ContractEvent], cursor: EventCursor) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        // `ProcessEvent` is logged right before the `ProcessIdEvent` of the same message
+        let mut pending_process = None;
+
+        for event in events {
+            let tx_id = hex_bytes(event.tx_id.as_ref());
+            match &event.event {
+                HyperlaneEvent::Mailbox(MailboxEvent::Dispatch(_)) => {
+                    let message = event.dispatched_message().unwrap();
+                    tx.execute(
+                        "INSERT OR REPLACE INTO messages VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                        params![
+                            hex_bytes(message.id().as_bytes()),
+                            DISPATCHED,
+                            message.nonce,
+                            message.origin,
+                            hex_bytes(message.sender.as_bytes()),
+                            message.destination,
+                            hex_bytes(message.recipient.as_bytes()),
+                            hex_bytes(&message.body),
+                            event.block_height,
+                            event.timestamp,
+                            tx_id,
+                        ],
+                    )?;
+                }
+                HyperlaneEvent::Mailbox(MailboxEvent::Process(process)) => {
+                    pending_process = Some(process.clone());
+                }
+                HyperlaneEvent::Mailbox(MailboxEvent::ProcessId(process_id)) => {
+                    let Some(process) = pending_process.take() else {
+                        continue;
+                    };
+                    tx.execute(
+                        "INSERT OR REPLACE INTO messages VALUES (?1, ?2, NULL, ?3, ?4, NULL, ?5, NULL, ?6, ?7, ?8)",
+                        params![
+                            hex_bits(&process_id.message_id),
+                            PROCESSED,
+                            process.origin,
+                            hex_bits(&process.sender),
+                            hex_bits(&process.recipient),
+                            event.block_height,
+                            event.timestamp,
+                            tx_id,
+                        ],
+                    )?;
+                }
+                HyperlaneEvent::Igp(IgpEvent::GasPayment(payment)) => {
+                    tx.execute(
+                        "INSERT INTO gas_payments VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                        params![
+                            hex_bits(&payment.message_id),
+                            payment.destination_domain,
+                            payment.gas_amount,
+                            payment.payment,
+                            event.block_height,
+                            tx_id,
+                        ],
+                    )?;
+                }
+                HyperlaneEvent::MerkleTreeHook(MerkleTreeHookEvent::InsertedIntoTree(insert)) => {
+                    tx.execute(
+                        "INSERT INTO merkle_insertions VALUES (?1, ?2, ?3, ?4)",
+                        params![
+                            hex_bits(&insert.message_id),
+                            insert.index,
+                            event.block_height,
+                            tx_id,
+                        ],
+                    )?;
+                }
+                HyperlaneEvent::WarpRoute(WarpRouteEvent::SentTransferRemote(sent)) => {
+                    tx.execute(
+                        "INSERT INTO warp_transfers VALUES (?1, 'sent', ?2, ?3, ?4, ?5, ?6)",
+                        params![
+                            hex_bytes(event.contract_id.as_ref()),
+                            sent.destination,
+                            hex_bits(&sent.recipient),
+                            sent.amount.to_string(),
+                            event.block_height,
+                            tx_id,
+                        ],
+                    )?;
+                }
+                HyperlaneEvent::WarpRoute(WarpRouteEvent::ReceivedTransferRemote(received)) => {
+                    tx.execute(
+                        "INSERT INTO warp_transfers VALUES (?1, 'received', ?2, ?3, ?4, ?5, ?6)",
+                        params![
+                            hex_bytes(event.contract_id.as_ref()),
+                            received.origin,
+                            hex_bits(&received.recipient),
+                            received.amount.to_string(),
+                            event.block_height,
+                            tx_id,
+                        ],
+                    )?;
+                }
+                _ => {}
+            }
+        }
+
+        set_cursor(&tx, cursor)?;
+        tx.commit()
+    }
+
+    /// The dispatched and processed rows of a message.
+    pub fn message(&self, message_id: &str) -> rusqlite::Result<Vec<MessageRow>> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT * FROM messages WHERE message_id = ?1 ORDER BY block_height")?;
+        let rows = statement.query_map([normalize_hex(message_id)], message_row)?;
+        rows.collect()
+    }
+
+    /// The messages dispatched or processed in a transaction.
+    pub fn messages_by_tx(&self, tx_id: &str) -> rusqlite::Result<Vec<MessageRow>> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT * FROM messages WHERE tx_id = ?1 ORDER BY block_height")?;
+        let rows = statement.query_map([normalize_hex(tx_id)], message_row)?;
+        rows.collect()
+    }
+
+    pub fn messages(&self, filter: &MessageFilter) -> rusqlite::Result<Vec<MessageRow>> {
+        let mut conditions = vec![];
+        let mut values: Vec<rusqlite::types::Value> = vec![];
+
+        if let Some(sender) = &filter.sender {
+            values.push(normalize_hex(sender).into());
+            conditions.push(format!("sender = ?{}", values.len()));
+        }
+        if let Some(recipient) = &filter.recipient {
+            values.push(normalize_hex(recipient).into());
+            conditions.push(format!("recipient = ?{}", values.len()));
+        }
+        if let Some(domain) = filter.domain {
+            values.push(domain.into());
+            conditions.push(format!(
+                "(origin = ?{0} OR destination = ?{0})",
+                values.len()
+            ));
+        }
+        if let Some(since) = filter.since {
+            values.push(since.into());
+            conditions.push(format!("timestamp >= ?{}", values.len()));
+        }
+        if let Some(until) = filter.until {
+            values.push(until.into());
+            conditions.push(format!("timestamp <= ?{}", values.len()));
+        }
+
+        let mut query = "SELECT * FROM messages".to_owned();
+        if !conditions.is_empty() {
+            query.push_str(" WHERE ");
+            query.push_str(&conditions.join(" AND "));
+        }
+        query.push_str(&format!(
+            " ORDER BY block_height DESC LIMIT {}",
+            filter.limit
+        ));
+
+        let mut statement = self.conn.prepare(&query)?;
+        let rows = statement.query_map(params_from_iter(values), message_row)?;
+        rows.collect()
+    }
+
+    pub fn gas_payments(&self, message_id: &str) -> rusqlite::Result<Vec<GasPaymentRow>> {
+        let mut statement = self.conn.prepare(
+            "SELECT destination, gas_amount, payment, block_height, tx_id FROM gas_payments WHERE message_id = ?1",
+        )?;
+        let rows = statement.query_map([normalize_hex(message_id)], |row| {
+            Ok(GasPaymentRow {
+                destination: row.get(0)?,
+                gas_amount: row.get(1)?,
+                payment: row.get(2)?,
+                block_height: row.get(3)?,
+                tx_id: row.get(4)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    pub fn merkle_index(&self, message_id: &str) -> rusqlite::Result<Option<u32>> {
+        self.conn
+            .query_row(
+                "SELECT leaf_index FROM merkle_insertions WHERE message_id = ?1",
+                [normalize_hex(message_id)],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /// The warp route transfers of a transaction, i.e. the transfer which
+    /// dispatched or processed a message in the same transaction.
+    pub fn warp_transfers(&self, tx_id: &str) -> rusqlite::Result<Vec<WarpTransferRow>> {
+        let mut statement = self.conn.prepare(
+            "SELECT warp_route, kind, domain, recipient, amount, block_height, tx_id FROM warp_transfers WHERE tx_id = ?1",
+        )?;
+        let rows = statement.query_map([normalize_hex(tx_id)], |row| {
+            Ok(WarpTransferRow {
+                warp_route: row.get(0)?,
+                kind: row.get(1)?,
+                domain: row.get(2)?,
+                recipient: row.get(3)?,
+                amount: row.get(4)?,
+                block_height: row.get(5)?,
+                tx_id: row.get(6)?,
+            })
+        })?;
+        rows.collect()
+    }
+}
+
+fn set_cursor(conn: &Connection, cursor: EventCursor) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO cursor (id, next_block) VALUES (0, ?1)
+         ON CONFLICT (id) DO UPDATE SET next_block = excluded.next_block",
+        [cursor.next_block],
+    )?;
+    Ok(())
+}
+
+fn message_row(row: &Row) -> rusqlite::Result<MessageRow> {
+    Ok(MessageRow {
+        message_id: row.get(0)?,
+        kind: row.get(1)?,
+        nonce: row.get(2)?,
+        origin: row.get(3)?,
+        sender: row.get(4)?,
+        destination: row.get(5)?,
+        recipient: row.get(6)?,
+        body: row.get(7)?,
+        block_height: row.get(8)?,
+        timestamp: row.get(9)?,
+        tx_id: row.get(10)?,
+    })
+}
+
+fn hex_bytes(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn hex_bits(bits: &Bits256) -> String {
+    hex_bytes(&bits.0)
+}
+
+/// Ids are stored as lowercase `0x` prefixed hex.
+pub fn normalize_hex(value: &str) -> String {
+    let value = value.trim().to_lowercase();
+    if value.starts_with("0x") {
+        value
+    } else {
+        format!("0x{}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_hyperlane_sdk::abis::{
+        DispatchEvent, EncodedMessage, GasPaymentEvent, InsertedIntoTreeEvent, ProcessEvent,
+        ProcessIdEvent, ReceivedTransferRemoteEvent, SentTransferRemoteAmountEvent,
+    };
+    use fuels::types::{Bytes, Bytes32, ContractId, U256};
+    use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage, H256};
+
+    use super::*;
+
+    const MAILBOX: ContractId = ContractId::new([1; 32]);
+    const WARP_ROUTE: ContractId = ContractId::new([2; 32]);
+
+    fn store() -> Store {
+        Store::open(":memory:").unwrap()
+    }
+
+    fn contract_event(
+        contract_id: ContractId,
+        block_height: u32,
+        tx: u8,
+        event: HyperlaneEvent,
+    ) -> ContractEvent {
+        ContractEvent {
+            contract_id,
+            block_height,
+            timestamp: Some(1_700_000_000 + block_height as i64),
+            tx_id: Bytes32::new([tx; 32]),
+            receipt_index: 0,
+            event,
+        }
+    }
+
+    fn message(nonce: u32) -> HyperlaneMessage {
+        HyperlaneMessage {
+            version: 3,
+            nonce,
+            origin: 0x6675656c,
+            sender: H256::repeat_byte(0xaa),
+            destination: 11155111,
+            recipient: H256::repeat_byte(0xbb),
+            body: b"hello".to_vec(),
+        }
+    }
+
+    fn dispatch(message: &HyperlaneMessage, block_height: u32, tx: u8) -> ContractEvent {
+        contract_event(
+            MAILBOX,
+            block_height,
+            tx,
+            HyperlaneEvent::Mailbox(MailboxEvent::Dispatch(DispatchEvent {
+                sender: Bits256(message.sender.0),
+                destination_domain: message.destination,
+                recipient_address: Bits256(message.recipient.0),
+                message: EncodedMessage {
+                    bytes: Bytes(RawHyperlaneMessage::from(message)),
+                },
+            })),
+        )
+    }
+
+    fn id_of(message: &HyperlaneMessage) -> String {
+        hex_bytes(message.id().as_bytes())
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let mut store = store();
+        assert_eq!(store.cursor().unwrap(), None);
+
+        store.apply(&[], EventCursor::from_block(10)).unwrap();
+        assert_eq!(store.cursor().unwrap(), Some(EventCursor::from_block(10)));
+
+        store.apply(&[], EventCursor::from_block(12)).unwrap();
+        assert_eq!(store.cursor().unwrap(), Some(EventCursor::from_block(12)));
+    }
+
+    #[test]
+    fn test_dispatched_message_round_trip() {
+        let mut store = store();
+        let message = message(7);
+        let message_id = Bits256(message.id().0);
+
+        let events = [
+            dispatch(&message, 5, 1),
+            contract_event(
+                MAILBOX,
+                5,
+                1,
+                HyperlaneEvent::MerkleTreeHook(MerkleTreeHookEvent::InsertedIntoTree(
+                    InsertedIntoTreeEvent {
+                        message_id,
+                        index: 3,
+                    },
+                )),
+            ),
+            contract_event(
+                MAILBOX,
+                5,
+                1,
+                HyperlaneEvent::Igp(IgpEvent::GasPayment(GasPaymentEvent {
+                    message_id,
+                    destination_domain: 11155111,
+                    gas_amount: 100_000,
+                    payment: 42,
+                })),
+            ),
+        ];
+        store.apply(&events, EventCursor::from_block(6)).unwrap();
+
+        let rows = store.message(&id_of(&message)).unwrap();
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(row.kind, DISPATCHED);
+        assert_eq!(row.nonce, Some(7));
+        assert_eq!(row.origin, message.origin);
+        assert_eq!(row.sender, hex_bytes(message.sender.as_bytes()));
+        assert_eq!(row.destination, Some(11155111));
+        assert_eq!(row.recipient, hex_bytes(message.recipient.as_bytes()));
+        assert_eq!(row.body, Some(hex_bytes(b"hello")));
+        assert_eq!(row.block_height, 5);
+        assert_eq!(row.timestamp, Some(1_700_000_005));
+        assert_eq!(row.tx_id, hex_bytes(&[1; 32]));
+
+        assert_eq!(store.merkle_index(&id_of(&message)).unwrap(), Some(3));
+
+        let payments = store.gas_payments(&id_of(&message)).unwrap();
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].destination, 11155111);
+        assert_eq!(payments[0].gas_amount, 100_000);
+        assert_eq!(payments[0].payment, 42);
+
+        // Ids are matched regardless of case and prefix
+        let unprefixed = id_of(&message)[2..].to_uppercase();
+        assert_eq!(store.message(&unprefixed).unwrap().len(), 1);
+        assert_eq!(store.messages_by_tx(&hex_bytes(&[1; 32])).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_processed_message_round_trip() {
+        let mut store = store();
+        let message_id = Bits256([0x33; 32]);
+
+        let events = [
+            contract_event(
+                MAILBOX,
+                8,
+                2,
+                HyperlaneEvent::Mailbox(MailboxEvent::Process(ProcessEvent {
+                    origin: 11155111,
+                    sender: Bits256([0xcc; 32]),
+                    recipient: Bits256([0xdd; 32]),
+                })),
+            ),
+            contract_event(
+                MAILBOX,
+                8,
+                2,
+                HyperlaneEvent::Mailbox(MailboxEvent::ProcessId(ProcessIdEvent { message_id })),
+            ),
+        ];
+        store.apply(&events, EventCursor::from_block(9)).unwrap();
+
+        let rows = store.message(&hex_bits(&message_id)).unwrap();
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(row.kind, PROCESSED);
+        assert_eq!(row.nonce, None);
+        assert_eq!(row.origin, 11155111);
+        assert_eq!(row.sender, hex_bits(&Bits256([0xcc; 32])));
+        assert_eq!(row.destination, None);
+        assert_eq!(row.recipient, hex_bits(&Bits256([0xdd; 32])));
+        assert_eq!(row.body, None);
+    }
+
+    #[test]
+    fn test_warp_transfers_round_trip() {
+        let mut store = store();
+        let recipient = Bits256([0xee; 32]);
+        // Larger than a u64, in the decimals of the destination router
+        let sent_amount = U256::from(10u64).pow(U256::from(20u64));
+
+        let events = [
+            contract_event(
+                WARP_ROUTE,
+                3,
+                4,
+                HyperlaneEvent::WarpRoute(WarpRouteEvent::SentTransferRemote(
+                    SentTransferRemoteAmountEvent {
+                        destination: 11155111,
+                        recipient,
+                        amount: sent_amount,
+                    },
+                )),
+            ),
+            contract_event(
+                WARP_ROUTE,
+                4,
+                5,
+                HyperlaneEvent::WarpRoute(WarpRouteEvent::ReceivedTransferRemote(
+                    ReceivedTransferRemoteEvent {
+                        origin: 11155111,
+                        recipient,
+                        amount: 500,
+                    },
+                )),
+            ),
+        ];
+        store.apply(&events, EventCursor::from_block(5)).unwrap();
+
+        let sent = store.warp_transfers(&hex_bytes(&[4; 32])).unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].warp_route, hex_bytes(WARP_ROUTE.as_ref()));
+        assert_eq!(sent[0].kind, "sent");
+        assert_eq!(sent[0].domain, 11155111);
+        assert_eq!(sent[0].recipient, hex_bits(&recipient));
+        assert_eq!(sent[0].amount, "100000000000000000000");
+
+        let received = store.warp_transfers(&hex_bytes(&[5; 32])).unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].kind, "received");
+        assert_eq!(received[0].amount, "500");
+    }
+
+    #[test]
+    fn test_messages_filters() {
+        let mut store = store();
+        let first = message(0);
+        let mut second = message(1);
+        second.sender = H256::repeat_byte(0x99);
+        second.destination = 97;
+
+        store
+            .apply(
+                &[dispatch(&first, 1, 1), dispatch(&second, 2, 2)],
+                EventCursor::from_block(3),
+            )
+            .unwrap();
+
+        let filter = MessageFilter {
+            limit: 10,
+            ..Default::default()
+        };
+        let all = store.messages(&filter).unwrap();
+        // Latest first
+        assert_eq!(
+            all.iter().map(|row| row.nonce).collect::<Vec<_>>(),
+            vec![Some(1), Some(0)]
+        );
+
+        let by_sender = store
+            .messages(&MessageFilter {
+                sender: Some(hex_bytes(second.sender.as_bytes())),
+                ..filter.clone()
+            })
+            .unwrap();
+        assert_eq!(by_sender.len(), 1);
+        assert_eq!(by_sender[0].message_id, id_of(&second));
+
+        let by_domain = store
+            .messages(&MessageFilter {
+                domain: Some(11155111),
+                ..filter.clone()
+            })
+            .unwrap();
+        assert_eq!(by_domain.len(), 1);
+        assert_eq!(by_domain[0].message_id, id_of(&first));
+
+        let by_time = store
+            .messages(&MessageFilter {
+                since: Some(1_700_000_002),
+                until: Some(1_700_000_002),
+                ..filter.clone()
+            })
+            .unwrap();
+        assert_eq!(by_time.len(), 1);
+        assert_eq!(by_time[0].message_id, id_of(&second));
+
+        let limited = store
+            .messages(&MessageFilter { limit: 1, ..filter })
+            .unwrap();
+        assert_eq!(limited.len(), 1);
+    }
+
+    #[test]
+    fn test_reset_to_deletes_later_blocks() {
+        let mut store = store();
+        let first = message(0);
+        let second = message(1);
+
+        store
+            .apply(
+                &[dispatch(&first, 1, 1), dispatch(&second, 2, 2)],
+                EventCursor::from_block(3),
+            )
+            .unwrap();
+        store.reset_to(2).unwrap();
+
+        assert_eq!(store.message(&id_of(&first)).unwrap().len(), 1);
+        assert!(store.message(&id_of(&second)).unwrap().is_empty());
+        assert_eq!(store.cursor().unwrap(), Some(EventCursor::from_block(2)));
+    }
+}
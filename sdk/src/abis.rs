@@ -1,3 +1,9 @@
+//! Bindings for the Hyperlane contracts and the `wr_multicall` script.
+//!
+//! The ABIs are read from the build output of the contracts, so they have to be built
+//! with `forc build`, and the script with `forc build -p scripts/wr_multicall`, first.
+//! The test contracts are included for the deploy script and the test fixtures.
+
 use fuels::macros::abigen;
 
 abigen!(
@@ -6,28 +12,32 @@ abigen!(
         abi = "contracts/mailbox/out/debug/mailbox-abi.json",
     ),
     Contract(
-        name = "PostDispatch",
-        abi = "contracts/mocks/mock-post-dispatch/out/debug/mock-post-dispatch-abi.json",
+        name = "InterchainGasPaymaster",
+        abi = "contracts/hooks/gas-paymaster/out/debug/gas-paymaster-abi.json",
+    ),
+    Contract(
+        name = "GasOracle",
+        abi = "contracts/gas-oracle/out/debug/gas-oracle-abi.json",
     ),
     Contract(
         name = "MerkleTreeHook",
         abi = "contracts/hooks/merkle-tree-hook/out/debug/merkle-tree-hook-abi.json",
     ),
     Contract(
-        name = "ValidatorAnnounce",
-        abi = "contracts/validator-announce/out/debug/validator-announce-abi.json",
+        name = "AggregationHook",
+        abi = "contracts/hooks/aggregation/out/debug/aggregation-abi.json",
     ),
     Contract(
-        name = "GasOracle",
-        abi = "contracts/gas-oracle/out/debug/gas-oracle-abi.json",
+        name = "FallbackDomainRoutingHook",
+        abi = "contracts/hooks/fallback-domain-routing-hook/out/debug/fallback-domain-routing-hook-abi.json",
     ),
     Contract(
-        name = "GasPaymaster",
-        abi = "contracts/hooks/gas-paymaster/out/debug/gas-paymaster-abi.json",
+        name = "PausableHook",
+        abi = "contracts/hooks/pausable-hook/out/debug/pausable-hook-abi.json",
     ),
     Contract(
-        name = "TestRecipient",
-        abi = "contracts/test/msg-recipient-test/out/debug/msg-recipient-test-abi.json",
+        name = "ProtocolFee",
+        abi = "contracts/hooks/protocol-fee/out/debug/protocol-fee-abi.json",
     ),
     Contract(
         name = "AggregationISM",
@@ -38,7 +48,7 @@ abigen!(
         abi = "contracts/ism/routing/domain-routing-ism/out/debug/domain-routing-ism-abi.json",
     ),
     Contract(
-        name = "FallbackDomainRoutingISM",
+        name = "DefaultFallbackDomainRoutingISM",
         abi = "contracts/ism/routing/default-fallback-domain-routing-ism/out/debug/default-fallback-domain-routing-ism-abi.json",
     ),
     Contract(
@@ -49,32 +59,48 @@ abigen!(
         name = "MerkleRootMultisigISM",
         abi = "contracts/ism/multisig/merkle-root-multisig-ism/out/debug/merkle-root-multisig-ism-abi.json",
     ),
+    Contract(
+        name = "PausableISM",
+        abi = "contracts/ism/pausable-ism/out/debug/pausable-ism-abi.json",
+    ),
     Contract(
         name = "WarpRoute",
         abi = "contracts/warp-route/out/debug/warp-route-abi.json",
     ),
     Contract(
-        name = "SRC20Test",
-        abi = "contracts/test/src20-test/out/debug/src20-test-abi.json",
+        name = "MultiAssetWarpRoute",
+        abi = "contracts/multi-asset-warp-route/out/debug/multi-asset-warp-route-abi.json",
     ),
     Contract(
-        name = "ProtocolFee",
-        abi = "contracts/hooks/protocol-fee/out/debug/protocol-fee-abi.json",
+        name = "ValidatorAnnounce",
+        abi = "contracts/validator-announce/out/debug/validator-announce-abi.json",
     ),
     Contract(
-        name = "AggregationHook",
-        abi = "contracts/hooks/aggregation/out/debug/aggregation-abi.json",
+        name = "TestRecipient",
+        abi = "contracts/test/msg-recipient-test/out/debug/msg-recipient-test-abi.json",
     ),
     Contract(
-      name = "PausableHook",
-      abi = "contracts/hooks/pausable-hook/out/debug/pausable-hook-abi.json",
+        name = "TestIsm",
+        abi = "contracts/test/ism-test/out/debug/ism-test-abi.json",
     ),
     Contract(
-        name = "PausableISM",
-        abi = "contracts/ism/pausable-ism/out/debug/pausable-ism-abi.json",
+        name = "PostDispatchMock",
+        abi = "contracts/mocks/mock-post-dispatch/out/debug/mock-post-dispatch-abi.json",
     ),
     Contract(
-        name = "FallbackDomainRoutingHook",
-        abi = "contracts/hooks/fallback-domain-routing-hook/out/debug/fallback-domain-routing-hook-abi.json",
-    )
+        name = "SRC20Test",
+        abi = "contracts/test/src20-test/out/debug/src20-test-abi.json",
+    ),
+    Script(
+        name = "WarpRouteTransfer",
+        abi = "scripts/wr_multicall/out/debug/wr_multicall-abi.json",
+    ),
 );
+
+// Events declared by several contracts are not re-exported by `abigen!` under their own name
+pub use abigen_bindings::interchain_gas_paymaster_mod::interfaces::hooks::igp::{
+    BeneficiarySetEvent as IgpBeneficiarySetEvent, ClaimEvent as IgpClaimEvent,
+};
+pub use abigen_bindings::warp_route_mod::interfaces::warp_route::{
+    BeneficiarySetEvent as WarpRouteBeneficiarySetEvent, ClaimEvent as WarpRouteClaimEvent,
+};
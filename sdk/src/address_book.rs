@@ -0,0 +1,217 @@
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
+
+use fuels::types::{AssetId, ContractId};
+use serde_json::Value as JsonValue;
+
+use crate::error::{SdkError, SdkResult};
+
+/// Contract addresses of a single Fuel deployment, keyed by the names used
+/// in the `deploy` dump, e.g. `mailbox`, `interchainGasPaymaster` or `warpRouteNative`.
+#[derive(Debug, Clone, Default)]
+pub struct AddressBook {
+    entries: HashMap<String, String>,
+}
+
+impl AddressBook {
+    pub fn new(entries: HashMap<String, String>) -> Self {
+        Self { entries }
+    }
+
+    /// Loads the `contract_addresses.yaml` written by the `deploy` script.
+    pub fn from_yaml_file(path: impl AsRef<Path>) -> SdkResult<Self> {
+        Self::from_yaml_str(&read(path.as_ref())?)
+    }
+
+    pub fn from_yaml_str(data: &str) -> SdkResult<Self> {
+        let entries: HashMap<String, String> = serde_yaml::from_str(data)
+            .map_err(|e| SdkError::AddressBook(format!("Invalid YAML: {}", e)))?;
+        Ok(Self { entries })
+    }
+
+    /// Loads the addresses of `chain` from a Hyperlane agent config,
+    /// e.g. `infra/configs/agent-config-local.json`.
+    /// Only string values holding a 32 byte hex address are kept.
+    pub fn from_agent_config_file(path: impl AsRef<Path>, chain: &str) -> SdkResult<Self> {
+        Self::from_agent_config_str(&read(path.as_ref())?, chain)
+    }
+
+    pub fn from_agent_config_str(data: &str, chain: &str) -> SdkResult<Self> {
+        let config: JsonValue = serde_json::from_str(data)
+            .map_err(|e| SdkError::AddressBook(format!("Invalid JSON: {}", e)))?;
+
+        let chain_config = config["chains"][chain]
+            .as_object()
+            .ok_or_else(|| SdkError::AddressBook(format!("Chain {} not found", chain)))?;
+
+        let entries = chain_config
+            .iter()
+            .filter_map(|(key, value)| {
+                value
+                    .as_str()
+                    .filter(|value| ContractId::from_str(value).is_ok())
+                    .map(|value| (key.clone(), value.to_owned()))
+            })
+            .collect();
+
+        Ok(Self { entries })
+    }
+
+    pub fn contract_id(&self, name: &str) -> SdkResult<ContractId> {
+        let value = self.raw(name)?;
+        ContractId::from_str(value)
+            .map_err(|e| SdkError::AddressBook(format!("Invalid address for {}: {}", name, e)))
+    }
+
+    pub fn asset_id(&self, name: &str) -> SdkResult<AssetId> {
+        let value = self.raw(name)?;
+        AssetId::from_str(value)
+            .map_err(|e| SdkError::AddressBook(format!("Invalid asset id for {}: {}", name, e)))
+    }
+
+    pub fn mailbox(&self) -> SdkResult<ContractId> {
+        self.contract_id("mailbox")
+    }
+
+    pub fn igp(&self) -> SdkResult<ContractId> {
+        self.contract_id("interchainGasPaymaster")
+    }
+
+    pub fn gas_oracle(&self) -> SdkResult<ContractId> {
+        self.contract_id("gasOracle")
+    }
+
+    pub fn merkle_tree_hook(&self) -> SdkResult<ContractId> {
+        self.contract_id("merkleTreeHook")
+    }
+
+    pub fn validator_announce(&self) -> SdkResult<ContractId> {
+        self.contract_id("validatorAnnounce")
+    }
+
+    fn raw(&self, name: &str) -> SdkResult<&str> {
+        self.entries
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| SdkError::AddressBook(format!("{} not found", name)))
+    }
+}
+
+fn read(path: &Path) -> SdkResult<String> {
+    fs::read_to_string(path)
+        .map_err(|e| SdkError::AddressBook(format!("Unable to read {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAILBOX: &str = "0x1e5ea93bba3b9a80a8e4b523398e8d8dc54d7bfcb33f8b14ea6318fff1957b56";
+    const IGP: &str = "0xeb12d82902da1abe20f5c51964d43a882328e5f682d8d97385cc15460e401eb9";
+    const WARP_ROUTE: &str = "0x56f42e6de67e92d128558a9163a5e6097f9bd926436eb7f87023f4afe9463aa5";
+
+    fn agent_config() -> String {
+        serde_json::json!({
+            "chains": {
+                "fueltest1": {
+                    "name": "fueltest1",
+                    "domainId": 13374,
+                    "protocol": "fuel",
+                    "mailbox": MAILBOX,
+                    "interchainGasPaymaster": IGP,
+                    "index": { "from": 100 },
+                },
+                "test1": {
+                    "mailbox": "0x8A791620dd6260079BF849Dc5567aDC3F2FdC318",
+                },
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_from_yaml_str() {
+        let book = AddressBook::from_yaml_str(&format!(
+            "mailbox: {}\ninterchainGasPaymaster: {}\nwarpRouteNative: {}\n",
+            MAILBOX, IGP, WARP_ROUTE
+        ))
+        .unwrap();
+
+        assert_eq!(
+            book.mailbox().unwrap(),
+            ContractId::from_str(MAILBOX).unwrap()
+        );
+        assert_eq!(book.igp().unwrap(), ContractId::from_str(IGP).unwrap());
+        assert_eq!(
+            book.contract_id("warpRouteNative").unwrap(),
+            ContractId::from_str(WARP_ROUTE).unwrap()
+        );
+        assert_eq!(
+            book.asset_id("warpRouteNative").unwrap(),
+            AssetId::from_str(WARP_ROUTE).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_yaml_str_invalid() {
+        let error = AddressBook::from_yaml_str("- mailbox").unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("Address book error: Invalid YAML"));
+    }
+
+    #[test]
+    fn test_missing_entry() {
+        let book = AddressBook::from_yaml_str(&format!("mailbox: {}\n", MAILBOX)).unwrap();
+
+        assert_eq!(
+            book.merkle_tree_hook().unwrap_err().to_string(),
+            "Address book error: merkleTreeHook not found"
+        );
+    }
+
+    #[test]
+    fn test_invalid_address() {
+        let book = AddressBook::from_yaml_str("mailbox: not-an-address\n").unwrap();
+
+        assert!(book
+            .mailbox()
+            .unwrap_err()
+            .to_string()
+            .starts_with("Address book error: Invalid address for mailbox"));
+    }
+
+    #[test]
+    fn test_from_agent_config_str() {
+        let book = AddressBook::from_agent_config_str(&agent_config(), "fueltest1").unwrap();
+
+        assert_eq!(
+            book.mailbox().unwrap(),
+            ContractId::from_str(MAILBOX).unwrap()
+        );
+        assert_eq!(book.igp().unwrap(), ContractId::from_str(IGP).unwrap());
+        // Values which are not 32 byte addresses are dropped
+        assert!(book.contract_id("name").is_err());
+        assert!(book.contract_id("protocol").is_err());
+    }
+
+    #[test]
+    fn test_from_agent_config_str_drops_evm_addresses() {
+        let book = AddressBook::from_agent_config_str(&agent_config(), "test1").unwrap();
+
+        assert_eq!(
+            book.mailbox().unwrap_err().to_string(),
+            "Address book error: mailbox not found"
+        );
+    }
+
+    #[test]
+    fn test_from_agent_config_str_unknown_chain() {
+        let error = AddressBook::from_agent_config_str(&agent_config(), "fueltest2").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Address book error: Chain fueltest2 not found"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use std::fmt;
+
+use fuels::{
+    core::{
+        codec::LogDecoder,
+        traits::{Parameterize, Tokenizable},
+    },
+    tx::Receipt,
+    types::errors::{transaction::Reason, Error},
+};
+
+use crate::abis::{IgpError, MailboxError, TokenRouterError, WarpRouteError};
+
+pub type SdkResult<T> = Result<T, SdkError>;
+
+/// An error enum logged by one of the Hyperlane contracts before reverting.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContractError {
+    Mailbox(MailboxError),
+    Igp(IgpError),
+    WarpRoute(WarpRouteError),
+    TokenRouter(TokenRouterError),
+}
+
+impl ContractError {
+    /// Decodes the contract error from the receipts of a reverted transaction.
+    /// Returns `None` if the revert did not log one of the known error enums,
+    /// e.g. when it originates from `sway_libs` such as `NotOwner` or `Paused`.
+    pub fn decode(receipts: &[Receipt], decoder: &LogDecoder) -> Option<Self> {
+        last_log::<MailboxError>(receipts, decoder)
+            .map(ContractError::Mailbox)
+            .or_else(|| last_log::<IgpError>(receipts, decoder).map(ContractError::Igp))
+            .or_else(|| last_log::<WarpRouteError>(receipts, decoder).map(ContractError::WarpRoute))
+            .or_else(|| {
+                last_log::<TokenRouterError>(receipts, decoder).map(ContractError::TokenRouter)
+            })
+    }
+}
+
+impl fmt::Display for ContractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContractError::Mailbox(e) => write!(f, "Mailbox: {:?}", e),
+            ContractError::Igp(e) => write!(f, "InterchainGasPaymaster: {:?}", e),
+            ContractError::WarpRoute(e) => write!(f, "WarpRoute: {:?}", e),
+            ContractError::TokenRouter(e) => write!(f, "TokenRouter: {:?}", e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SdkError {
+    /// A contract call reverted.
+    /// `error` holds the decoded contract error enum, if the revert logged one.
+    Revert {
+        reason: String,
+        error: Option<ContractError>,
+    },
+    /// Any other error returned by the `fuels` SDK, e.g. a provider or IO error.
+    Fuels(Error),
+    /// The address book could not be read or is missing an entry.
+    AddressBook(String),
+    /// A collateral or synthetic transfer was sent without the `wr_multicall` script binary.
+    TransferScriptNotSet,
+}
+
+impl SdkError {
+    /// Maps a failed call, decoding the contract error with the logs known to `decoder`.
+    pub(crate) fn from_call(error: Error, decoder: &LogDecoder) -> Self {
+        match error {
+            Error::Transaction(Reason::Reverted {
+                reason, receipts, ..
+            }) => SdkError::Revert {
+                error: ContractError::decode(&receipts, decoder),
+                reason,
+            },
+            error => SdkError::Fuels(error),
+        }
+    }
+
+    /// The revert reason as reported by the node, if the error is a revert.
+    pub fn revert_reason(&self) -> Option<&str> {
+        match self {
+            SdkError::Revert { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// The decoded contract error, if the error is a revert which logged one.
+    pub fn contract_error(&self) -> Option<&ContractError> {
+        match self {
+            SdkError::Revert { error, .. } => error.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SdkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SdkError::Revert {
+                error: Some(error), ..
+            } => write!(f, "Reverted with {}", error),
+            SdkError::Revert { reason, .. } => write!(f, "Reverted with {}", reason),
+            SdkError::Fuels(e) => write!(f, "{}", e),
+            SdkError::AddressBook(e) => write!(f, "Address book error: {}", e),
+            SdkError::TransferScriptNotSet => write!(f, "Transfer script path not set"),
+        }
+    }
+}
+
+impl std::error::Error for SdkError {}
+
+impl From<Error> for SdkError {
+    fn from(error: Error) -> Self {
+        SdkError::Fuels(error)
+    }
+}
+
+pub(crate) fn last_log<T: Tokenizable + Parameterize + 'static>(
+    receipts: &[Receipt],
+    decoder: &LogDecoder,
+) -> Option<T> {
+    decoder
+        .decode_logs_with_type::<T>(receipts)
+        .ok()
+        .and_then(|mut logs| logs.pop())
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::{
+        core::{
+            codec::{log_formatters_lookup, ABIEncoder, LogFormatter},
+            traits::Tokenizable,
+        },
+        types::{Bytes32, ContractId},
+    };
+
+    use super::*;
+
+    const CONTRACT_ID: ContractId = ContractId::new([1; 32]);
+    const MAILBOX_ERROR_LOG_ID: u64 = 1;
+    const WARP_ROUTE_ERROR_LOG_ID: u64 = 2;
+    const TOKEN_ROUTER_ERROR_LOG_ID: u64 = 3;
+
+    fn log_decoder() -> LogDecoder {
+        LogDecoder::new(log_formatters_lookup(
+            vec![
+                (
+                    MAILBOX_ERROR_LOG_ID.to_string(),
+                    LogFormatter::new::<MailboxError>(),
+                ),
+                (
+                    WARP_ROUTE_ERROR_LOG_ID.to_string(),
+                    LogFormatter::new::<WarpRouteError>(),
+                ),
+                (
+                    TOKEN_ROUTER_ERROR_LOG_ID.to_string(),
+                    LogFormatter::new::<TokenRouterError>(),
+                ),
+            ],
+            CONTRACT_ID,
+        ))
+    }
+
+    fn log_receipt(log_id: u64, value: impl Tokenizable) -> Receipt {
+        let data = ABIEncoder::default().encode(&[value.into_token()]).unwrap();
+        Receipt::LogData {
+            id: CONTRACT_ID,
+            ra: 0,
+            rb: log_id,
+            ptr: 0,
+            len: data.len() as u64,
+            digest: Bytes32::zeroed(),
+            pc: 0,
+            is: 0,
+            data: Some(data),
+        }
+    }
+
+    fn reverted(receipts: Vec<Receipt>) -> Error {
+        Error::Transaction(Reason::Reverted {
+            reason: "Revert(0)".to_string(),
+            revert_id: 0,
+            receipts,
+        })
+    }
+
+    #[test]
+    fn test_decode_mailbox_error() {
+        let receipts = vec![log_receipt(
+            MAILBOX_ERROR_LOG_ID,
+            MailboxError::HookNotAllowed(CONTRACT_ID),
+        )];
+
+        assert_eq!(
+            ContractError::decode(&receipts, &log_decoder()),
+            Some(ContractError::Mailbox(MailboxError::HookNotAllowed(
+                CONTRACT_ID
+            )))
+        );
+    }
+
+    #[test]
+    fn test_decode_warp_route_error() {
+        let receipts = vec![log_receipt(
+            WARP_ROUTE_ERROR_LOG_ID,
+            WarpRouteError::AmountRoundsToZero,
+        )];
+
+        assert_eq!(
+            ContractError::decode(&receipts, &log_decoder()),
+            Some(ContractError::WarpRoute(WarpRouteError::AmountRoundsToZero))
+        );
+    }
+
+    #[test]
+    fn test_decode_last_logged_error() {
+        let receipts = vec![
+            log_receipt(TOKEN_ROUTER_ERROR_LOG_ID, TokenRouterError::RouterNotSet),
+            log_receipt(
+                TOKEN_ROUTER_ERROR_LOG_ID,
+                TokenRouterError::RouterLengthMismatch,
+            ),
+        ];
+
+        assert_eq!(
+            ContractError::decode(&receipts, &log_decoder()),
+            Some(ContractError::TokenRouter(
+                TokenRouterError::RouterLengthMismatch
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_log() {
+        let receipts = vec![log_receipt(42, 7u64)];
+
+        assert_eq!(ContractError::decode(&receipts, &log_decoder()), None);
+        assert_eq!(ContractError::decode(&[], &log_decoder()), None);
+    }
+
+    #[test]
+    fn test_from_call_revert() {
+        let receipts = vec![log_receipt(
+            MAILBOX_ERROR_LOG_ID,
+            MailboxError::MessageAlreadyDelivered,
+        )];
+
+        let error = SdkError::from_call(reverted(receipts), &log_decoder());
+
+        assert_eq!(error.revert_reason(), Some("Revert(0)"));
+        assert_eq!(
+            error.contract_error(),
+            Some(&ContractError::Mailbox(
+                MailboxError::MessageAlreadyDelivered
+            ))
+        );
+        assert_eq!(
+            error.to_string(),
+            "Reverted with Mailbox: MessageAlreadyDelivered"
+        );
+    }
+
+    #[test]
+    fn test_from_call_revert_without_contract_error() {
+        let error = SdkError::from_call(reverted(vec![]), &log_decoder());
+
+        assert_eq!(error.contract_error(), None);
+        assert_eq!(error.to_string(), "Reverted with Revert(0)");
+    }
+
+    #[test]
+    fn test_from_call_other_error() {
+        let error = SdkError::from_call(Error::Other("connection refused".into()), &log_decoder());
+
+        assert!(matches!(error, SdkError::Fuels(_)));
+        assert_eq!(error.revert_reason(), None);
+    }
+}
@@ -0,0 +1,719 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use fuels::{
+    accounts::wallet::WalletUnlocked,
+    core::codec::LogDecoder,
+    prelude::Provider,
+    tx::Receipt,
+    types::{tx_status::TxStatus, Bits256, Bytes32, ContractId},
+};
+use futures::Stream;
+use hyperlane_core::HyperlaneMessage;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abis::*,
+    address_book::AddressBook,
+    error::{last_log, SdkResult},
+};
+
+/// Default number of blocks fetched by a single `poll`.
+pub const DEFAULT_MAX_BLOCKS_PER_POLL: u32 = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MailboxEvent {
+    Dispatch(DispatchEvent),
+    DispatchId(DispatchIdEvent),
+    Process(ProcessEvent),
+    ProcessId(ProcessIdEvent),
+    DefaultIsmSet(DefaultIsmSetEvent),
+    DefaultHookSet(DefaultHookSetEvent),
+    RequiredHookSet(RequiredHookSetEvent),
+    HookAllowlistEnabledSet(HookAllowlistEnabledSetEvent),
+    HookAllowedSet(HookAllowedSetEvent),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MerkleTreeHookEvent {
+    InsertedIntoTree(InsertedIntoTreeEvent),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IgpEvent {
+    GasPayment(GasPaymentEvent),
+    GasOracleSet(GasOracleSetEvent),
+    DestinationGasConfigSet(DestinationGasConfigSetEvent),
+    BeneficiarySet(IgpBeneficiarySetEvent),
+    Claim(IgpClaimEvent),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WarpRouteEvent {
+    /// Decoded from the `u256` event, the `u64` event is not logged for larger amounts.
+    SentTransferRemote(SentTransferRemoteAmountEvent),
+    ReceivedTransferRemote(ReceivedTransferRemoteEvent),
+    BeneficiarySet(WarpRouteBeneficiarySetEvent),
+    Claim(WarpRouteClaimEvent),
+    PayloadDropped(PayloadDroppedEvent),
+    ContractRecipientSet(ContractRecipientSetEvent),
+    FeeSet(FeeSetEvent),
+    TransferFeeRetained(TransferFeeRetainedEvent),
+    BalanceReconciled(BalanceReconciledEvent),
+    OriginTokenSet(OriginTokenSetEvent),
+    Deposit(DepositEvent),
+    DepositRefunded(DepositRefundedEvent),
+    RateLimitSet(RateLimitSetEvent),
+    RateLimitReached(RateLimitReachedEvent),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidatorAnnounceEvent {
+    ValidatorAnnouncement(ValidatorAnnouncementEvent),
+}
+
+/// A decoded log of one of the watched contracts.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HyperlaneEvent {
+    Mailbox(MailboxEvent),
+    MerkleTreeHook(MerkleTreeHookEvent),
+    Igp(IgpEvent),
+    WarpRoute(WarpRouteEvent),
+    ValidatorAnnounce(ValidatorAnnounceEvent),
+}
+
+/// The contract a watched ID is decoded as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractKind {
+    Mailbox,
+    MerkleTreeHook,
+    Igp,
+    WarpRoute,
+    ValidatorAnnounce,
+}
+
+/// An event and where it was emitted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractEvent {
+    pub contract_id: ContractId,
+    pub block_height: u32,
+    /// Unix timestamp of the block, in seconds.
+    pub timestamp: Option<i64>,
+    pub tx_id: Bytes32,
+    /// Index of the log receipt in the transaction, orders the events of a transaction.
+    pub receipt_index: usize,
+    pub event: HyperlaneEvent,
+}
+
+impl ContractEvent {
+    /// The message of a `DispatchEvent`.
+    pub fn dispatched_message(&self) -> Option<HyperlaneMessage> {
+        match &self.event {
+            HyperlaneEvent::Mailbox(MailboxEvent::Dispatch(event)) => {
+                Some(HyperlaneMessage::from(event.message.bytes.0.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// The message ID of a `ProcessIdEvent`.
+    pub fn processed_message_id(&self) -> Option<Bits256> {
+        match &self.event {
+            HyperlaneEvent::Mailbox(MailboxEvent::ProcessId(event)) => Some(event.message_id),
+            _ => None,
+        }
+    }
+}
+
+/// The next block to fetch. Persist it to resume a subscription where it stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventCursor {
+    pub next_block: u32,
+}
+
+impl EventCursor {
+    pub fn from_block(height: u32) -> Self {
+        Self { next_block: height }
+    }
+
+    /// A cursor only returning events of blocks produced after the current one.
+    pub async fn latest(provider: &Provider) -> SdkResult<Self> {
+        Ok(Self::from_block(provider.latest_block_height().await? + 1))
+    }
+}
+
+struct WatchedContract {
+    id: ContractId,
+    kind: ContractKind,
+    log_decoder: LogDecoder,
+}
+
+/// Polls the blocks of a provider for the logs of the watched contracts,
+/// decoded into [HyperlaneEvent]s.
+///
+/// Only logs of successful transactions are returned, as the logs of
+/// reverted transactions are not part of the contract state.
+pub struct EventSubscription {
+    provider: Provider,
+    cursor: EventCursor,
+    max_blocks_per_poll: u32,
+    watched: Vec<WatchedContract>,
+}
+
+impl EventSubscription {
+    pub fn new(provider: Provider, cursor: EventCursor) -> Self {
+        Self {
+            provider,
+            cursor,
+            max_blocks_per_poll: DEFAULT_MAX_BLOCKS_PER_POLL,
+            watched: vec![],
+        }
+    }
+
+    /// Watches the core contracts of the address book and the given warp routes.
+    pub fn from_address_book(
+        provider: Provider,
+        cursor: EventCursor,
+        book: &AddressBook,
+        warp_routes: &[&str],
+    ) -> SdkResult<Self> {
+        let mut subscription = Self::new(provider, cursor)
+            .watch(book.mailbox()?, ContractKind::Mailbox)
+            .watch(book.merkle_tree_hook()?, ContractKind::MerkleTreeHook)
+            .watch(book.igp()?, ContractKind::Igp)
+            .watch(book.validator_announce()?, ContractKind::ValidatorAnnounce);
+        for name in warp_routes {
+            subscription = subscription.watch(book.contract_id(name)?, ContractKind::WarpRoute);
+        }
+        Ok(subscription)
+    }
+
+    pub fn watch(mut self, id: ContractId, kind: ContractKind) -> Self {
+        // The instance is only used for its log decoder, which does not need a provider
+        let account = WalletUnlocked::new_random(None);
+        let log_decoder = match kind {
+            ContractKind::Mailbox => Mailbox::new(id, account).log_decoder(),
+            ContractKind::MerkleTreeHook => MerkleTreeHook::new(id, account).log_decoder(),
+            ContractKind::Igp => InterchainGasPaymaster::new(id, account).log_decoder(),
+            ContractKind::WarpRoute => WarpRoute::new(id, account).log_decoder(),
+            ContractKind::ValidatorAnnounce => ValidatorAnnounce::new(id, account).log_decoder(),
+        };
+        self.watched.push(WatchedContract {
+            id,
+            kind,
+            log_decoder,
+        });
+        self
+    }
+
+    pub fn with_max_blocks_per_poll(mut self, max_blocks_per_poll: u32) -> Self {
+        self.max_blocks_per_poll = max_blocks_per_poll.max(1);
+        self
+    }
+
+    pub fn cursor(&self) -> EventCursor {
+        self.cursor
+    }
+
+    /// Fetches the events of the blocks from the cursor up to the latest block,
+    /// at most `max_blocks_per_poll` blocks, and advances the cursor past them.
+    ///
+    /// The cursor is only advanced once every block was fetched, so after an error
+    /// the next poll fetches the same blocks again and no event is skipped.
+    ///
+    /// ### Returns
+    ///
+    /// * The events in the order they were emitted, empty if no block was produced.
+    pub async fn poll(&mut self) -> SdkResult<Vec<ContractEvent>> {
+        let (events, cursor) = fetch_events(
+            &self.provider,
+            &self.watched,
+            self.cursor,
+            self.max_blocks_per_poll,
+        )
+        .await?;
+        self.cursor = cursor;
+        Ok(events)
+    }
+
+    /// Polls until an event matches `predicate` or `timeout` elapses.
+    ///
+    /// ### Returns
+    ///
+    /// * The first matching event, `None` on timeout.
+    pub async fn wait_for(
+        &mut self,
+        poll_interval: Duration,
+        timeout: Duration,
+        predicate: impl Fn(&ContractEvent) -> bool,
+    ) -> SdkResult<Option<ContractEvent>> {
+        let start = Instant::now();
+        loop {
+            if let Some(event) = self.poll().await?.into_iter().find(|e| predicate(e)) {
+                return Ok(Some(event));
+            }
+            if start.elapsed() >= timeout {
+                return Ok(None);
+            }
+            tokio::time::sleep(poll_interval.min(timeout.saturating_sub(start.elapsed()))).await;
+        }
+    }
+
+    /// Waits for the `ProcessIdEvent` of `message_id`, instead of polling `delivered`.
+    pub async fn wait_for_process(
+        &mut self,
+        message_id: Bits256,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> SdkResult<Option<ContractEvent>> {
+        self.wait_for(poll_interval, timeout, |event| {
+            event.processed_message_id() == Some(message_id)
+        })
+        .await
+    }
+
+    /// An endless stream of events, polling every `poll_interval` once caught up.
+    /// The stream ends after the first error, once every event of the fetched blocks
+    /// was yielded. The cursor of the subscription is not persisted, so resume from
+    /// `EventCursor::from_block(last.block_height + 1)` of the last event.
+    pub fn into_stream(
+        self,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = SdkResult<ContractEvent>> {
+        futures::stream::unfold(Some((self, VecDeque::new())), move |state| async move {
+            let (mut subscription, mut buffer) = state?;
+            loop {
+                if let Some(event) = buffer.pop_front() {
+                    return Some((Ok(event), Some((subscription, buffer))));
+                }
+                let caught_up = match subscription.poll().await {
+                    Ok(events) => {
+                        buffer.extend(events);
+                        buffer.is_empty()
+                    }
+                    Err(e) => return Some((Err(e), None)),
+                };
+                if caught_up {
+                    tokio::time::sleep(poll_interval).await;
+                }
+            }
+        })
+    }
+}
+
+/// A block as read by `fetch_events`.
+struct FetchedBlock {
+    /// Unix timestamp of the block, in seconds.
+    timestamp: Option<i64>,
+    transactions: Vec<Bytes32>,
+}
+
+/// The blocks and receipts read by `fetch_events`, the `Provider` outside of the tests.
+trait BlockSource {
+    async fn latest_height(&self) -> SdkResult<u32>;
+
+    async fn block_at(&self, height: u32) -> SdkResult<Option<FetchedBlock>>;
+
+    /// The receipts of a transaction, `None` if it did not succeed.
+    async fn successful_receipts(&self, tx_id: &Bytes32) -> SdkResult<Option<Vec<Receipt>>>;
+}
+
+impl BlockSource for Provider {
+    async fn latest_height(&self) -> SdkResult<u32> {
+        Ok(self.latest_block_height().await?)
+    }
+
+    async fn block_at(&self, height: u32) -> SdkResult<Option<FetchedBlock>> {
+        Ok(self
+            .block_by_height(height.into())
+            .await?
+            .map(|block| FetchedBlock {
+                timestamp: block.header.time.map(|time| time.timestamp()),
+                transactions: block.transactions,
+            }))
+    }
+
+    async fn successful_receipts(&self, tx_id: &Bytes32) -> SdkResult<Option<Vec<Receipt>>> {
+        match self.tx_status(tx_id).await? {
+            TxStatus::Success { receipts, .. } => Ok(Some(receipts)),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Fetches the events of the watched contracts in the blocks from `cursor` up to
+/// the latest block, at most `max_blocks` blocks.
+///
+/// ### Returns
+///
+/// * The events and the cursor past the fetched blocks. Nothing is returned on error,
+///   so the blocks are fetched again from `cursor`.
+async fn fetch_events(
+    source: &impl BlockSource,
+    watched: &[WatchedContract],
+    cursor: EventCursor,
+    max_blocks: u32,
+) -> SdkResult<(Vec<ContractEvent>, EventCursor)> {
+    let latest = source.latest_height().await?;
+    let start = cursor.next_block;
+    let end = latest.min(start.saturating_add(max_blocks - 1));
+    let mut events = vec![];
+    let mut next = cursor;
+
+    for height in start..=end {
+        let Some(block) = source.block_at(height).await? else {
+            break;
+        };
+
+        for tx_id in block.transactions {
+            if let Some(receipts) = source.successful_receipts(&tx_id).await? {
+                events.extend(decode_receipts(
+                    watched,
+                    height,
+                    block.timestamp,
+                    tx_id,
+                    &receipts,
+                ));
+            }
+        }
+        next = EventCursor::from_block(height + 1);
+    }
+
+    Ok((events, next))
+}
+
+fn decode_receipts(
+    watched: &[WatchedContract],
+    block_height: u32,
+    timestamp: Option<i64>,
+    tx_id: Bytes32,
+    receipts: &[Receipt],
+) -> Vec<ContractEvent> {
+    receipts
+        .iter()
+        .enumerate()
+        .filter_map(|(receipt_index, receipt)| {
+            let Receipt::LogData { id, .. } = receipt else {
+                return None;
+            };
+            let watched = watched.iter().find(|w| w.id == *id)?;
+            let event = decode_event(watched, std::slice::from_ref(receipt))?;
+            Some(ContractEvent {
+                contract_id: *id,
+                block_height,
+                timestamp,
+                tx_id,
+                receipt_index,
+                event,
+            })
+        })
+        .collect()
+}
+
+fn decode_event(watched: &WatchedContract, receipts: &[Receipt]) -> Option<HyperlaneEvent> {
+    let decoder = &watched.log_decoder;
+    match watched.kind {
+        ContractKind::Mailbox => None::<MailboxEvent>
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::Dispatch))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::DispatchId))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::Process))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::ProcessId))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::DefaultIsmSet))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::DefaultHookSet))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::RequiredHookSet))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::HookAllowlistEnabledSet))
+            .or_else(|| last_log(receipts, decoder).map(MailboxEvent::HookAllowedSet))
+            .map(HyperlaneEvent::Mailbox),
+        ContractKind::MerkleTreeHook => last_log(receipts, decoder)
+            .map(MerkleTreeHookEvent::InsertedIntoTree)
+            .map(HyperlaneEvent::MerkleTreeHook),
+        ContractKind::Igp => None::<IgpEvent>
+            .or_else(|| last_log(receipts, decoder).map(IgpEvent::GasPayment))
+            .or_else(|| last_log(receipts, decoder).map(IgpEvent::GasOracleSet))
+            .or_else(|| last_log(receipts, decoder).map(IgpEvent::DestinationGasConfigSet))
+            .or_else(|| last_log(receipts, decoder).map(IgpEvent::BeneficiarySet))
+            .or_else(|| last_log(receipts, decoder).map(IgpEvent::Claim))
+            .map(HyperlaneEvent::Igp),
+        ContractKind::WarpRoute => None::<WarpRouteEvent>
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::SentTransferRemote))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::ReceivedTransferRemote))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::BeneficiarySet))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::Claim))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::PayloadDropped))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::ContractRecipientSet))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::FeeSet))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::TransferFeeRetained))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::BalanceReconciled))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::OriginTokenSet))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::Deposit))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::DepositRefunded))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::RateLimitSet))
+            .or_else(|| last_log(receipts, decoder).map(WarpRouteEvent::RateLimitReached))
+            .map(HyperlaneEvent::WarpRoute),
+        ContractKind::ValidatorAnnounce => last_log(receipts, decoder)
+            .map(ValidatorAnnounceEvent::ValidatorAnnouncement)
+            .map(HyperlaneEvent::ValidatorAnnounce),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::{
+        core::{
+            codec::{log_formatters_lookup, ABIEncoder, LogFormatter},
+            traits::Tokenizable,
+        },
+        types::{errors::Error, Identity},
+    };
+
+    use super::*;
+    use crate::error::SdkError;
+
+    const CONTRACT_ID: ContractId = ContractId::new([1; 32]);
+
+    fn watched(kind: ContractKind, formatters: Vec<(u64, LogFormatter)>) -> WatchedContract {
+        WatchedContract {
+            id: CONTRACT_ID,
+            kind,
+            log_decoder: LogDecoder::new(log_formatters_lookup(
+                formatters
+                    .into_iter()
+                    .map(|(log_id, formatter)| (log_id.to_string(), formatter))
+                    .collect(),
+                CONTRACT_ID,
+            )),
+        }
+    }
+
+    fn log_receipt(log_id: u64, value: impl Tokenizable) -> Receipt {
+        let data = ABIEncoder::default().encode(&[value.into_token()]).unwrap();
+        Receipt::LogData {
+            id: CONTRACT_ID,
+            ra: 0,
+            rb: log_id,
+            ptr: 0,
+            len: data.len() as u64,
+            digest: Bytes32::zeroed(),
+            pc: 0,
+            is: 0,
+            data: Some(data),
+        }
+    }
+
+    fn warp_route() -> WatchedContract {
+        watched(
+            ContractKind::WarpRoute,
+            vec![
+                (1, LogFormatter::new::<FeeSetEvent>()),
+                (2, LogFormatter::new::<TransferFeeRetainedEvent>()),
+                (3, LogFormatter::new::<DepositEvent>()),
+                (4, LogFormatter::new::<DepositRefundedEvent>()),
+                (5, LogFormatter::new::<RateLimitSetEvent>()),
+                (6, LogFormatter::new::<RateLimitReachedEvent>()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_decode_warp_route_fee_events() {
+        let watched = warp_route();
+
+        let event = FeeSetEvent { fee_bps: 30 };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(1, event.clone())]),
+            Some(HyperlaneEvent::WarpRoute(WarpRouteEvent::FeeSet(event)))
+        );
+
+        let event = TransferFeeRetainedEvent {
+            destination: 11155111,
+            fee: 3,
+        };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(2, event.clone())]),
+            Some(HyperlaneEvent::WarpRoute(
+                WarpRouteEvent::TransferFeeRetained(event)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decode_warp_route_deposit_events() {
+        let watched = warp_route();
+        let depositor = Identity::ContractId(ContractId::new([2; 32]));
+
+        let event = DepositEvent {
+            depositor: depositor.clone(),
+            amount: 100,
+        };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(3, event.clone())]),
+            Some(HyperlaneEvent::WarpRoute(WarpRouteEvent::Deposit(event)))
+        );
+
+        let event = DepositRefundedEvent {
+            depositor,
+            amount: 100,
+        };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(4, event.clone())]),
+            Some(HyperlaneEvent::WarpRoute(WarpRouteEvent::DepositRefunded(
+                event
+            )))
+        );
+    }
+
+    #[test]
+    fn test_decode_warp_route_rate_limit_events() {
+        let watched = warp_route();
+
+        let event = RateLimitSetEvent {
+            domain: 11155111,
+            direction: RateLimitDirection::Outbound,
+            capacity: 1000,
+            refill_rate: 10,
+        };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(5, event.clone())]),
+            Some(HyperlaneEvent::WarpRoute(WarpRouteEvent::RateLimitSet(
+                event
+            )))
+        );
+
+        let event = RateLimitReachedEvent {
+            domain: 11155111,
+            direction: RateLimitDirection::Inbound,
+            capacity: 1000,
+        };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(6, event.clone())]),
+            Some(HyperlaneEvent::WarpRoute(WarpRouteEvent::RateLimitReached(
+                event
+            )))
+        );
+    }
+
+    #[test]
+    fn test_decode_mailbox_hook_allowlist_events() {
+        let watched = watched(
+            ContractKind::Mailbox,
+            vec![
+                (1, LogFormatter::new::<HookAllowlistEnabledSetEvent>()),
+                (2, LogFormatter::new::<HookAllowedSetEvent>()),
+            ],
+        );
+
+        let event = HookAllowlistEnabledSetEvent { enabled: true };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(1, event.clone())]),
+            Some(HyperlaneEvent::Mailbox(
+                MailboxEvent::HookAllowlistEnabledSet(event)
+            ))
+        );
+
+        let event = HookAllowedSetEvent {
+            hook: ContractId::new([3; 32]),
+            allowed: false,
+        };
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(2, event.clone())]),
+            Some(HyperlaneEvent::Mailbox(MailboxEvent::HookAllowedSet(event)))
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_log() {
+        let watched = warp_route();
+        assert_eq!(
+            decode_event(&watched, &[log_receipt(99, FeeSetEvent { fee_bps: 30 })]),
+            None
+        );
+    }
+
+    /// Blocks `1..=latest`, each holding one transaction logging a `FeeSetEvent`
+    /// with the block height as fee.
+    struct FakeChain {
+        latest: u32,
+        /// Height of the block whose fetch fails.
+        fail_at: Option<u32>,
+    }
+
+    impl BlockSource for FakeChain {
+        async fn latest_height(&self) -> SdkResult<u32> {
+            Ok(self.latest)
+        }
+
+        async fn block_at(&self, height: u32) -> SdkResult<Option<FetchedBlock>> {
+            if self.fail_at == Some(height) {
+                return Err(SdkError::Fuels(Error::Provider(
+                    "connection reset".to_string(),
+                )));
+            }
+            Ok((height <= self.latest).then(|| FetchedBlock {
+                timestamp: Some(height.into()),
+                transactions: vec![Bytes32::new([height as u8; 32])],
+            }))
+        }
+
+        async fn successful_receipts(&self, tx_id: &Bytes32) -> SdkResult<Option<Vec<Receipt>>> {
+            let fee_bps = u64::from(tx_id[0]);
+            Ok(Some(vec![log_receipt(1, FeeSetEvent { fee_bps })]))
+        }
+    }
+
+    fn fee_set_heights(events: &[ContractEvent]) -> Vec<u32> {
+        events
+            .iter()
+            .map(|event| {
+                assert_eq!(
+                    event.event,
+                    HyperlaneEvent::WarpRoute(WarpRouteEvent::FeeSet(FeeSetEvent {
+                        fee_bps: event.block_height.into()
+                    }))
+                );
+                event.block_height
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_events_failure_keeps_cursor() {
+        let watched = [warp_route()];
+        let mut chain = FakeChain {
+            latest: 4,
+            fail_at: Some(3),
+        };
+        let cursor = EventCursor::from_block(1);
+
+        assert!(fetch_events(&chain, &watched, cursor, 10).await.is_err());
+
+        chain.fail_at = None;
+        let (events, next) = fetch_events(&chain, &watched, cursor, 10).await.unwrap();
+        assert_eq!(fee_set_heights(&events), vec![1, 2, 3, 4]);
+        assert_eq!(next, EventCursor::from_block(5));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_events_max_blocks() {
+        let watched = [warp_route()];
+        let chain = FakeChain {
+            latest: 4,
+            fail_at: None,
+        };
+
+        let (events, next) = fetch_events(&chain, &watched, EventCursor::from_block(1), 3)
+            .await
+            .unwrap();
+        assert_eq!(fee_set_heights(&events), vec![1, 2, 3]);
+        assert_eq!(next, EventCursor::from_block(4));
+
+        let (events, next) = fetch_events(&chain, &watched, next, 3).await.unwrap();
+        assert_eq!(fee_set_heights(&events), vec![4]);
+        assert_eq!(next, EventCursor::from_block(5));
+
+        let (events, next) = fetch_events(&chain, &watched, next, 3).await.unwrap();
+        assert!(events.is_empty());
+        assert_eq!(next, EventCursor::from_block(5));
+    }
+}
@@ -0,0 +1,106 @@
+use fuels::{
+    accounts::Account,
+    core::codec::LogDecoder,
+    programs::calls::{CallParameters, Execution},
+    types::{
+        bech32::Bech32ContractId, transaction_builders::VariableOutputPolicy, AssetId, Bits256,
+        ContractId, Identity,
+    },
+};
+
+use crate::{
+    abis::InterchainGasPaymaster,
+    address_book::AddressBook,
+    error::{SdkError, SdkResult},
+};
+
+/// Client for the `InterchainGasPaymaster` contract.
+pub struct IgpClient<A: Account> {
+    instance: InterchainGasPaymaster<A>,
+    log_decoder: LogDecoder,
+}
+
+impl<A: Account> IgpClient<A> {
+    pub fn new(id: impl Into<Bech32ContractId>, account: A) -> Self {
+        let instance = InterchainGasPaymaster::new(id, account);
+        let log_decoder = instance.log_decoder();
+        Self {
+            instance,
+            log_decoder,
+        }
+    }
+
+    pub fn from_address_book(book: &AddressBook, account: A) -> SdkResult<Self> {
+        Ok(Self::new(book.igp()?, account))
+    }
+
+    /// The underlying contract instance, for calls the client does not wrap.
+    pub fn instance(&self) -> &InterchainGasPaymaster<A> {
+        &self.instance
+    }
+
+    pub fn contract_id(&self) -> ContractId {
+        ContractId::from(self.instance.id())
+    }
+
+    /// Quotes the payment in the base asset for `gas_amount` on the destination domain.
+    pub async fn quote_gas_payment(
+        &self,
+        destination_domain: u32,
+        gas_amount: u64,
+    ) -> SdkResult<u64> {
+        let response = self
+            .instance
+            .methods()
+            .quote_gas_payment(destination_domain, gas_amount)
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    /// Pays for the delivery of an already dispatched message.
+    /// The payment is quoted first, so nothing is refunded to `refund_address`
+    /// unless the gas price changes between the quote and the call.
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The amount paid in the base asset.
+    pub async fn pay_for_gas(
+        &self,
+        message_id: Bits256,
+        destination_domain: u32,
+        gas_amount: u64,
+        refund_address: Identity,
+    ) -> SdkResult<u64> {
+        let payment = self
+            .quote_gas_payment(destination_domain, gas_amount)
+            .await?;
+
+        self.instance
+            .methods()
+            .pay_for_gas(message_id, destination_domain, gas_amount, refund_address)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(payment)
+                    .with_asset_id(AssetId::BASE),
+            )?
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .call()
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(payment)
+    }
+
+    fn error(&self, error: fuels::types::errors::Error) -> SdkError {
+        SdkError::from_call(error, &self.log_decoder)
+    }
+}
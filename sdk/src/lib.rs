@@ -0,0 +1,29 @@
+//! Typed clients for the Fuel Hyperlane contracts.
+//!
+//! The contract bindings live in [`abis`]. The clients wrap the calls which need more
+//! than a single contract call, e.g. quoting and forwarding the gas payment, and decode
+//! the contract error enums of reverted calls into [`ContractError`].
+//! [`EventSubscription`] polls the contract logs as typed [`HyperlaneEvent`]s.
+//!
+//! The bindings are generated from the ABIs of the built contracts. Collateral and synthetic
+//! transfers also need the `scripts/wr_multicall` binary, see
+//! [`WarpRouteClient::with_transfer_script`].
+
+pub mod abis;
+pub mod address_book;
+pub mod error;
+pub mod events;
+pub mod igp;
+pub mod mailbox;
+pub mod warp_route;
+
+/// The `fuels` version the SDK is built against, for crates on another version
+/// which have to build the SDK types, e.g. a `Provider` for [`EventSubscription`].
+pub use fuels;
+
+pub use address_book::AddressBook;
+pub use error::{ContractError, SdkError, SdkResult};
+pub use events::{ContractEvent, EventCursor, EventSubscription, HyperlaneEvent};
+pub use igp::IgpClient;
+pub use mailbox::MailboxClient;
+pub use warp_route::WarpRouteClient;
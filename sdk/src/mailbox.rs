@@ -0,0 +1,219 @@
+use fuels::{
+    accounts::Account,
+    core::codec::LogDecoder,
+    programs::calls::{CallParameters, Execution},
+    types::{
+        bech32::Bech32ContractId, transaction_builders::VariableOutputPolicy, AssetId, Bits256,
+        Bytes, ContractId, Identity,
+    },
+};
+use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage};
+
+use crate::{
+    abis::Mailbox,
+    address_book::AddressBook,
+    error::{SdkError, SdkResult},
+};
+
+/// Client for the `Mailbox` contract.
+///
+/// `dispatch` quotes the hook payment and forwards it in the base asset,
+/// so callers do not have to know which hooks the Mailbox is configured with.
+pub struct MailboxClient<A: Account> {
+    instance: Mailbox<A>,
+    log_decoder: LogDecoder,
+}
+
+impl<A: Account> MailboxClient<A> {
+    pub fn new(id: impl Into<Bech32ContractId>, account: A) -> Self {
+        let instance = Mailbox::new(id, account);
+        let log_decoder = instance.log_decoder();
+        Self {
+            instance,
+            log_decoder,
+        }
+    }
+
+    /// Adds the logs of another contract, e.g. a hook or ISM, to the ones used
+    /// to decode revert errors, so reverts inside them are decoded as well.
+    pub fn with_log_decoder(mut self, log_decoder: LogDecoder) -> Self {
+        self.log_decoder.merge(log_decoder);
+        self
+    }
+
+    pub fn from_address_book(book: &AddressBook, account: A) -> SdkResult<Self> {
+        Ok(Self::new(book.mailbox()?, account))
+    }
+
+    /// The underlying contract instance, for calls the client does not wrap.
+    pub fn instance(&self) -> &Mailbox<A> {
+        &self.instance
+    }
+
+    pub fn contract_id(&self) -> ContractId {
+        ContractId::from(self.instance.id())
+    }
+
+    /// Quotes the payment required by the hooks to dispatch the message.
+    ///
+    /// ### Arguments
+    ///
+    /// * `hook` - Hook to use instead of the default hook, the required hook is always quoted.
+    pub async fn quote_dispatch(
+        &self,
+        destination_domain: u32,
+        recipient: Bits256,
+        body: Vec<u8>,
+        metadata: Vec<u8>,
+        hook: Option<ContractId>,
+    ) -> SdkResult<u64> {
+        let response = self
+            .instance
+            .methods()
+            .quote_dispatch(
+                destination_domain,
+                recipient,
+                Bytes(body),
+                Bytes(metadata),
+                hook.unwrap_or_default(),
+            )
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    /// Dispatches a message, paying the quoted amount to the hooks.
+    ///
+    /// ### Returns
+    ///
+    /// * [Bits256] - The ID of the dispatched message.
+    pub async fn dispatch(
+        &self,
+        destination_domain: u32,
+        recipient: Bits256,
+        body: Vec<u8>,
+        metadata: Vec<u8>,
+        hook: Option<ContractId>,
+    ) -> SdkResult<Bits256> {
+        let payment = self
+            .quote_dispatch(
+                destination_domain,
+                recipient,
+                body.clone(),
+                metadata.clone(),
+                hook,
+            )
+            .await?;
+
+        let response = self
+            .instance
+            .methods()
+            .dispatch(
+                destination_domain,
+                recipient,
+                Bytes(body),
+                Bytes(metadata),
+                hook.unwrap_or_default(),
+            )
+            .call_params(
+                CallParameters::default()
+                    .with_amount(payment)
+                    .with_asset_id(AssetId::BASE),
+            )?
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .call()
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    /// Delivers an inbound message, verified by the recipient ISM with `metadata`.
+    pub async fn process(&self, metadata: Vec<u8>, message: &HyperlaneMessage) -> SdkResult<()> {
+        self.instance
+            .methods()
+            .process(Bytes(metadata), Bytes(RawHyperlaneMessage::from(message)))
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .call()
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(())
+    }
+
+    pub async fn delivered(&self, message_id: Bits256) -> SdkResult<bool> {
+        let response = self
+            .instance
+            .methods()
+            .delivered(message_id)
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    /// The account which processed the message, `None` if it was not delivered.
+    pub async fn processor(&self, message_id: Bits256) -> SdkResult<Option<Identity>> {
+        let response = self
+            .instance
+            .methods()
+            .processor(message_id)
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    /// The block height at which the message was processed, `None` if it was not delivered.
+    pub async fn processed_at(&self, message_id: Bits256) -> SdkResult<Option<u32>> {
+        let response = self
+            .instance
+            .methods()
+            .processed_at(message_id)
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    pub async fn nonce(&self) -> SdkResult<u32> {
+        let response = self
+            .instance
+            .methods()
+            .nonce()
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    pub async fn latest_dispatched_id(&self) -> SdkResult<Bits256> {
+        let response = self
+            .instance
+            .methods()
+            .latest_dispatched_id()
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    fn error(&self, error: fuels::types::errors::Error) -> SdkError {
+        SdkError::from_call(error, &self.log_decoder)
+    }
+}
@@ -0,0 +1,230 @@
+use fuels::{
+    accounts::Account,
+    core::codec::LogDecoder,
+    programs::calls::{CallParameters, Execution},
+    tx::Output,
+    types::{
+        bech32::Bech32ContractId, transaction_builders::VariableOutputPolicy, Address, AssetId,
+        Bits256, ContractId,
+    },
+};
+
+use crate::{
+    abis::{TokenMetadata, WarpRoute, WarpRouteTokenMode, WarpRouteTransfer},
+    address_book::AddressBook,
+    error::{SdkError, SdkResult},
+};
+
+/// Client for the `WarpRoute` contract.
+pub struct WarpRouteClient<A: Account + Clone> {
+    instance: WarpRoute<A>,
+    account: A,
+    transfer_script_path: Option<String>,
+    log_decoder: LogDecoder,
+}
+
+impl<A: Account + Clone> WarpRouteClient<A> {
+    pub fn new(id: impl Into<Bech32ContractId>, account: A) -> Self {
+        let instance = WarpRoute::new(id, account.clone());
+        let log_decoder = instance.log_decoder();
+        Self {
+            instance,
+            account,
+            transfer_script_path: None,
+            log_decoder,
+        }
+    }
+
+    /// Loads the warp route stored under `name` in the address book, e.g. `warpRouteNative`.
+    pub fn from_address_book(book: &AddressBook, name: &str, account: A) -> SdkResult<Self> {
+        Ok(Self::new(book.contract_id(name)?, account))
+    }
+
+    /// Sets the path of the `scripts/wr_multicall` binary, which sends the tokens and
+    /// calls `transfer_remote` in the same transaction so the transfer cannot be frontrun.
+    /// Required for collateral and synthetic transfers.
+    pub fn with_transfer_script(mut self, path: impl Into<String>) -> Self {
+        self.transfer_script_path = Some(path.into());
+        self
+    }
+
+    /// Adds the logs of another contract, e.g. the Mailbox or a hook, to the ones used
+    /// to decode revert errors, so reverts inside them are decoded as well.
+    pub fn with_log_decoder(mut self, log_decoder: LogDecoder) -> Self {
+        self.log_decoder.merge(log_decoder);
+        self
+    }
+
+    /// The underlying contract instance, for calls the client does not wrap.
+    pub fn instance(&self) -> &WarpRoute<A> {
+        &self.instance
+    }
+
+    pub fn contract_id(&self) -> ContractId {
+        ContractId::from(self.instance.id())
+    }
+
+    pub async fn token_mode(&self) -> SdkResult<WarpRouteTokenMode> {
+        let response = self
+            .instance
+            .methods()
+            .get_token_mode()
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    pub async fn token_info(&self) -> SdkResult<TokenMetadata> {
+        let response = self
+            .instance
+            .methods()
+            .get_token_info()
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    /// Quotes the gas payment in the base asset for a transfer to the destination domain.
+    pub async fn quote_gas_payment(&self, destination_domain: u32) -> SdkResult<u64> {
+        let response = self
+            .instance
+            .methods()
+            .quote_gas_payment(destination_domain)
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    /// Transfers `amount` of the warp route token to `recipient` on the destination domain.
+    ///
+    /// The gas payment is quoted and forwarded along with the tokens.
+    /// Native transfers forward both in a single call, collateral and synthetic transfers
+    /// go through the `wr_multicall` script, as the tokens have to reach the warp route
+    /// before `transfer_remote` is called, and fail with [SdkError::TransferScriptNotSet]
+    /// unless it was set with `with_transfer_script`.
+    ///
+    /// ### Returns
+    ///
+    /// * [Bits256] - The ID of the dispatched message.
+    pub async fn transfer_remote(
+        &self,
+        destination_domain: u32,
+        recipient: Bits256,
+        amount: u64,
+    ) -> SdkResult<Bits256> {
+        let quote = self.quote_gas_payment(destination_domain).await?;
+
+        match self.token_mode().await? {
+            WarpRouteTokenMode::NATIVE => {
+                self.transfer_remote_native(destination_domain, recipient, amount, quote)
+                    .await
+            }
+            WarpRouteTokenMode::COLLATERAL
+            | WarpRouteTokenMode::COLLATERAL_FEE
+            | WarpRouteTokenMode::SYNTHETIC => {
+                let asset_id = self.token_info().await?.asset_id;
+                self.transfer_remote_with_script(
+                    destination_domain,
+                    recipient,
+                    amount,
+                    asset_id,
+                    quote,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn transfer_remote_native(
+        &self,
+        destination_domain: u32,
+        recipient: Bits256,
+        amount: u64,
+        quote: u64,
+    ) -> SdkResult<Bits256> {
+        let response = self
+            .instance
+            .methods()
+            .transfer_remote(destination_domain, recipient, amount, None, None)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(amount + quote)
+                    .with_asset_id(AssetId::BASE),
+            )?
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .call()
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    async fn transfer_remote_with_script(
+        &self,
+        destination_domain: u32,
+        recipient: Bits256,
+        amount: u64,
+        asset_id: AssetId,
+        quote: u64,
+    ) -> SdkResult<Bits256> {
+        let script_path = self
+            .transfer_script_path
+            .as_deref()
+            .ok_or(SdkError::TransferScriptNotSet)?;
+        let owner = Address::from(self.account.address());
+
+        let mut inputs = self
+            .account
+            .get_asset_inputs_for_amount(asset_id, amount, None)
+            .await?;
+        let mut outputs = vec![Output::change(owner, 0, asset_id)];
+
+        if quote > 0 {
+            inputs.extend(
+                self.account
+                    .get_asset_inputs_for_amount(AssetId::BASE, quote, None)
+                    .await?,
+            );
+            outputs.push(Output::change(owner, 0, AssetId::BASE));
+        }
+
+        let script = WarpRouteTransfer::new(self.account.clone(), script_path);
+        let response = script
+            .main(
+                Bits256(self.contract_id().into()),
+                destination_domain,
+                recipient,
+                amount,
+                Bits256(*asset_id),
+                quote,
+            )
+            .with_inputs(inputs)
+            .with_outputs(outputs)
+            .with_contract_ids(&[self.instance.id()])
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+            .determine_missing_contracts(None)
+            .await
+            .map_err(|e| self.error(e))?
+            .call()
+            .await
+            .map_err(|e| self.error(e))?;
+
+        Ok(response.value)
+    }
+
+    fn error(&self, error: fuels::types::errors::Error) -> SdkError {
+        SdkError::from_call(error, &self.log_decoder)
+    }
+}
@@ -0,0 +1,171 @@
+//! Checkpoint hashing and signing, mirroring `contracts/libs/checkpoint`.
+
+use alloy_primitives::{eip191_hash_message, keccak256, B256};
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use fuels::types::{Bits256, EvmAddress};
+use hyperlane_core::H256;
+
+use crate::ism_metadata::SIGNATURE_LENGTH;
+
+/// Same as `domain_hash` in the Sway library.
+pub fn domain_hash(origin: u32, origin_merkle_tree_hook: H256) -> H256 {
+    let mut bytes = Vec::with_capacity(4 + 32 + 9);
+    bytes.extend_from_slice(&origin.to_be_bytes());
+    bytes.extend_from_slice(origin_merkle_tree_hook.as_bytes());
+    bytes.extend_from_slice(b"HYPERLANE");
+    H256(keccak256(bytes).0)
+}
+
+/// A checkpoint of the origin merkle tree hook, with the same fields as in `hyperlane_core`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub merkle_tree_hook_address: H256,
+    pub mailbox_domain: u32,
+    pub root: H256,
+    pub index: u32,
+}
+
+impl Checkpoint {
+    pub fn domain_hash(&self) -> H256 {
+        domain_hash(self.mailbox_domain, self.merkle_tree_hook_address)
+    }
+
+    pub fn with_message_id(self, message_id: H256) -> CheckpointWithMessageId {
+        CheckpointWithMessageId {
+            checkpoint: self,
+            message_id,
+        }
+    }
+}
+
+/// The checkpoint signed by the validators of the multisig ISMs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointWithMessageId {
+    pub checkpoint: Checkpoint,
+    pub message_id: H256,
+}
+
+impl CheckpointWithMessageId {
+    /// The hash the validators sign, before the EIP-191 prefix is applied.
+    pub fn signing_hash(&self) -> H256 {
+        let mut bytes = Vec::with_capacity(32 + 32 + 4 + 32);
+        bytes.extend_from_slice(self.checkpoint.domain_hash().as_bytes());
+        bytes.extend_from_slice(self.checkpoint.root.as_bytes());
+        bytes.extend_from_slice(&self.checkpoint.index.to_be_bytes());
+        bytes.extend_from_slice(self.message_id.as_bytes());
+        H256(keccak256(bytes).0)
+    }
+
+    /// Same as `digest` in the Sway library, the EIP-191 hash of `signing_hash`.
+    pub fn digest(&self) -> H256 {
+        H256(eip191_hash_message(self.signing_hash().as_bytes()).0)
+    }
+
+    pub fn to_hyperlane(&self) -> hyperlane_core::CheckpointWithMessageId {
+        hyperlane_core::CheckpointWithMessageId {
+            checkpoint: hyperlane_core::Checkpoint {
+                merkle_tree_hook_address: self.checkpoint.merkle_tree_hook_address,
+                mailbox_domain: self.checkpoint.mailbox_domain,
+                root: self.checkpoint.root,
+                index: self.checkpoint.index,
+            },
+            message_id: self.message_id,
+        }
+    }
+}
+
+/// Signs the checkpoint with EIP-191, as the validator agents do.
+///
+/// ### Returns
+///
+/// * The signature packed as `r || s || v`, as read by `signature_at` of the multisig metadata.
+pub fn sign_checkpoint(
+    signer: &PrivateKeySigner,
+    checkpoint: &CheckpointWithMessageId,
+) -> [u8; SIGNATURE_LENGTH] {
+    signer
+        .sign_message_sync(checkpoint.signing_hash().as_bytes())
+        .expect("Failed to sign checkpoint.")
+        .as_bytes()
+}
+
+/// The address of a validator as stored in the multisig ISMs.
+pub fn validator_address(signer: &PrivateKeySigner) -> EvmAddress {
+    EvmAddress::from(Bits256(signer.address().into_word().0))
+}
+
+/// Validators of a multisig ISM, in the order they are stored in the ISM.
+pub struct ValidatorSet {
+    pub signers: Vec<PrivateKeySigner>,
+}
+
+impl ValidatorSet {
+    pub fn new(signers: Vec<PrivateKeySigner>) -> Self {
+        Self { signers }
+    }
+
+    pub fn random(count: usize) -> Self {
+        Self::new((0..count).map(|_| PrivateKeySigner::random()).collect())
+    }
+
+    pub fn from_private_keys(private_keys: &[&str]) -> Self {
+        Self::new(
+            private_keys
+                .iter()
+                .map(|key| key.parse().expect("Invalid validator private key."))
+                .collect(),
+        )
+    }
+
+    /// The validator addresses to initialize the ISM with.
+    pub fn addresses(&self) -> Vec<EvmAddress> {
+        self.signers.iter().map(validator_address).collect()
+    }
+
+    /// Signs the checkpoint with every validator.
+    pub fn sign(&self, checkpoint: &CheckpointWithMessageId) -> Vec<[u8; SIGNATURE_LENGTH]> {
+        self.signers
+            .iter()
+            .map(|signer| sign_checkpoint(signer, checkpoint))
+            .collect()
+    }
+
+    /// Signs the checkpoint with the first `threshold` validators.
+    pub fn sign_threshold(
+        &self,
+        checkpoint: &CheckpointWithMessageId,
+        threshold: usize,
+    ) -> Vec<[u8; SIGNATURE_LENGTH]> {
+        let indexes: Vec<usize> = (0..threshold).collect();
+        self.sign_with(checkpoint, &indexes)
+    }
+
+    /// Signs the checkpoint with the validators at `indexes`.
+    ///
+    /// The multisig ISMs only accept signatures in the same order as the validators,
+    /// so the signatures are ordered by validator index regardless of the order of `indexes`.
+    pub fn sign_with(
+        &self,
+        checkpoint: &CheckpointWithMessageId,
+        indexes: &[usize],
+    ) -> Vec<[u8; SIGNATURE_LENGTH]> {
+        let mut indexes = indexes.to_vec();
+        indexes.sort_unstable();
+        indexes.dedup();
+
+        indexes
+            .into_iter()
+            .map(|index| sign_checkpoint(&self.signers[index], checkpoint))
+            .collect()
+    }
+}
+
+/// Recovers the address of the validator which signed the digest.
+pub fn recover_validator(signature: &[u8; SIGNATURE_LENGTH], digest: H256) -> Option<EvmAddress> {
+    let signature = alloy_primitives::Signature::try_from(&signature[..]).ok()?;
+    let address = signature
+        .recover_address_from_prehash(&B256::from(digest.0))
+        .ok()?;
+    Some(EvmAddress::from(Bits256(address.into_word().0)))
+}
@@ -0,0 +1 @@
+pub use fuel_hyperlane_sdk::abis::*;
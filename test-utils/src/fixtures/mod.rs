@@ -0,0 +1,151 @@
+//! Fixtures which deploy and wire up a complete local Hyperlane topology,
+//! so integration tests do not have to repeat the deployment of every contract.
+//!
+//! ```ignore
+//! let topology = Topology::launch(TopologyConfig::default()).await;
+//! let message_id = topology.dispatch(b"hello".to_vec()).await;
+//! ```
+//!
+//! `Simulator` deploys two topologies with different local domains and relays
+//! messages between them, to test Fuel to Fuel flows end to end.
+//!
+//! The contracts have to be built with `forc build` before the fixtures are used.
+//! The contract bindings are the ones of `fuel-hyperlane-sdk`, re-exported in [`abis`].
+
+pub mod abis;
+mod simulator;
+mod topology;
+
+pub use simulator::*;
+pub use topology::*;
+
+use fuels::types::{AssetId, Bits256};
+
+/// Domain of the deployed Mailbox, `fuel` by default.
+pub const LOCAL_DOMAIN: u32 = 0x6675656c;
+/// Domain messages are dispatched to and processed from by default.
+pub const REMOTE_DOMAIN: u32 = 11155111;
+/// Router of the remote warp route, enrolled on the local warp route by default.
+pub const REMOTE_ROUTER: Bits256 = Bits256([0xde; 32]);
+/// Decimals of the remote warp route token.
+pub const REMOTE_DECIMALS: u8 = 18;
+
+/// Collateral asset the launched wallet is funded with, next to the base asset.
+pub const COLLATERAL_ASSET: AssetId = AssetId::new([0xc0; 32]);
+
+pub const TOKEN_NAME: &str = "TestToken";
+pub const TOKEN_SYMBOL: &str = "TT";
+pub const TOKEN_DECIMALS: u8 = 9;
+pub const TOKEN_TOTAL_SUPPLY: u64 = 100_000_000_000_000;
+
+/// The ISM set as the default ISM of the Mailbox and the ISM of the recipient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsmConfig {
+    /// `ism-test`, which accepts every message unless told otherwise.
+    Test,
+    /// `MessageIdMultisigISM` with `validators` random validators.
+    MessageIdMultisig { validators: usize, threshold: u8 },
+    /// `MerkleRootMultisigISM` with `validators` random validators.
+    MerkleRootMultisig { validators: usize, threshold: u8 },
+}
+
+/// A hook of the Mailbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookConfig {
+    /// `mock-post-dispatch`, which records the call and charges the configured quote.
+    Mock,
+    /// `MerkleTreeHook`.
+    MerkleTree,
+    /// `InterchainGasPaymaster`, configured for the remote domain.
+    Igp,
+    /// `AggregationHook` of the `MerkleTreeHook` and the `InterchainGasPaymaster`.
+    MerkleTreeAndIgp,
+}
+
+/// Warp route deployed next to the core contracts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarpRouteConfig {
+    Native,
+    /// Backed by an `src20-test` token minted to the wallet.
+    Collateral,
+    Synthetic,
+}
+
+/// What `Topology::launch` and `Topology::deploy` deploy.
+#[derive(Debug, Clone)]
+pub struct TopologyConfig {
+    pub local_domain: u32,
+    /// Domain the IGP is configured for and the warp route router is enrolled on.
+    pub remote_domain: u32,
+    pub remote_router: Bits256,
+    pub remote_decimals: u8,
+    /// Decimals of the native and synthetic warp route tokens,
+    /// a collateral warp route uses the decimals of the collateral token.
+    pub token_decimals: u8,
+    pub ism: IsmConfig,
+    pub default_hook: HookConfig,
+    pub required_hook: HookConfig,
+    pub warp_route: Option<WarpRouteConfig>,
+}
+
+impl Default for TopologyConfig {
+    /// Test ISM, merkle tree hook as the default hook and the mock hook as the required hook,
+    /// the same setup the agents run against in the E2E tests.
+    fn default() -> Self {
+        Self {
+            local_domain: LOCAL_DOMAIN,
+            remote_domain: REMOTE_DOMAIN,
+            remote_router: REMOTE_ROUTER,
+            remote_decimals: REMOTE_DECIMALS,
+            token_decimals: TOKEN_DECIMALS,
+            ism: IsmConfig::Test,
+            default_hook: HookConfig::MerkleTree,
+            required_hook: HookConfig::Mock,
+            warp_route: None,
+        }
+    }
+}
+
+impl TopologyConfig {
+    pub fn with_local_domain(mut self, local_domain: u32) -> Self {
+        self.local_domain = local_domain;
+        self
+    }
+
+    pub fn with_remote_domain(mut self, remote_domain: u32) -> Self {
+        self.remote_domain = remote_domain;
+        self
+    }
+
+    /// The router enrolled on the warp route for the remote domain, with its decimals.
+    pub fn with_remote_router(mut self, remote_router: Bits256, remote_decimals: u8) -> Self {
+        self.remote_router = remote_router;
+        self.remote_decimals = remote_decimals;
+        self
+    }
+
+    pub fn with_token_decimals(mut self, token_decimals: u8) -> Self {
+        self.token_decimals = token_decimals;
+        self
+    }
+
+    pub fn with_ism(mut self, ism: IsmConfig) -> Self {
+        self.ism = ism;
+        self
+    }
+
+    pub fn with_default_hook(mut self, hook: HookConfig) -> Self {
+        self.default_hook = hook;
+        self
+    }
+
+    pub fn with_required_hook(mut self, hook: HookConfig) -> Self {
+        self.required_hook = hook;
+        self
+    }
+
+    pub fn with_warp_route(mut self, warp_route: WarpRouteConfig) -> Self {
+        self.warp_route = Some(warp_route);
+        self
+    }
+}
@@ -0,0 +1,262 @@
+use fuels::{
+    prelude::*,
+    programs::{
+        calls::{CallParameters, Execution},
+        responses::CallResponse,
+    },
+    types::{transaction_builders::VariableOutputPolicy, Bits256, ContractId},
+};
+use hyperlane_core::HyperlaneMessage;
+
+use super::{abis::DispatchEvent, *};
+
+/// Domain of the second chain of the simulator, `fue2`.
+pub const SECOND_LOCAL_DOMAIN: u32 = 0x66756532;
+
+/// One of the two chains of the simulator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chain {
+    A,
+    B,
+}
+
+impl Chain {
+    pub fn counterpart(self) -> Self {
+        match self {
+            Chain::A => Chain::B,
+            Chain::B => Chain::A,
+        }
+    }
+}
+
+/// What `Simulator::launch` deploys on each chain.
+///
+/// The remote domain of each chain is always the local domain of the other one.
+#[derive(Debug, Clone)]
+pub struct SimulatorConfig {
+    pub chain_a: TopologyConfig,
+    pub chain_b: TopologyConfig,
+    /// Deploys each chain on its own node instead of sharing one.
+    pub separate_nodes: bool,
+}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        Self {
+            chain_a: TopologyConfig::default(),
+            chain_b: TopologyConfig::default().with_local_domain(SECOND_LOCAL_DOMAIN),
+            separate_nodes: false,
+        }
+    }
+}
+
+impl SimulatorConfig {
+    pub fn with_chain_a(mut self, config: TopologyConfig) -> Self {
+        self.chain_a = config;
+        self
+    }
+
+    pub fn with_chain_b(mut self, config: TopologyConfig) -> Self {
+        self.chain_b = config;
+        self
+    }
+
+    /// Warp routes enrolled as each other's remote router.
+    pub fn with_warp_routes(mut self, chain_a: WarpRouteConfig, chain_b: WarpRouteConfig) -> Self {
+        self.chain_a.warp_route = Some(chain_a);
+        self.chain_b.warp_route = Some(chain_b);
+        self
+    }
+
+    pub fn with_separate_nodes(mut self) -> Self {
+        self.separate_nodes = true;
+        self
+    }
+}
+
+/// Two Fuel Hyperlane deployments with different local domains, pointed at each other,
+/// which relays messages between them like the relayer agent does.
+pub struct Simulator {
+    pub chain_a: Topology,
+    pub chain_b: Topology,
+}
+
+impl Simulator {
+    pub async fn launch(config: SimulatorConfig) -> Self {
+        let SimulatorConfig {
+            mut chain_a,
+            mut chain_b,
+            separate_nodes,
+        } = config;
+
+        chain_a.remote_domain = chain_b.local_domain;
+        chain_b.remote_domain = chain_a.local_domain;
+        // The warp routes are enrolled once both are deployed
+        let warp_route_a = chain_a.warp_route.take();
+        let warp_route_b = chain_b.warp_route.take();
+
+        let (mut chain_a, mut chain_b) = if separate_nodes {
+            (
+                Topology::launch(chain_a).await,
+                Topology::launch(chain_b).await,
+            )
+        } else {
+            let chain_a = Topology::launch(chain_a).await;
+            let chain_b = Topology::deploy(chain_a.wallet.clone(), chain_b).await;
+            (chain_a, chain_b)
+        };
+
+        if let (Some(mode_a), Some(mode_b)) = (warp_route_a, warp_route_b) {
+            chain_a.add_warp_route(mode_a).await;
+            chain_b.add_warp_route(mode_b).await;
+
+            let (router_a, decimals_a) = warp_router(&chain_a).await;
+            let (router_b, decimals_b) = warp_router(&chain_b).await;
+            chain_a
+                .enroll_remote_router(chain_b.config.local_domain, router_b, decimals_b)
+                .await;
+            chain_b
+                .enroll_remote_router(chain_a.config.local_domain, router_a, decimals_a)
+                .await;
+        }
+
+        Self { chain_a, chain_b }
+    }
+
+    pub fn chain(&self, chain: Chain) -> &Topology {
+        match chain {
+            Chain::A => &self.chain_a,
+            Chain::B => &self.chain_b,
+        }
+    }
+
+    /// The chain with `domain` as its local domain.
+    pub fn chain_by_domain(&self, domain: u32) -> Option<&Topology> {
+        [&self.chain_a, &self.chain_b]
+            .into_iter()
+            .find(|topology| topology.config.local_domain == domain)
+    }
+
+    /// Dispatches a message from `from` to `recipient` on the other chain.
+    ///
+    /// ### Returns
+    ///
+    /// * The dispatched messages, to be passed to `relay`.
+    pub async fn dispatch(
+        &self,
+        from: Chain,
+        recipient: ContractId,
+        body: Vec<u8>,
+    ) -> Result<Vec<HyperlaneMessage>> {
+        let origin = self.chain(from);
+        let destination = self.chain(from.counterpart());
+
+        let response = origin
+            .dispatch_to(destination.config.local_domain, Bits256(*recipient), body)
+            .await?;
+        dispatched_messages(&response)
+    }
+
+    /// Transfers `amount` of the warp route asset of `from` to `recipient` on the other chain,
+    /// paying the gas quote of the warp route in the base asset.
+    ///
+    /// ### Returns
+    ///
+    /// * The dispatched messages, to be passed to `relay`.
+    pub async fn transfer_remote(
+        &self,
+        from: Chain,
+        recipient: Bits256,
+        amount: u64,
+    ) -> Result<Vec<HyperlaneMessage>> {
+        let origin = self.chain(from);
+        let destination = self.chain(from.counterpart()).config.local_domain;
+        let warp_route = origin
+            .warp_route
+            .as_ref()
+            .expect("Simulator has no warp routes.");
+
+        let quote = warp_route
+            .instance
+            .methods()
+            .quote_gas_payment(destination)
+            .with_contract_ids(&origin.contract_ids())
+            .simulate(Execution::StateReadOnly)
+            .await?
+            .value;
+
+        // Native transfers are paid with the quote, the other modes require the asset
+        // to be sent to the warp route beforehand
+        let payment = match warp_route.mode {
+            WarpRouteConfig::Native => amount + quote,
+            WarpRouteConfig::Collateral | WarpRouteConfig::Synthetic => {
+                origin
+                    .wallet
+                    .force_transfer_to_contract(
+                        warp_route.instance.contract_id(),
+                        amount,
+                        warp_route.asset_id,
+                        TxPolicies::default(),
+                    )
+                    .await?;
+                quote
+            }
+        };
+
+        let response = warp_route
+            .instance
+            .methods()
+            .transfer_remote(destination, recipient, amount, None, None)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(payment)
+                    .with_asset_id(AssetId::BASE),
+            )?
+            .with_contracts(&[&origin.mailbox])
+            .with_contract_ids(&origin.contract_ids())
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+            .call()
+            .await?;
+        dispatched_messages(&response)
+    }
+
+    /// Processes the messages on their destination chain, with metadata
+    /// generated for the ISM of that chain.
+    pub async fn relay(&self, messages: &[HyperlaneMessage]) -> Result<()> {
+        for message in messages {
+            let destination = self
+                .chain_by_domain(message.destination)
+                .expect("Message destination is not a simulated chain.");
+            destination.process(message).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the messages dispatched in a call, which has to know the Mailbox logs,
+/// e.g. through `with_contracts(&[&mailbox])` when the Mailbox is called by another contract.
+pub fn dispatched_messages<T>(response: &CallResponse<T>) -> Result<Vec<HyperlaneMessage>> {
+    Ok(response
+        .decode_logs_with_type::<DispatchEvent>()?
+        .into_iter()
+        .map(|event| HyperlaneMessage::from(event.message.bytes.0))
+        .collect())
+}
+
+/// The warp route of `topology` as a remote router, with the decimals of its token.
+async fn warp_router(topology: &Topology) -> (Bits256, u8) {
+    let warp_route = &topology.warp_route.as_ref().unwrap().instance;
+    let decimals = warp_route
+        .methods()
+        .get_token_info()
+        .simulate(Execution::StateReadOnly)
+        .await
+        .expect("Failed to read warp route token info.")
+        .value
+        .decimals;
+
+    (
+        Bits256(ContractId::from(warp_route.contract_id()).into()),
+        decimals,
+    )
+}
@@ -0,0 +1,681 @@
+use fuels::{
+    prelude::*,
+    programs::{
+        calls::{CallParameters, Execution},
+        responses::CallResponse,
+    },
+    types::{
+        transaction_builders::VariableOutputPolicy, Bits256, Bytes, ContractId, Identity, Salt,
+    },
+};
+use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage, H256};
+use rand::{thread_rng, Rng};
+
+use super::{abis::*, *};
+use crate::{
+    checkpoint::{Checkpoint, ValidatorSet},
+    ism_metadata::{MerkleRootMultisigMetadata, MessageIdMultisigMetadata},
+    merkle::MerkleTree,
+};
+
+/// Same as the gas payment config of the E2E deployment.
+const TOKEN_EXCHANGE_RATE_SCALE: u64 = 15_000_000_000_000;
+const DEFAULT_GAS_AMOUNT: u64 = 5000;
+const GAS_OVERHEAD: u64 = 151966;
+
+/// Base and collateral asset amount of the launched wallet.
+const LAUNCH_COIN_AMOUNT: u64 = 10 * 10u64.pow(18);
+
+/// The ISM of a topology, typed by `IsmConfig`.
+pub enum IsmHandle {
+    Test(TestIsm<WalletUnlocked>),
+    MessageIdMultisig(MessageIdMultisigISM<WalletUnlocked>),
+    MerkleRootMultisig(MerkleRootMultisigISM<WalletUnlocked>),
+}
+
+impl IsmHandle {
+    pub fn contract_id(&self) -> ContractId {
+        match self {
+            IsmHandle::Test(ism) => ism.contract_id().into(),
+            IsmHandle::MessageIdMultisig(ism) => ism.contract_id().into(),
+            IsmHandle::MerkleRootMultisig(ism) => ism.contract_id().into(),
+        }
+    }
+}
+
+/// The warp route of a topology and the asset it transfers.
+pub struct WarpRouteFixture {
+    pub instance: WarpRoute<WalletUnlocked>,
+    pub mode: WarpRouteConfig,
+    pub asset_id: AssetId,
+    /// The token backing a collateral warp route.
+    pub collateral_token: Option<SRC20Test<WalletUnlocked>>,
+}
+
+/// A complete local Hyperlane deployment, wired up and initialized.
+///
+/// Every hook is deployed regardless of the config, only the Mailbox
+/// configuration changes, so tests can inspect any of them.
+pub struct Topology {
+    pub config: TopologyConfig,
+    /// Owner of every contract.
+    pub wallet: WalletUnlocked,
+    pub mailbox: Mailbox<WalletUnlocked>,
+    pub mock_hook: PostDispatchMock<WalletUnlocked>,
+    pub merkle_tree_hook: MerkleTreeHook<WalletUnlocked>,
+    pub igp: InterchainGasPaymaster<WalletUnlocked>,
+    pub gas_oracle: GasOracle<WalletUnlocked>,
+    pub aggregation_hook: AggregationHook<WalletUnlocked>,
+    pub ism: IsmHandle,
+    /// Validators of the multisig ISM, empty for the test ISM.
+    pub validators: ValidatorSet,
+    pub recipient: TestRecipient<WalletUnlocked>,
+    pub warp_route: Option<WarpRouteFixture>,
+}
+
+impl Topology {
+    /// Launches a local node with a single wallet funded with the base and collateral
+    /// assets, and deploys the topology with it.
+    pub async fn launch(config: TopologyConfig) -> Self {
+        let mut wallets = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new_multiple_assets(
+                1,
+                vec![
+                    AssetConfig {
+                        id: AssetId::BASE,
+                        num_coins: 1,
+                        coin_amount: LAUNCH_COIN_AMOUNT,
+                    },
+                    AssetConfig {
+                        id: COLLATERAL_ASSET,
+                        num_coins: 1,
+                        coin_amount: LAUNCH_COIN_AMOUNT,
+                    },
+                ],
+            ),
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to launch local node.");
+
+        Self::deploy(wallets.pop().unwrap(), config).await
+    }
+
+    /// Deploys the topology with `wallet`, which becomes the owner of every contract.
+    /// Contracts are deployed with random salts, so multiple topologies can share a node.
+    pub async fn deploy(wallet: WalletUnlocked, config: TopologyConfig) -> Self {
+        let wallet_bits = Bits256(wallet.address().hash().into());
+
+        let mailbox_configurables = MailboxConfigurables::default()
+            .with_LOCAL_DOMAIN(config.local_domain)
+            .unwrap()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+        let mailbox_id = deploy(
+            "contracts/mailbox/out/debug/mailbox.bin",
+            deployment_config().with_configurables(mailbox_configurables),
+            &wallet,
+        )
+        .await;
+
+        let mock_hook_id = deploy(
+            "contracts/mocks/mock-post-dispatch/out/debug/mock-post-dispatch.bin",
+            deployment_config(),
+            &wallet,
+        )
+        .await;
+
+        let merkle_configurables = MerkleTreeHookConfigurables::default()
+            .with_EXPECTED_INITIALIZER(wallet_bits)
+            .unwrap();
+        let merkle_tree_hook_id = deploy(
+            "contracts/hooks/merkle-tree-hook/out/debug/merkle-tree-hook.bin",
+            deployment_config().with_configurables(merkle_configurables),
+            &wallet,
+        )
+        .await;
+
+        let gas_oracle_configurables = GasOracleConfigurables::default()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+        let gas_oracle_id = deploy(
+            "contracts/gas-oracle/out/debug/gas-oracle.bin",
+            deployment_config().with_configurables(gas_oracle_configurables),
+            &wallet,
+        )
+        .await;
+
+        let igp_configurables = InterchainGasPaymasterConfigurables::default()
+            .with_TOKEN_EXCHANGE_RATE_SCALE(TOKEN_EXCHANGE_RATE_SCALE)
+            .unwrap()
+            .with_DEFAULT_GAS_AMOUNT(DEFAULT_GAS_AMOUNT)
+            .unwrap()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+        let igp_id = deploy(
+            "contracts/hooks/gas-paymaster/out/debug/gas-paymaster.bin",
+            deployment_config().with_configurables(igp_configurables),
+            &wallet,
+        )
+        .await;
+
+        let aggregation_configurables = AggregationHookConfigurables::default()
+            .with_EXPECTED_INITIALIZER(wallet_bits)
+            .unwrap();
+        let aggregation_hook_id = deploy(
+            "contracts/hooks/aggregation/out/debug/aggregation.bin",
+            deployment_config().with_configurables(aggregation_configurables),
+            &wallet,
+        )
+        .await;
+
+        let (ism, validators) = deploy_ism(config.ism, &wallet).await;
+
+        let recipient_id = deploy(
+            "contracts/test/msg-recipient-test/out/debug/msg-recipient-test.bin",
+            deployment_config(),
+            &wallet,
+        )
+        .await;
+
+        let mut topology = Self {
+            mailbox: Mailbox::new(mailbox_id, wallet.clone()),
+            mock_hook: PostDispatchMock::new(mock_hook_id, wallet.clone()),
+            merkle_tree_hook: MerkleTreeHook::new(merkle_tree_hook_id, wallet.clone()),
+            igp: InterchainGasPaymaster::new(igp_id, wallet.clone()),
+            gas_oracle: GasOracle::new(gas_oracle_id, wallet.clone()),
+            aggregation_hook: AggregationHook::new(aggregation_hook_id, wallet.clone()),
+            recipient: TestRecipient::new(recipient_id, wallet.clone()),
+            ism,
+            validators,
+            warp_route: None,
+            wallet,
+            config,
+        };
+
+        topology.initialize().await;
+        if let Some(mode) = topology.config.warp_route {
+            topology.add_warp_route(mode).await;
+            topology
+                .enroll_remote_router(
+                    topology.config.remote_domain,
+                    topology.config.remote_router,
+                    topology.config.remote_decimals,
+                )
+                .await;
+        }
+        topology
+    }
+
+    async fn initialize(&self) {
+        let owner = self.owner();
+
+        self.merkle_tree_hook
+            .methods()
+            .initialize(self.mailbox.contract_id().into())
+            .call()
+            .await
+            .expect("Failed to initialize Merkle Tree Hook.");
+
+        self.gas_oracle
+            .methods()
+            .initialize_ownership(owner)
+            .call()
+            .await
+            .expect("Failed to initialize Gas Oracle.");
+
+        self.gas_oracle
+            .methods()
+            .set_remote_gas_data_configs(vec![RemoteGasDataConfig {
+                domain: self.config.remote_domain,
+                remote_gas_data: RemoteGasData {
+                    domain: self.config.remote_domain,
+                    token_exchange_rate: 15000000000,
+                    gas_price: 37999464941,
+                    token_decimals: 18,
+                },
+            }])
+            .call()
+            .await
+            .expect("Failed to set gas data.");
+
+        self.igp
+            .methods()
+            .initialize(owner, owner)
+            .call()
+            .await
+            .expect("Failed to initialize IGP.");
+
+        self.igp
+            .methods()
+            .set_destination_gas_config(
+                vec![self.config.remote_domain],
+                vec![DomainGasConfig {
+                    gas_oracle: contract_bits(self.gas_oracle.id()),
+                    gas_overhead: GAS_OVERHEAD,
+                }],
+            )
+            .call()
+            .await
+            .expect("Failed to set destination gas config.");
+
+        self.aggregation_hook
+            .methods()
+            .initialize(vec![
+                self.merkle_tree_hook.contract_id().into(),
+                self.igp.contract_id().into(),
+            ])
+            .call()
+            .await
+            .expect("Failed to initialize Aggregation Hook.");
+
+        if !self.validators.signers.is_empty() {
+            let validators = self.validators.addresses();
+            let initialized = match &self.ism {
+                IsmHandle::MessageIdMultisig(ism) => ism
+                    .methods()
+                    .initialize(validators)
+                    .call()
+                    .await
+                    .map(|_| ()),
+                IsmHandle::MerkleRootMultisig(ism) => ism
+                    .methods()
+                    .initialize(validators)
+                    .call()
+                    .await
+                    .map(|_| ()),
+                IsmHandle::Test(_) => Ok(()),
+            };
+            initialized.expect("Failed to initialize Multisig ISM.");
+        }
+
+        self.mailbox
+            .methods()
+            .initialize(
+                owner,
+                Bits256(self.ism.contract_id().into()),
+                Bits256(self.hook_id(self.config.default_hook).into()),
+                Bits256(self.hook_id(self.config.required_hook).into()),
+            )
+            .call()
+            .await
+            .expect("Failed to initialize Mailbox.");
+
+        self.recipient
+            .methods()
+            .set_ism(self.ism.contract_id())
+            .call()
+            .await
+            .expect("Failed to set ISM in Test Recipient.");
+    }
+
+    /// Deploys and initializes a warp route with the ISM and default hook of the topology,
+    /// without enrolling a remote router.
+    pub async fn add_warp_route(&mut self, mode: WarpRouteConfig) {
+        let wallet_bits = Bits256(self.wallet.address().hash().into());
+
+        let configurables = WarpRouteConfigurables::default()
+            .with_EXPECTED_OWNER(wallet_bits)
+            .unwrap();
+        let warp_route_id = deploy(
+            "contracts/warp-route/out/debug/warp-route.bin",
+            deployment_config().with_configurables(configurables),
+            &self.wallet,
+        )
+        .await;
+        let instance = WarpRoute::new(warp_route_id, self.wallet.clone());
+
+        let (token_mode, asset_id, collateral_token) = match mode {
+            WarpRouteConfig::Native => (WarpRouteTokenMode::NATIVE, AssetId::BASE, None),
+            WarpRouteConfig::Synthetic => (WarpRouteTokenMode::SYNTHETIC, AssetId::BASE, None),
+            WarpRouteConfig::Collateral => {
+                let token_id = deploy(
+                    "contracts/test/src20-test/out/debug/src20-test.bin",
+                    deployment_config(),
+                    &self.wallet,
+                )
+                .await;
+                let token = SRC20Test::new(token_id.clone(), self.wallet.clone());
+
+                token
+                    .methods()
+                    .mint(self.owner(), Some(Bits256::zeroed()), TOKEN_TOTAL_SUPPLY)
+                    .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+                    .call()
+                    .await
+                    .expect("Failed to mint collateral token.");
+
+                let asset_id = token_id.asset_id(&Bits256::zeroed());
+                (WarpRouteTokenMode::COLLATERAL, asset_id, Some(token))
+            }
+        };
+
+        let asset_contract_id = collateral_token
+            .as_ref()
+            .map(|token: &SRC20Test<WalletUnlocked>| contract_bits(token.id()));
+        let mut call_handler = instance
+            .methods()
+            .initialize(
+                self.owner(),
+                contract_bits(self.mailbox.id()),
+                token_mode,
+                Bits256(self.hook_id(self.config.default_hook).into()),
+                Bits256(self.ism.contract_id().into()),
+                Some(TOKEN_NAME.to_string()),
+                Some(TOKEN_SYMBOL.to_string()),
+                Some(self.config.token_decimals),
+                Some(TOKEN_TOTAL_SUPPLY),
+                Some(asset_id),
+                asset_contract_id,
+            )
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum);
+        if let Some(token) = &collateral_token {
+            call_handler = call_handler.with_contract_ids(&[token.id()]);
+        }
+        call_handler
+            .call()
+            .await
+            .expect("Failed to initialize Warp Route.");
+
+        // The synthetic asset is derived from the warp route id
+        let asset_id = instance
+            .methods()
+            .get_token_info()
+            .simulate(Execution::StateReadOnly)
+            .await
+            .expect("Failed to read warp route token info.")
+            .value
+            .asset_id;
+
+        self.config.warp_route = Some(mode);
+        self.warp_route = Some(WarpRouteFixture {
+            instance,
+            mode,
+            asset_id,
+            collateral_token,
+        });
+    }
+
+    /// Enrolls `router` on the warp route for `domain`, with the decimals of its token.
+    pub async fn enroll_remote_router(&self, domain: u32, router: Bits256, decimals: u8) {
+        let warp_route = &self
+            .warp_route
+            .as_ref()
+            .expect("Topology has no warp route.")
+            .instance;
+
+        warp_route
+            .methods()
+            .enroll_remote_router(domain, router)
+            .call()
+            .await
+            .expect("Failed to enroll remote router.");
+
+        warp_route
+            .methods()
+            .set_remote_router_decimals(router, decimals)
+            .call()
+            .await
+            .expect("Failed to set remote router decimals.");
+    }
+
+    pub fn owner(&self) -> Identity {
+        Identity::Address(Address::from(self.wallet.address()))
+    }
+
+    /// The contract deployed for the hook config.
+    pub fn hook_id(&self, hook: HookConfig) -> ContractId {
+        match hook {
+            HookConfig::Mock => self.mock_hook.contract_id().into(),
+            HookConfig::MerkleTree => self.merkle_tree_hook.contract_id().into(),
+            HookConfig::Igp => self.igp.contract_id().into(),
+            HookConfig::MerkleTreeAndIgp => self.aggregation_hook.contract_id().into(),
+        }
+    }
+
+    /// Every contract of the topology, for calls which go through several of them.
+    pub fn contract_ids(&self) -> Vec<Bech32ContractId> {
+        let mut ids = vec![
+            self.mailbox.id(),
+            self.mock_hook.id(),
+            self.merkle_tree_hook.id(),
+            self.igp.id(),
+            self.gas_oracle.id(),
+            self.aggregation_hook.id(),
+            self.recipient.id(),
+            Bech32ContractId::from(self.ism.contract_id()),
+        ];
+        if let Some(warp_route) = &self.warp_route {
+            ids.push(warp_route.instance.id());
+            if let Some(token) = &warp_route.collateral_token {
+                ids.push(token.id());
+            }
+        }
+        ids
+    }
+
+    /// Quotes and dispatches a message to the remote router on the remote domain,
+    /// going through the default and required hooks.
+    ///
+    /// ### Returns
+    ///
+    /// * [Bits256] - The ID of the dispatched message.
+    pub async fn dispatch(&self, body: Vec<u8>) -> Result<Bits256> {
+        let response = self
+            .dispatch_to(self.config.remote_domain, self.config.remote_router, body)
+            .await?;
+        Ok(response.value)
+    }
+
+    /// Quotes and dispatches a message to `recipient` on `destination`,
+    /// paying the quote in the base asset.
+    ///
+    /// ### Returns
+    ///
+    /// * The response of the Mailbox call, which decodes the `DispatchEvent`.
+    pub async fn dispatch_to(
+        &self,
+        destination: u32,
+        recipient: Bits256,
+        body: Vec<u8>,
+    ) -> Result<CallResponse<Bits256>> {
+        let quote = self
+            .mailbox
+            .methods()
+            .quote_dispatch(
+                destination,
+                recipient,
+                Bytes(body.clone()),
+                Bytes(vec![]),
+                ContractId::default(),
+            )
+            .with_contract_ids(&self.contract_ids())
+            .simulate(Execution::StateReadOnly)
+            .await?
+            .value;
+
+        self.mailbox
+            .methods()
+            .dispatch(
+                destination,
+                recipient,
+                Bytes(body),
+                Bytes(vec![]),
+                ContractId::default(),
+            )
+            .call_params(
+                CallParameters::default()
+                    .with_amount(quote)
+                    .with_asset_id(AssetId::BASE),
+            )?
+            .with_contract_ids(&self.contract_ids())
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+            .call()
+            .await
+    }
+
+    /// A message from the remote router on the remote domain to `recipient`.
+    pub fn inbound_message(&self, recipient: ContractId, body: Vec<u8>) -> HyperlaneMessage {
+        HyperlaneMessage {
+            version: 3,
+            nonce: thread_rng().gen(),
+            origin: self.config.remote_domain,
+            sender: H256(self.config.remote_router.0),
+            destination: self.config.local_domain,
+            recipient: H256(*recipient),
+            body,
+        }
+    }
+
+    /// Metadata which satisfies the ISM of the topology for `message`.
+    ///
+    /// The multisig metadata is signed by the threshold of validators over
+    /// a checkpoint of a tree only containing the message.
+    pub fn process_metadata(&self, message: &HyperlaneMessage) -> Vec<u8> {
+        let message_id = message.id();
+        let origin_merkle_tree_hook = H256::repeat_byte(0x11);
+
+        let tree = MerkleTree::from_leaves([message_id]).unwrap();
+        let checkpoint = Checkpoint {
+            merkle_tree_hook_address: origin_merkle_tree_hook,
+            mailbox_domain: message.origin,
+            root: tree.root(),
+            index: 0,
+        }
+        .with_message_id(message_id);
+
+        match (&self.ism, self.config.ism) {
+            (IsmHandle::Test(_), _) => vec![],
+            (IsmHandle::MessageIdMultisig(_), IsmConfig::MessageIdMultisig { threshold, .. }) => {
+                self.validators
+                    .sign_threshold(&checkpoint, threshold as usize)
+                    .into_iter()
+                    .fold(
+                        MessageIdMultisigMetadata::new(
+                            origin_merkle_tree_hook,
+                            checkpoint.checkpoint.root,
+                            checkpoint.checkpoint.index,
+                        ),
+                        |metadata, signature| metadata.with_signature(signature),
+                    )
+                    .encode()
+            }
+            (IsmHandle::MerkleRootMultisig(_), IsmConfig::MerkleRootMultisig { threshold, .. }) => {
+                self.validators
+                    .sign_threshold(&checkpoint, threshold as usize)
+                    .into_iter()
+                    .fold(
+                        MerkleRootMultisigMetadata::from_proof(
+                            origin_merkle_tree_hook,
+                            &tree.proof(0).unwrap(),
+                            message_id,
+                            0,
+                        ),
+                        |metadata, signature| metadata.with_signature(signature),
+                    )
+                    .encode()
+            }
+            _ => unreachable!("ISM handle always matches the ISM config"),
+        }
+    }
+
+    /// Processes an inbound message with metadata from `process_metadata`.
+    pub async fn process(&self, message: &HyperlaneMessage) -> Result<CallResponse<()>> {
+        self.mailbox
+            .methods()
+            .process(
+                Bytes(self.process_metadata(message)),
+                Bytes(RawHyperlaneMessage::from(message)),
+            )
+            .with_contract_ids(&self.contract_ids())
+            .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+            .call()
+            .await
+    }
+}
+
+async fn deploy_ism(config: IsmConfig, wallet: &WalletUnlocked) -> (IsmHandle, ValidatorSet) {
+    let wallet_bits = Bits256(wallet.address().hash().into());
+
+    match config {
+        IsmConfig::Test => {
+            let id = deploy(
+                "contracts/test/ism-test/out/debug/ism-test.bin",
+                deployment_config(),
+                wallet,
+            )
+            .await;
+            (
+                IsmHandle::Test(TestIsm::new(id, wallet.clone())),
+                ValidatorSet::new(vec![]),
+            )
+        }
+        IsmConfig::MessageIdMultisig {
+            validators,
+            threshold,
+        } => {
+            let configurables = MessageIdMultisigISMConfigurables::default()
+                .with_THRESHOLD(threshold)
+                .unwrap()
+                .with_EXPECTED_INITIALIZER(wallet_bits)
+                .unwrap();
+            let id = deploy(
+                "contracts/ism/multisig/message-id-multisig-ism/out/debug/message-id-multisig-ism.bin",
+                deployment_config().with_configurables(configurables),
+                wallet,
+            )
+            .await;
+            (
+                IsmHandle::MessageIdMultisig(MessageIdMultisigISM::new(id, wallet.clone())),
+                ValidatorSet::random(validators),
+            )
+        }
+        IsmConfig::MerkleRootMultisig {
+            validators,
+            threshold,
+        } => {
+            let configurables = MerkleRootMultisigISMConfigurables::default()
+                .with_THRESHOLD(threshold)
+                .unwrap()
+                .with_EXPECTED_INITIALIZER(wallet_bits)
+                .unwrap();
+            let id = deploy(
+                "contracts/ism/multisig/merkle-root-multisig-ism/out/debug/merkle-root-multisig-ism.bin",
+                deployment_config().with_configurables(configurables),
+                wallet,
+            )
+            .await;
+            (
+                IsmHandle::MerkleRootMultisig(MerkleRootMultisigISM::new(id, wallet.clone())),
+                ValidatorSet::random(validators),
+            )
+        }
+    }
+}
+
+/// Deploys a binary relative to the repository root, so the fixtures work
+/// regardless of the directory the tests are run from.
+async fn deploy(
+    binary_filepath: &str,
+    config: LoadConfiguration,
+    wallet: &WalletUnlocked,
+) -> Bech32ContractId {
+    let path = format!("{}/../{}", env!("CARGO_MANIFEST_DIR"), binary_filepath);
+
+    Contract::load_from(&path, config)
+        .unwrap_or_else(|e| panic!("Failed to load {}: {:?}", binary_filepath, e))
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap_or_else(|e| panic!("Failed to deploy {}: {:?}", binary_filepath, e))
+}
+
+fn deployment_config() -> LoadConfiguration {
+    let mut bytes = [0u8; 32];
+    thread_rng().fill(&mut bytes[..]);
+    LoadConfiguration::default().with_salt(Salt::new(bytes))
+}
+
+fn contract_bits(id: Bech32ContractId) -> Bits256 {
+    Bits256(ContractId::from(id).into())
+}
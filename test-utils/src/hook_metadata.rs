@@ -0,0 +1,192 @@
+//! Rust counterpart of `StandardHookMetadata` in `contracts/libs/std-hook-metadata`.
+
+use hyperlane_core::{H256, U256};
+
+/// Byte offsets of metadata properties
+pub const VARIANT_OFFSET: usize = 0;
+pub const MSG_VALUE_OFFSET: usize = 2;
+pub const GAS_LIMIT_OFFSET: usize = 34;
+pub const REFUND_ADDRESS_OFFSET: usize = 66;
+pub const MIN_METADATA_LENGTH: usize = 98;
+/// Standard variant value
+pub const DEFAULT_VARIANT: u16 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookMetadataError {
+    /// The metadata is neither empty nor at least `MIN_METADATA_LENGTH` bytes long.
+    InvalidLength(usize),
+    /// The metadata variant is not `DEFAULT_VARIANT`.
+    InvalidVariant(u16),
+}
+
+/// Metadata passed to the hooks and read with `StandardHookMetadata` on the Sway side.
+///
+/// Byte layout:
+///   variant:         [0:2]
+///   msg_value:       [2:34]
+///   gas_limit:       [34:66]
+///   refund_address:  [66:98]
+///   custom_metadata: [98:????]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardHookMetadata {
+    pub variant: u16,
+    pub msg_value: U256,
+    pub gas_limit: U256,
+    pub refund_address: H256,
+    pub custom_metadata: Vec<u8>,
+}
+
+impl Default for StandardHookMetadata {
+    fn default() -> Self {
+        Self {
+            variant: DEFAULT_VARIANT,
+            msg_value: U256::zero(),
+            gas_limit: U256::zero(),
+            refund_address: H256::zero(),
+            custom_metadata: vec![],
+        }
+    }
+}
+
+impl StandardHookMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_msg_value(mut self, msg_value: impl Into<U256>) -> Self {
+        self.msg_value = msg_value.into();
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: impl Into<U256>) -> Self {
+        self.gas_limit = gas_limit.into();
+        self
+    }
+
+    /// The address refunded by the IGP, e.g. the `Address` of the sender wallet.
+    pub fn with_refund_address(mut self, refund_address: impl Into<H256>) -> Self {
+        self.refund_address = refund_address.into();
+        self
+    }
+
+    /// Appended after the standard fields, as read by `get_custom_metadata`.
+    pub fn with_custom_metadata(mut self, custom_metadata: Vec<u8>) -> Self {
+        self.custom_metadata = custom_metadata;
+        self
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MIN_METADATA_LENGTH + self.custom_metadata.len());
+        bytes.extend_from_slice(&self.variant.to_be_bytes());
+        bytes.extend_from_slice(&u256_to_bytes(self.msg_value));
+        bytes.extend_from_slice(&u256_to_bytes(self.gas_limit));
+        bytes.extend_from_slice(self.refund_address.as_bytes());
+        bytes.extend_from_slice(&self.custom_metadata);
+        bytes
+    }
+
+    /// Decodes the metadata the same way as `from_bytes` in the Sway library,
+    /// fields which do not fit in `bytes` are left as zero.
+    /// Use `decode` to reject metadata which `is_valid` rejects.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let read_u256 = |offset: usize| match bytes.get(offset..offset + 32) {
+            Some(slice) => U256::from_big_endian(slice),
+            None => U256::zero(),
+        };
+
+        Self {
+            variant: get_variant(bytes),
+            msg_value: read_u256(MSG_VALUE_OFFSET),
+            gas_limit: read_u256(GAS_LIMIT_OFFSET),
+            refund_address: bytes
+                .get(REFUND_ADDRESS_OFFSET..REFUND_ADDRESS_OFFSET + 32)
+                .map(H256::from_slice)
+                .unwrap_or_default(),
+            custom_metadata: get_custom_metadata(bytes).to_vec(),
+        }
+    }
+
+    /// Decodes non-empty metadata, failing on metadata rejected by `is_valid`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, HookMetadataError> {
+        validate(bytes)?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Same as `format_metadata` in the Sway library.
+    ///
+    /// Note that the Sway function ABI encodes the custom metadata, so it is prefixed
+    /// with its length as a big endian `u64`, which `get_custom_metadata` does not strip.
+    pub fn format_metadata(
+        msg_value: impl Into<U256>,
+        gas_limit: impl Into<U256>,
+        refund_address: impl Into<H256>,
+        custom_metadata: &[u8],
+    ) -> Vec<u8> {
+        let mut abi_encoded_custom_metadata = Vec::with_capacity(8 + custom_metadata.len());
+        abi_encoded_custom_metadata
+            .extend_from_slice(&(custom_metadata.len() as u64).to_be_bytes());
+        abi_encoded_custom_metadata.extend_from_slice(custom_metadata);
+
+        Self::new()
+            .with_msg_value(msg_value)
+            .with_gas_limit(gas_limit)
+            .with_refund_address(refund_address)
+            .with_custom_metadata(abi_encoded_custom_metadata)
+            .encode()
+    }
+
+    /// Same as `override_gas_limit` in the Sway library, where the refund address is the sender.
+    pub fn override_gas_limit(gas_limit: impl Into<U256>, sender: impl Into<H256>) -> Vec<u8> {
+        Self::format_metadata(0, gas_limit, sender, &[])
+    }
+
+    /// Same as `override_msg_value` in the Sway library, where the refund address is the sender.
+    pub fn override_msg_value(msg_value: impl Into<U256>, sender: impl Into<H256>) -> Vec<u8> {
+        Self::format_metadata(msg_value, 0, sender, &[])
+    }
+
+    /// Same as `override_refund_address` in the Sway library.
+    pub fn override_refund_address(refund_address: impl Into<H256>) -> Vec<u8> {
+        Self::format_metadata(0, 0, refund_address, &[])
+    }
+}
+
+/// Same as `get_variant` in the Sway library, `0` if the metadata is too short.
+pub fn get_variant(bytes: &[u8]) -> u16 {
+    match bytes.get(VARIANT_OFFSET..VARIANT_OFFSET + 2) {
+        Some(slice) => u16::from_be_bytes([slice[0], slice[1]]),
+        None => 0,
+    }
+}
+
+/// Same as `get_custom_metadata` in the Sway library, empty if the metadata is too short.
+pub fn get_custom_metadata(bytes: &[u8]) -> &[u8] {
+    bytes.get(MIN_METADATA_LENGTH..).unwrap_or_default()
+}
+
+/// Same as `is_valid` in the Sway library.
+pub fn is_valid(bytes: &[u8]) -> bool {
+    validate(bytes).is_ok()
+}
+
+/// Same checks as `is_valid` in the Sway library, returning why the metadata is invalid.
+/// Empty metadata is valid.
+pub fn validate(bytes: &[u8]) -> Result<(), HookMetadataError> {
+    if bytes.is_empty() {
+        return Ok(());
+    }
+    if bytes.len() < MIN_METADATA_LENGTH {
+        return Err(HookMetadataError::InvalidLength(bytes.len()));
+    }
+    let variant = get_variant(bytes);
+    if variant != DEFAULT_VARIANT {
+        return Err(HookMetadataError::InvalidVariant(variant));
+    }
+    Ok(())
+}
+
+fn u256_to_bytes(value: U256) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    bytes
+}
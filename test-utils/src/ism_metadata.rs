@@ -0,0 +1,349 @@
+//! Builders and parsers for the metadata formats read by the ISMs,
+//! mirroring the libraries in `contracts/ism/libs`.
+
+use hyperlane_core::{accumulator::merkle::Proof, Signature as HyperlaneSignature, H256};
+
+pub const SIGNATURE_LENGTH: usize = 65;
+pub const MERKLE_PROOF_DEPTH: usize = 32;
+
+/// Size of a single `(start, end)` range in the aggregation metadata header.
+const RANGE_SIZE: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataError {
+    /// The metadata is shorter than the fixed part of the format.
+    TooShort { expected: usize, actual: usize },
+    /// The signatures section is not a multiple of `SIGNATURE_LENGTH`.
+    InvalidSignaturesLength(usize),
+    /// An aggregation range points outside of the metadata or ends before it starts.
+    InvalidRange { index: usize, start: u32, end: u32 },
+}
+
+/// Packs a `hyperlane_core` signature as `r || s || v`, the layout read by `signature_at`.
+pub fn signature_to_bytes(signature: &HyperlaneSignature) -> [u8; SIGNATURE_LENGTH] {
+    let mut bytes = [0u8; SIGNATURE_LENGTH];
+    signature.r.to_big_endian(&mut bytes[0..32]);
+    signature.s.to_big_endian(&mut bytes[32..64]);
+    bytes[64] = signature.v as u8;
+    bytes
+}
+
+// ----------------------------------------------------------------------------
+// Message Id Multisig ISM Metadata
+
+/// Metadata of the `MessageIdMultisigISM`.
+///
+/// origin_merkle_tree_hook: [0:32]
+/// root:                    [32:64]
+/// index:                   [64:68]
+/// signatures:              [68:68 + 65 * n]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageIdMultisigMetadata {
+    pub origin_merkle_tree_hook: H256,
+    pub root: H256,
+    pub index: u32,
+    pub signatures: Vec<[u8; SIGNATURE_LENGTH]>,
+}
+
+impl MessageIdMultisigMetadata {
+    const SIGNATURES_OFFSET: usize = 68;
+
+    pub fn new(origin_merkle_tree_hook: H256, root: H256, index: u32) -> Self {
+        Self {
+            origin_merkle_tree_hook,
+            root,
+            index,
+            signatures: vec![],
+        }
+    }
+
+    /// Signatures have to be added in the same order as the validators are stored in the ISM.
+    pub fn with_signature(mut self, signature: [u8; SIGNATURE_LENGTH]) -> Self {
+        self.signatures.push(signature);
+        self
+    }
+
+    pub fn with_signatures(mut self, signatures: &[HyperlaneSignature]) -> Self {
+        self.signatures
+            .extend(signatures.iter().map(signature_to_bytes));
+        self
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(Self::SIGNATURES_OFFSET + self.signatures.len() * SIGNATURE_LENGTH);
+        bytes.extend_from_slice(self.origin_merkle_tree_hook.as_bytes());
+        bytes.extend_from_slice(self.root.as_bytes());
+        bytes.extend_from_slice(&self.index.to_be_bytes());
+        for signature in self.signatures.iter() {
+            bytes.extend_from_slice(signature);
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, MetadataError> {
+        check_length(bytes, Self::SIGNATURES_OFFSET)?;
+
+        Ok(Self {
+            origin_merkle_tree_hook: H256::from_slice(&bytes[0..32]),
+            root: H256::from_slice(&bytes[32..64]),
+            index: read_u32(bytes, 64),
+            signatures: decode_signatures(&bytes[Self::SIGNATURES_OFFSET..])?,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Merkle Root Multisig ISM Metadata
+
+/// Metadata of the `MerkleRootMultisigISM`.
+///
+/// origin_merkle_tree_hook: [0:32]
+/// message_index:           [32:36]
+/// signed_message_id:       [36:68]
+/// proof:                   [68:1092]
+/// signed_index:            [1092:1096]
+/// signatures:              [1096:1096 + 65 * n]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleRootMultisigMetadata {
+    pub origin_merkle_tree_hook: H256,
+    pub message_index: u32,
+    pub signed_message_id: H256,
+    pub proof: [H256; MERKLE_PROOF_DEPTH],
+    pub signed_index: u32,
+    pub signatures: Vec<[u8; SIGNATURE_LENGTH]>,
+}
+
+impl MerkleRootMultisigMetadata {
+    const PROOF_OFFSET: usize = 68;
+    const SIGNED_INDEX_OFFSET: usize = 1092;
+    const SIGNATURES_OFFSET: usize = 1096;
+
+    pub fn new(
+        origin_merkle_tree_hook: H256,
+        message_index: u32,
+        signed_message_id: H256,
+        proof: [H256; MERKLE_PROOF_DEPTH],
+        signed_index: u32,
+    ) -> Self {
+        Self {
+            origin_merkle_tree_hook,
+            message_index,
+            signed_message_id,
+            proof,
+            signed_index,
+            signatures: vec![],
+        }
+    }
+
+    /// Builds the metadata for the message proven by `proof`,
+    /// signed by the validators at the checkpoint `signed_index`.
+    pub fn from_proof(
+        origin_merkle_tree_hook: H256,
+        proof: &Proof,
+        signed_message_id: H256,
+        signed_index: u32,
+    ) -> Self {
+        Self::new(
+            origin_merkle_tree_hook,
+            proof.index as u32,
+            signed_message_id,
+            proof.path,
+            signed_index,
+        )
+    }
+
+    /// Signatures have to be added in the same order as the validators are stored in the ISM.
+    pub fn with_signature(mut self, signature: [u8; SIGNATURE_LENGTH]) -> Self {
+        self.signatures.push(signature);
+        self
+    }
+
+    pub fn with_signatures(mut self, signatures: &[HyperlaneSignature]) -> Self {
+        self.signatures
+            .extend(signatures.iter().map(signature_to_bytes));
+        self
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(Self::SIGNATURES_OFFSET + self.signatures.len() * SIGNATURE_LENGTH);
+        bytes.extend_from_slice(self.origin_merkle_tree_hook.as_bytes());
+        bytes.extend_from_slice(&self.message_index.to_be_bytes());
+        bytes.extend_from_slice(self.signed_message_id.as_bytes());
+        for node in self.proof.iter() {
+            bytes.extend_from_slice(node.as_bytes());
+        }
+        bytes.extend_from_slice(&self.signed_index.to_be_bytes());
+        for signature in self.signatures.iter() {
+            bytes.extend_from_slice(signature);
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, MetadataError> {
+        check_length(bytes, Self::SIGNATURES_OFFSET)?;
+
+        let mut proof = [H256::zero(); MERKLE_PROOF_DEPTH];
+        for (index, node) in proof.iter_mut().enumerate() {
+            let start = Self::PROOF_OFFSET + index * 32;
+            *node = H256::from_slice(&bytes[start..start + 32]);
+        }
+
+        Ok(Self {
+            origin_merkle_tree_hook: H256::from_slice(&bytes[0..32]),
+            message_index: read_u32(bytes, 32),
+            signed_message_id: H256::from_slice(&bytes[36..68]),
+            proof,
+            signed_index: read_u32(bytes, Self::SIGNED_INDEX_OFFSET),
+            signatures: decode_signatures(&bytes[Self::SIGNATURES_OFFSET..])?,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Aggregation ISM Metadata
+
+/// Metadata of the `AggregationISM`.
+///
+/// Starts with a `(start, end)` pair of big endian `u32` offsets for every module,
+/// followed by the metadata of each module. The offsets are relative to the start of
+/// the aggregation metadata and a start of `0` means no metadata is provided for the module.
+/// Nested aggregation metadata is added as a regular entry, its offsets stay relative to itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AggregationMetadata {
+    entries: Vec<Option<Vec<u8>>>,
+}
+
+impl AggregationMetadata {
+    /// Creates metadata for an aggregation of `module_count` modules, none of which have metadata yet.
+    pub fn new(module_count: usize) -> Self {
+        Self {
+            entries: vec![None; module_count],
+        }
+    }
+
+    /// Sets the metadata of the module at `index`, growing the module count if required.
+    pub fn with_metadata(mut self, index: usize, metadata: Vec<u8>) -> Self {
+        if index >= self.entries.len() {
+            self.entries.resize(index + 1, None);
+        }
+        self.entries[index] = Some(metadata);
+        self
+    }
+
+    pub fn module_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Same as `has_metadata` in the Sway library.
+    pub fn has_metadata(&self, index: usize) -> bool {
+        matches!(self.entries.get(index), Some(Some(_)))
+    }
+
+    /// Same as `metadata_at` in the Sway library, `None` if the module has no metadata.
+    pub fn metadata_at(&self, index: usize) -> Option<&[u8]> {
+        self.entries.get(index)?.as_deref()
+    }
+
+    /// The `(start, end)` offsets of every module, as written in the header.
+    pub fn ranges(&self) -> Vec<(u32, u32)> {
+        let mut offset = (self.entries.len() * RANGE_SIZE * 2) as u32;
+        self.entries
+            .iter()
+            .map(|entry| match entry {
+                Some(metadata) => {
+                    let start = offset;
+                    offset += metadata.len() as u32;
+                    (start, offset)
+                }
+                None => (0, 0),
+            })
+            .collect()
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        for (start, end) in self.ranges() {
+            bytes.extend_from_slice(&start.to_be_bytes());
+            bytes.extend_from_slice(&end.to_be_bytes());
+        }
+        for metadata in self.entries.iter().flatten() {
+            bytes.extend_from_slice(metadata);
+        }
+        bytes
+    }
+
+    /// Decodes the metadata of an aggregation of `module_count` modules.
+    pub fn decode(bytes: &[u8], module_count: usize) -> Result<Self, MetadataError> {
+        check_length(bytes, module_count * RANGE_SIZE * 2)?;
+
+        let mut entries = Vec::with_capacity(module_count);
+        for index in 0..module_count {
+            let start = read_u32(bytes, index * RANGE_SIZE * 2);
+            let end = read_u32(bytes, index * RANGE_SIZE * 2 + RANGE_SIZE);
+
+            if start == 0 {
+                entries.push(None);
+                continue;
+            }
+            if end < start || end as usize > bytes.len() {
+                return Err(MetadataError::InvalidRange { index, start, end });
+            }
+            entries.push(Some(bytes[start as usize..end as usize].to_vec()));
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Routing ISM Metadata
+
+/// Metadata of the `DomainRoutingISM` and `DefaultFallbackDomainRoutingISM`.
+///
+/// The routing ISMs pass the metadata unchanged to the module the message is routed to,
+/// so this only wraps the metadata of that module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutingMetadata(pub Vec<u8>);
+
+impl RoutingMetadata {
+    pub fn new(routed_metadata: Vec<u8>) -> Self {
+        Self(routed_metadata)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    pub fn decode(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Helpers
+
+fn check_length(bytes: &[u8], expected: usize) -> Result<(), MetadataError> {
+    if bytes.len() < expected {
+        return Err(MetadataError::TooShort {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok(())
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn decode_signatures(bytes: &[u8]) -> Result<Vec<[u8; SIGNATURE_LENGTH]>, MetadataError> {
+    if bytes.len() % SIGNATURE_LENGTH != 0 {
+        return Err(MetadataError::InvalidSignaturesLength(bytes.len()));
+    }
+    Ok(bytes
+        .chunks(SIGNATURE_LENGTH)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect())
+}
@@ -21,6 +21,12 @@ use hyperlane_core::{
 };
 // use hyperlane_ethereum::Signers;
 
+pub mod checkpoint;
+pub mod fixtures;
+pub mod hook_metadata;
+pub mod ism_metadata;
+pub mod merkle;
+
 fn hyperlane_to_ethers_u256(value: hyperlane_core::U256) -> ethers::types::U256 {
     let mut bytes = [0u8; 32];
     value.to_big_endian(&mut bytes);
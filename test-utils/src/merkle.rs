@@ -0,0 +1,210 @@
+//! Incremental merkle tree with the same layout as `StorageMerkleTree` in `contracts/libs/merkle`,
+//! which also keeps the ingested leaves to generate proofs.
+
+use ethers::utils::keccak256;
+use hyperlane_core::{accumulator::merkle::Proof, H256};
+
+/// The depth of the merkle tree.
+pub const TREE_DEPTH: usize = 32;
+
+/// The max number of leaves in the tree, (2 ** 32) - 1.
+pub const MAX_LEAVES: u32 = u32::MAX;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MerkleTreeError {
+    /// Same as `MerkleError::MerkleTreeFull` in the Sway library.
+    MerkleTreeFull,
+    /// The branch returned by `tree()` does not have `TREE_DEPTH` elements.
+    InvalidBranchLength(usize),
+    /// The leaf at `index` has not been ingested yet.
+    IndexOutOfBounds { index: u32, count: u32 },
+    /// The leaf at `index` was ingested before the tree was reconstructed from a branch.
+    UnknownLeaf(u32),
+}
+
+/// Keccak256 zero hashes, `zero_hashes()[i]` is the root of an empty subtree of depth `i`.
+pub fn zero_hashes() -> [H256; TREE_DEPTH] {
+    let mut zero_hashes = [H256::zero(); TREE_DEPTH];
+    for i in 1..TREE_DEPTH {
+        zero_hashes[i] = hash_pair(zero_hashes[i - 1], zero_hashes[i - 1]);
+    }
+    zero_hashes
+}
+
+/// Same as `StorageMerkleTree::branch_root` in the Sway library.
+pub fn branch_root(leaf: H256, branch: &[H256; TREE_DEPTH], index: u32) -> H256 {
+    let mut current = leaf;
+    for (i, next) in branch.iter().enumerate() {
+        if (index >> i) & 1 == 1 {
+            current = hash_pair(*next, current);
+        } else {
+            current = hash_pair(current, *next);
+        }
+    }
+    current
+}
+
+/// An incremental merkle tree mirroring `StorageMerkleTree`.
+///
+/// Proofs can be generated for every leaf ingested by this instance. A tree reconstructed
+/// from the branch and count returned by `MerkleTreeHook::tree()` only knows the leaves
+/// ingested after the reconstruction, as the branch does not contain the earlier leaves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    zero_hashes: [H256; TREE_DEPTH],
+    branch: [H256; TREE_DEPTH],
+    count: u32,
+    /// Branch and count the tree was reconstructed from.
+    base_branch: [H256; TREE_DEPTH],
+    base_count: u32,
+    /// Leaves ingested since `base_count`.
+    leaves: Vec<H256>,
+}
+
+impl Default for MerkleTree {
+    fn default() -> Self {
+        Self::from_branch([H256::zero(); TREE_DEPTH], 0)
+    }
+}
+
+impl MerkleTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_leaves(leaves: impl IntoIterator<Item = H256>) -> Result<Self, MerkleTreeError> {
+        let mut tree = Self::new();
+        for leaf in leaves {
+            tree.ingest(leaf)?;
+        }
+        Ok(tree)
+    }
+
+    /// Reconstructs the tree state from a branch and count,
+    /// e.g. the ones stored in the `MerkleTree` returned by `MerkleTreeHook::tree()`.
+    pub fn from_branch(branch: [H256; TREE_DEPTH], count: u32) -> Self {
+        Self {
+            zero_hashes: zero_hashes(),
+            branch,
+            count,
+            base_branch: branch,
+            base_count: count,
+            leaves: vec![],
+        }
+    }
+
+    /// Same as `from_branch`, for a branch returned as a `Vec` by the contract.
+    pub fn from_branch_slice(branch: &[H256], count: u32) -> Result<Self, MerkleTreeError> {
+        let branch: [H256; TREE_DEPTH] = branch
+            .try_into()
+            .map_err(|_| MerkleTreeError::InvalidBranchLength(branch.len()))?;
+        Ok(Self::from_branch(branch, count))
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn branch(&self) -> &[H256; TREE_DEPTH] {
+        &self.branch
+    }
+
+    /// Same as `StorageMerkleTree::insert` in the Sway library.
+    ///
+    /// ### Returns
+    ///
+    /// * The index of the ingested leaf, as emitted in the `InsertedIntoTreeEvent`.
+    pub fn ingest(&mut self, leaf: H256) -> Result<u32, MerkleTreeError> {
+        if self.count >= MAX_LEAVES {
+            return Err(MerkleTreeError::MerkleTreeFull);
+        }
+        let index = self.count;
+        self.count += 1;
+        self.leaves.push(leaf);
+
+        let mut size = self.count;
+        let mut node = leaf;
+        for i in 0..TREE_DEPTH {
+            if size & 1 == 1 {
+                self.branch[i] = node;
+                return Ok(index);
+            }
+            node = hash_pair(self.branch[i], node);
+            size /= 2;
+        }
+        unreachable!("count is below MAX_LEAVES");
+    }
+
+    /// Same as `StorageMerkleTree::root` in the Sway library.
+    pub fn root(&self) -> H256 {
+        let mut current = H256::zero();
+        for i in 0..TREE_DEPTH {
+            if (self.count >> i) & 1 == 1 {
+                current = hash_pair(self.branch[i], current);
+            } else {
+                current = hash_pair(current, self.zero_hashes[i]);
+            }
+        }
+        current
+    }
+
+    /// Generates the proof of the leaf at `index` against the current root.
+    pub fn proof(&self, index: u32) -> Result<Proof, MerkleTreeError> {
+        if index >= self.count {
+            return Err(MerkleTreeError::IndexOutOfBounds {
+                index,
+                count: self.count,
+            });
+        }
+        if index < self.base_count {
+            return Err(MerkleTreeError::UnknownLeaf(index));
+        }
+
+        let mut path = [H256::zero(); TREE_DEPTH];
+        for (height, sibling) in path.iter_mut().enumerate() {
+            *sibling = self.node(height, (index >> height) ^ 1);
+        }
+
+        Ok(Proof {
+            leaf: self.leaves[(index - self.base_count) as usize],
+            index: index as usize,
+            path,
+        })
+    }
+
+    /// Generates the proof of the leaf at `index` as the array of siblings,
+    /// the format expected by `branch_root` and the merkle root multisig metadata.
+    pub fn branch_at(&self, index: u32) -> Result<[H256; TREE_DEPTH], MerkleTreeError> {
+        Ok(self.proof(index)?.path)
+    }
+
+    /// The node at `height` and `index`, where leaves are at height 0.
+    ///
+    /// Nodes which only cover leaves ingested before the tree was reconstructed are only
+    /// requested as left siblings, in which case they are the node stored in the base branch.
+    fn node(&self, height: usize, index: u32) -> H256 {
+        let start = (index as u64) << height;
+        let end = start + (1u64 << height);
+
+        if start >= self.count as u64 {
+            return self.zero_hashes[height];
+        }
+        if end <= self.base_count as u64 {
+            return self.base_branch[height];
+        }
+        if height == 0 {
+            return self.leaves[(start - self.base_count as u64) as usize];
+        }
+        hash_pair(
+            self.node(height - 1, index * 2),
+            self.node(height - 1, index * 2 + 1),
+        )
+    }
+}
+
+fn hash_pair(left: H256, right: H256) -> H256 {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(left.as_bytes());
+    bytes[32..].copy_from_slice(right.as_bytes());
+    H256(keccak256(bytes))
+}